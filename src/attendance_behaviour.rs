@@ -0,0 +1,304 @@
+use crate::general_behaviour::is_user_administrator;
+use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+use crate::send_message;
+use itertools::Itertools;
+
+/**
+ * The number of the latest movie nights whose attendees count as active members
+ */
+const ACTIVE_MEMBER_NIGHTS: usize = 5;
+
+/**
+ * The attendance of one member over all movie nights since the member attended or added a movie for the first time
+ */
+pub struct AttendanceStats {
+    pub attended: usize,
+    pub nights: usize,
+    pub current_streak: usize,
+    pub longest_streak: usize,
+    pub missed_streak: usize, // The number of the latest nights the member missed in a row
+}
+
+impl AttendanceStats {
+    pub fn get_rate(&self) -> f64 {
+        if self.nights == 0 {
+            0.0
+        } else {
+            self.attended as f64 / self.nights as f64
+        }
+    }
+}
+
+/**
+ * Adds the users to the attendees of the entry, keeping the list sorted and free of duplicates
+ */
+pub fn add_attendees(entry: &mut WatchListEntry, user_ids: &[u64]) {
+    entry.attendees.extend(user_ids.iter());
+    entry.attendees.sort_unstable();
+    entry.attendees.dedup();
+}
+
+/**
+ * Formats the attendees of a movie as mentions
+ */
+pub fn format_attendees(entry: &WatchListEntry) -> String {
+    if entry.status != MovieStatus::Watched {
+        String::from("Noch nicht geschaut")
+    } else if entry.attendees.is_empty() {
+        String::from("Nicht erfasst")
+    } else {
+        entry.attendees.iter().map(|user_id| format!("<@{}>", user_id)).join(", ")
+    }
+}
+
+/**
+ * Returns all movie nights, which are the watched movies with known attendees, sorted by the date they were watched
+ */
+fn get_movie_nights(bot_data: &crate::BotData) -> Vec<&WatchListEntry> {
+    bot_data.watch_list.values()
+        .filter(|entry| entry.status == MovieStatus::Watched && !entry.attendees.is_empty())
+        .sorted_by_key(|entry| entry.watched_or_removed_timestamp)
+        .collect()
+}
+
+/**
+ * Returns the ids of all members who attended at least one of the given number of latest movie nights
+ */
+fn get_latest_attendees(bot_data: &crate::BotData, nights: usize) -> Vec<u64> {
+    get_movie_nights(bot_data).iter()
+        .rev()
+        .take(nights)
+        .flat_map(|entry| entry.attendees.iter().copied())
+        .unique()
+        .collect()
+}
+
+/**
+ * Returns the sorted ids of all active members. These are the owners of movies on the watch list
+ * and the attendees of the latest movie nights.
+ */
+pub fn get_active_members(bot_data: &crate::BotData) -> Vec<u64> {
+    let mut active_members: Vec<u64> = bot_data.watch_list.values()
+        .filter(|entry| entry.status.is_watch_list_status())
+        .map(|entry| entry.user_id.0)
+        .chain(get_latest_attendees(bot_data, ACTIVE_MEMBER_NIGHTS))
+        .collect();
+
+    active_members.sort_unstable();
+    active_members.dedup();
+    active_members
+}
+
+/**
+ * Calculates the attendance of the user. Nights before the user attended for the first time or added the first movie
+ * are not counted, so new members do not start with a bad attendance. Members who added a movie but never attended
+ * therefore missed every night since then.
+ */
+pub fn get_attendance_stats(bot_data: &crate::BotData, user_id: u64) -> AttendanceStats {
+    let first_added_timestamp = bot_data.watch_list.values()
+        .filter(|entry| entry.user_id.0 == user_id)
+        .map(|entry| entry.added_timestamp)
+        .min();
+
+    let attended_nights: Vec<bool> = get_movie_nights(bot_data).iter()
+        .skip_while(|entry| {
+            let watched_before_first_movie = match (entry.watched_or_removed_timestamp, first_added_timestamp) {
+                (Some(watched_timestamp), Some(added_timestamp)) => watched_timestamp < added_timestamp,
+                _ => true,
+            };
+
+            watched_before_first_movie && !entry.attendees.contains(&user_id)
+        })
+        .map(|entry| entry.attendees.contains(&user_id))
+        .collect();
+
+    let mut longest_streak = 0;
+    let mut streak = 0;
+    for attended in attended_nights.iter() {
+        if *attended {
+            streak += 1;
+            longest_streak = longest_streak.max(streak);
+        } else {
+            streak = 0;
+        }
+    }
+
+    AttendanceStats {
+        attended: attended_nights.iter().filter(|attended| **attended).count(),
+        nights: attended_nights.len(),
+        current_streak: attended_nights.iter().rev().take_while(|attended| **attended).count(),
+        longest_streak,
+        missed_streak: attended_nights.iter().rev().take_while(|attended| !**attended).count(),
+    }
+}
+
+/**
+ * Returns true if the owner missed at least as many of the latest movie nights in a row as the configured limit.
+ * Movies of such owners are skipped by random movie votes.
+ */
+pub fn owner_missed_too_many_nights(bot_data: &crate::BotData, user_id: u64) -> bool {
+    bot_data.missed_nights_limit > 0
+        && get_attendance_stats(bot_data, user_id).missed_streak >= bot_data.missed_nights_limit as usize
+}
+
+/**
+ * Adds the users to the attendees of a watched movie. Without users the author of the message is added.
+ * Only the owner of the movie or an administrator can add other users.
+ */
+pub fn add_attended(bot_data: &mut crate::BotData, id: u32, user_ids: Vec<u64>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to add_attended function failed.").author.id;
+    let user_ids = if user_ids.is_empty() { vec![author_id.0] } else { user_ids };
+
+    let entry = match bot_data.watch_list.get(&id) {
+        Some(entry) => entry,
+        None => return send_message::movie_id_not_found_error(bot_data, &id),
+    };
+
+    if user_ids.iter().any(|user_id| *user_id != author_id.0)
+        && entry.user_id != author_id
+        && !is_user_administrator(bot_data, author_id)
+    {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if entry.status != MovieStatus::Watched {
+        return send_message::movie_not_watched_yet_error(bot_data, id);
+    }
+
+    if let Some(entry) = bot_data.watch_list.get_mut(&id) {
+        add_attendees(entry, &user_ids);
+        let entry = entry.clone();
+        send_message::attendees_changed_successfully(bot_data, id, &entry);
+    }
+}
+
+/**
+ * Removes the users from the attendees of a watched movie. Users can remove themselves,
+ * all others can only be removed by the owner of the movie or an administrator.
+ */
+pub fn remove_attended(bot_data: &mut crate::BotData, id: u32, user_ids: Vec<u64>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to remove_attended function failed.").author.id;
+    let user_ids = if user_ids.is_empty() { vec![author_id.0] } else { user_ids };
+
+    let entry = match bot_data.watch_list.get(&id) {
+        Some(entry) => entry,
+        None => return send_message::movie_id_not_found_error(bot_data, &id),
+    };
+
+    if user_ids.iter().any(|user_id| *user_id != author_id.0)
+        && entry.user_id != author_id
+        && !is_user_administrator(bot_data, author_id)
+    {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if let Some(entry) = bot_data.watch_list.get_mut(&id) {
+        entry.attendees.retain(|user_id| !user_ids.contains(user_id));
+        let entry = entry.clone();
+        send_message::attendees_changed_successfully(bot_data, id, &entry);
+    }
+}
+
+/**
+ * Sets the number of movie nights in a row an owner can miss before the movies of the owner are skipped
+ * by random movie votes. 0 disables the limit. Only administrators can change it.
+ */
+pub fn set_missed_nights_limit(bot_data: &mut crate::BotData, limit: u32) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_missed_nights_limit function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    bot_data.missed_nights_limit = limit;
+    send_message::missed_nights_limit_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current missed nights limit
+ */
+pub fn show_missed_nights_limit(bot_data: &crate::BotData) {
+    send_message::missed_nights_limit_information(bot_data, false);
+}
+
+/**
+ * Generates the attendance of all members for the statistics, sorted by the attendance rate
+ */
+pub fn generate_attendance_lines(bot_data: &crate::BotData) -> Vec<String> {
+    let user_ids: Vec<u64> = get_movie_nights(bot_data).iter()
+        .flat_map(|entry| entry.attendees.iter().copied())
+        .unique()
+        .collect();
+
+    user_ids.iter()
+        .map(|user_id| (user_id, get_attendance_stats(bot_data, *user_id)))
+        .sorted_by(|first, second| second.1.get_rate().partial_cmp(&first.1.get_rate())
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| second.1.attended.cmp(&first.1.attended)))
+        .map(|(user_id, stats)| format!(
+            "<@{}> - {}/{} ({:.0}%) | Serie: {} | Beste Serie: {}",
+            user_id,
+            stats.attended,
+            stats.nights,
+            stats.get_rate() * 100.0,
+            stats.current_streak,
+            stats.longest_streak
+        ))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    /**
+     * Adds a watched movie for every night with the attendees of that night. The nights are one day apart.
+     */
+    fn add_movie_nights(bot_data: &mut crate::BotData, nights: &[&[u64]]) {
+        let first_night = DateTime::parse_from_rfc3339("2021-02-01T20:00:00+01:00").unwrap();
+        for (index, attendees) in nights.iter().enumerate() {
+            let mut entry = crate::get_test_watch_list_entry("Filmabend", 2);
+            entry.status = MovieStatus::Watched;
+            entry.watched_or_removed_timestamp = Some(first_night + chrono::Duration::days(index as i64));
+            add_attendees(&mut entry, attendees);
+            bot_data.watch_list.insert(index as u32, entry);
+        }
+    }
+
+    #[test]
+    fn counts_streaks_since_the_first_attended_night() {
+        let mut bot_data = crate::get_test_bot_data();
+        add_movie_nights(&mut bot_data, &[&[2], &[1, 2], &[1], &[2], &[1, 2], &[1], &[1, 2], &[2], &[2]]);
+
+        let stats = get_attendance_stats(&bot_data, 1);
+        assert_eq!(stats.attended, 5);
+        assert_eq!(stats.nights, 8);
+        assert_eq!(stats.current_streak, 0);
+        assert_eq!(stats.longest_streak, 3);
+        assert_eq!(stats.missed_streak, 2);
+
+        let stats = get_attendance_stats(&bot_data, 2);
+        assert_eq!((stats.attended, stats.nights), (7, 9));
+        assert_eq!((stats.current_streak, stats.longest_streak, stats.missed_streak), (3, 3, 0));
+    }
+
+    #[test]
+    fn counts_missed_nights_since_the_first_added_movie() {
+        let mut bot_data = crate::get_test_bot_data();
+        add_movie_nights(&mut bot_data, &[&[2], &[2], &[2], &[2]]);
+        let mut entry = crate::get_test_watch_list_entry("Alien", 3);
+        entry.added_timestamp = DateTime::parse_from_rfc3339("2021-02-02T12:00:00+01:00").unwrap();
+        bot_data.watch_list.insert(10, entry);
+
+        let stats = get_attendance_stats(&bot_data, 3);
+        assert_eq!((stats.attended, stats.nights, stats.missed_streak), (0, 3, 3));
+        assert_eq!(get_attendance_stats(&bot_data, 4).nights, 0);
+
+        assert!(!owner_missed_too_many_nights(&bot_data, 3));
+        bot_data.missed_nights_limit = 3;
+        assert!(owner_missed_too_many_nights(&bot_data, 3));
+        bot_data.missed_nights_limit = 4;
+        assert!(!owner_missed_too_many_nights(&bot_data, 3));
+    }
+}
@@ -1,4 +1,12 @@
 use std::{str::FromStr};
+use crate::filter_behaviour::MovieFilter;
+use crate::movie_behaviour::MovieSortOrder;
+use crate::export_behaviour::{ExportList, ExportFormat};
+use crate::seen_behaviour::SeenPolicy;
+use crate::selection_behaviour::SelectionSetting;
+use crate::snooze_behaviour::SnoozeEnd;
+use crate::tie_break_behaviour::TieBreakPolicy;
+use crate::voting_behaviour::VoteSettings;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum Command {
@@ -6,29 +14,73 @@ pub enum Command {
     AddMovie(String),
     RemoveMovieByTitle(String),
     RemoveMovieById(u32),
-    ShowWatchlist(String),
+    ShowWatchlist(MovieSortOrder, /*descending*/ bool, Vec<MovieFilter>),
     Help(SimpleCommand),
     Prefix(char),
     SetMovieLimit(u32),
     ShowMovieLimit,
-    History(String),
+    History(MovieSortOrder, /*descending*/ bool, Vec<MovieFilter>),
     SetStatus(u32, String),
     Unavailable(u32),
     Watched(u32, String),
     ShowMovieByTitle(String),
     ShowMovieById(u32),
     SearchMovie(String),
-    CreateVote(String, Vec<String>),
+    CreateVote(String, Vec<String>, VoteSettings),
     SendVote,
     SendVoteWithUserId(u64),
-    CloseVote,
+    SendVoteWithId(u32),
+    CloseVote(/*vote id*/ Option<u32>),
     SetMovieVoteLimit(u32),
     ShowMovieVoteLimit,
-    RandomMovieVote(Option<u32>),
+    RandomMovieVote(Option<u32>, VoteSettings),
+    RandomMovieVoteDryRun(Option<u32>),
     CloseMovieVote,
     Info,
     Save,
     Count,
+    RefreshMovie(u32),
+    RefreshAllMovies,
+    SetAutoRefresh(u32),
+    ShowAutoRefresh,
+    Export(ExportList, ExportFormat),
+    Import,
+    ChooseImportOption(/*row*/ usize, /*option*/ usize),
+    Rate(u32, u8),
+    WriteReview(u32, /*user_id*/ Option<u64>, String),
+    DeleteReview(u32, /*user_id*/ Option<u64>),
+    ShowReviews(/*user_id*/ Option<u64>),
+    Statistics,
+    Profile(/*user_id*/ Option<u64>),
+    CreateEvent(/*date*/ String, /*time*/ String, /*title*/ String),
+    CancelEvent(u32),
+    ShowEvents,
+    SetEventReminders(/*offsets in minutes*/ Vec<u32>),
+    ShowEventReminders,
+    AddAttended(u32, /*user_ids*/ Vec<u64>),
+    RemoveAttended(u32, /*user_ids*/ Vec<u64>),
+    SetMissedNightsLimit(u32),
+    ShowMissedNightsLimit,
+    MarkSeen(u32, /*seen*/ bool),
+    SetSeenLimit(/*share in percent*/ u32, Option<SeenPolicy>),
+    ShowSeenLimit,
+    Rank(/*vote id*/ Option<u32>, /*emojis*/ Vec<String>),
+    Ballot(/*vote id*/ Option<u32>, /*emojis*/ Vec<String>),
+    SetTieBreakPolicy(TieBreakPolicy),
+    ShowTieBreakPolicy,
+    ShowVoteHistory,
+    ShowArchivedVote(u32),
+    AddVoteOption(/*vote id*/ Option<u32>, String),
+    RemoveVoteOption(/*vote id*/ Option<u32>, /*emoji*/ String),
+    RenameVote(/*vote id*/ Option<u32>, String),
+    SetSelectionPolicy(SelectionSetting),
+    ShowSelectionPolicy,
+    ShowOwnerAppearances,
+    Veto(/*vote id*/ Option<u32>, /*emoji*/ String),
+    ShowVetoes,
+    SetVetoLimit(u32, /*period in days*/ Option<u32>),
+    ShowVetoLimit,
+    Snooze(u32, SnoozeEnd),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -50,10 +102,45 @@ pub enum ParseCommandError {
     NoArgumentsForShowMovie,
     NoArgumentsForSearchMovie,
     NoArgumentsForCreateVote,
+    WrongArgumentsForCreateVote,
     WrongArgumentsForMovieLimit,
     WrongArgumentsForMovieVoteLimit,
     WrongArgumentsForSendVoteWithUserId,
+    WrongArgumentsForCloseVote,
     WrongArgumentForRandomMovieVote,
+    NoArgumentsForRefresh,
+    WrongArgumentsForRefresh,
+    NotEnoughArgumentsForExport,
+    WrongArgumentsForExport,
+    WrongArgumentsForImport,
+    NotEnoughArgumentsForRate,
+    WrongArgumentsForRate,
+    NotEnoughArgumentsForReview,
+    WrongArgumentsForReview,
+    WrongArgumentsForReviews,
+    WrongArgumentsForProfile,
+    NotEnoughArgumentsForEvent,
+    WrongArgumentsForEvent,
+    NotEnoughArgumentsForAttended,
+    WrongArgumentsForAttended,
+    WrongArgumentsForMissedNightsLimit,
+    NotEnoughArgumentsForSeen,
+    WrongArgumentsForSeen,
+    WrongArgumentsForSeenLimit,
+    NotEnoughArgumentsForRank,
+    WrongArgumentsForRank,
+    NotEnoughArgumentsForBallot,
+    WrongArgumentsForBallot,
+    WrongArgumentsForTieBreak,
+    WrongArgumentsForVotes,
+    NotEnoughArgumentsForVote,
+    WrongArgumentsForVote,
+    WrongArgumentsForSelectionPolicy,
+    NotEnoughArgumentsForVeto,
+    WrongArgumentsForVeto,
+    WrongArgumentsForVetoLimit,
+    NotEnoughArgumentsForSnooze,
+    WrongArgumentsForSnooze,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -81,6 +168,28 @@ pub enum SimpleCommand {
     Info,
     Save,
     Count,
+    Refresh,
+    Export,
+    Import,
+    Rate,
+    Review,
+    Statistics,
+    Profile,
+    Event,
+    Attended,
+    MissedNightsLimit,
+    Seen,
+    SeenLimit,
+    Rank,
+    Ballot,
+    TieBreak,
+    Vote,
+    Votes,
+    SelectionPolicy,
+    Veto,
+    Vetoes,
+    VetoLimit,
+    Snooze,
     Unknown(String),
 }
 
@@ -110,6 +219,28 @@ impl From<&str> for SimpleCommand {
             INFO => Self::Info,
             SAVE => Self::Save,
             COUNT_MOVIES | COUNT_MOVIES_SHORT => Self::Count,
+            REFRESH | REFRESH_SHORT => Self::Refresh,
+            EXPORT => Self::Export,
+            IMPORT => Self::Import,
+            RATE => Self::Rate,
+            REVIEW | REVIEW_SHORT | REVIEWS => Self::Review,
+            STATISTICS | STATISTICS_SHORT => Self::Statistics,
+            PROFILE | PROFILE_SHORT => Self::Profile,
+            EVENT | EVENTS => Self::Event,
+            ATTENDED | ATTENDED_SHORT => Self::Attended,
+            MISSED_NIGHTS_LIMIT | MISSED_NIGHTS_LIMIT_SHORT => Self::MissedNightsLimit,
+            SEEN => Self::Seen,
+            SEEN_LIMIT | SEEN_LIMIT_SHORT => Self::SeenLimit,
+            RANK => Self::Rank,
+            BALLOT => Self::Ballot,
+            TIE_BREAK | TIE_BREAK_SHORT => Self::TieBreak,
+            VOTE => Self::Vote,
+            VOTES => Self::Votes,
+            SELECTION_POLICY | SELECTION_POLICY_SHORT => Self::SelectionPolicy,
+            VETO => Self::Veto,
+            VETOES => Self::Vetoes,
+            VETO_LIMIT | VETO_LIMIT_SHORT => Self::VetoLimit,
+            SNOOZE => Self::Snooze,
             st => Self::Unknown(String::from(st)),
         }
     }
@@ -135,17 +266,17 @@ impl FromStr for Command {
             QUIT => Self::Quit,
             HELP | HELP_SHORT => Self::Help(SimpleCommand::from(arguments.join(" ").as_str())),
             SHOW_WATCH_LIST | SHOW_WATCH_LIST_SHORT => {
-                // Only one argument is expected. All others will be ignored
-                let mut argument = "".to_string();
-                if arguments.len() > 0 {
-                    argument = arguments[0].to_lowercase();
-                }
+                // The watch list has no watched date, every other order is allowed
+                let allowed_orders = vec![
+                    MovieSortOrder::Id, MovieSortOrder::User, MovieSortOrder::Title, MovieSortOrder::Year, 
+                    MovieSortOrder::Runtime, MovieSortOrder::Added, MovieSortOrder::Rating, MovieSortOrder::Language,
+                ];
 
-                if argument != "id" && argument != "user" && argument != "" {
+                if let Ok((order, descending, filters)) = parse_order_and_filters(arguments, allowed_orders, MovieSortOrder::Id) {
+                    Self::ShowWatchlist(order, descending, filters)
+                } else {
                     return Err(ParseCommandError::WrongArgumentForWatchList);
                 }
-
-                Self::ShowWatchlist(argument)
             },
             ADD_MOVIE | ADD_MOVIE_SHORT => {
                 let title = arguments.join(" ");
@@ -184,17 +315,16 @@ impl FromStr for Command {
                 }
             },
             SHOW_HISTORY | SHOW_HISTORY_SHORT => {
-                // Only one argument expected. Others are ignored
-                let mut argument = "".to_string();
-                if arguments.len() > 0 {
-                    argument = arguments[0].to_lowercase();
-                }
+                let allowed_orders = vec![
+                    MovieSortOrder::Date, MovieSortOrder::User, MovieSortOrder::Id, MovieSortOrder::Title, MovieSortOrder::Year, 
+                    MovieSortOrder::Runtime, MovieSortOrder::Added, MovieSortOrder::Rating, MovieSortOrder::Language,
+                ];
 
-                if argument != "date" && argument != "user" && argument != "" {
+                if let Ok((order, descending, filters)) = parse_order_and_filters(arguments, allowed_orders, MovieSortOrder::Date) {
+                    Self::History(order, descending, filters)
+                } else {
                     return Err(ParseCommandError::WrongArgumentForHistory);
                 }
-
-                Self::History(argument)
             },
             SET_STATUS | SET_STATUS_SHORT => {
                 // first argument should be u32, second should be the new status
@@ -285,9 +415,21 @@ impl FromStr for Command {
                     return Err(ParseCommandError::NoArgumentsForCreateVote);
                 }
 
-                let vote_title = vote_parameters.remove(0);
+                let mut vote_title = vote_parameters.remove(0);
+
+                // The title can start with settings of the vote, e.g. mode:ranked or duration:2h
+                let mut settings = VoteSettings::default();
+                loop {
+                    let (setting, title) = vote_title.split_once(' ').unwrap_or((vote_title.as_str(), ""));
+
+                    match settings.parse_setting(setting) {
+                        Ok(true) => vote_title = title.trim().to_string(),
+                        Ok(false) => break,
+                        Err(_) => return Err(ParseCommandError::WrongArgumentsForCreateVote),
+                    }
+                }
 
-                Self::CreateVote(vote_title, vote_parameters)
+                Self::CreateVote(vote_title, vote_parameters, settings)
             },
             SEND_VOTE | SEND_VOTE_SHORT => {
                 let argument = arguments.join(" ");
@@ -296,6 +438,10 @@ impl FromStr for Command {
                     return Ok(Self::SendVote);
                 }
 
+                if let Ok(vote_id) = argument.parse::<u32>() {
+                    return Ok(Self::SendVoteWithId(vote_id));
+                }
+
                 if argument.contains("!") {
                     if let Some(remainder) = argument.strip_prefix("<@!") {
                         if let Some(remainder) = remainder.strip_suffix(">") {
@@ -318,7 +464,13 @@ impl FromStr for Command {
                     return Err(ParseCommandError::WrongArgumentsForSendVoteWithUserId);
                 }
             },
-            CLOSE_VOTE | CLOSE_VOTE_SHORT => Self::CloseVote,
+            CLOSE_VOTE | CLOSE_VOTE_SHORT => {
+                match arguments.first().map(|argument| argument.parse::<u32>()) {
+                    None => Self::CloseVote(None),
+                    Some(Ok(vote_id)) if arguments.len() == 1 => Self::CloseVote(Some(vote_id)),
+                    _ => return Err(ParseCommandError::WrongArgumentsForCloseVote),
+                }
+            },
             MOVIE_LIMIT | MOVIE_LIMIT_SHORT => {
                 let argument = arguments.join(" ");
                 if argument.is_empty() {
@@ -346,27 +498,482 @@ impl FromStr for Command {
                 }
             },
             RANDOM_MOVIE_VOTE | RANDOM_MOVIE_VOTE_SHORT => {
-                let argument = arguments.join(" ");
-                if argument.is_empty() {
-                    return Ok(Self::RandomMovieVote(None));
+                // A dry run only takes the optional limit
+                if arguments.first() == Some(&"dry-run") {
+                    return match arguments.get(1).map(|argument| argument.parse::<u32>()) {
+                        None if arguments.len() == 1 => Ok(Self::RandomMovieVoteDryRun(None)),
+                        Some(Ok(n)) if arguments.len() == 2 => Ok(Self::RandomMovieVoteDryRun(Some(n))),
+                        _ => Err(ParseCommandError::WrongArgumentForRandomMovieVote),
+                    };
                 }
 
-                // Try to parse the first argument to u32
-                if let Ok(n) = argument.parse::<u32>() {
-                    Self::RandomMovieVote(Some(n))
-                } else {
-                    return Err(ParseCommandError::WrongArgumentForRandomMovieVote);
+                let mut limit = None;
+                let mut settings = VoteSettings::default();
+
+                // Arguments can be the limit and the settings of the vote in any order
+                for argument in arguments.iter() {
+                    match settings.parse_setting(argument) {
+                        Ok(true) => continue,
+                        Ok(false) => (),
+                        Err(_) => return Err(ParseCommandError::WrongArgumentForRandomMovieVote),
+                    }
+
+                    if let Ok(n) = argument.parse::<u32>() {
+                        limit = Some(n);
+                    } else {
+                        return Err(ParseCommandError::WrongArgumentForRandomMovieVote);
+                    }
                 }
+
+                Self::RandomMovieVote(limit, settings)
             },
             CLOSE_MOVIE_VOTE | CLOSE_MOVIE_VOTE_SHORT => Self::CloseMovieVote,
             INFO => Self::Info,
             SAVE => Self::Save,
             COUNT_MOVIES | COUNT_MOVIES_SHORT => Self::Count,
+            REFRESH | REFRESH_SHORT => {
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NoArgumentsForRefresh);
+                }
+
+                match arguments[0].to_lowercase().as_str() {
+                    "all" => Self::RefreshAllMovies,
+                    "auto" => {
+                        // The second argument is the amount of days after which a movie gets refreshed automatically
+                        if arguments.len() < 2 {
+                            return Ok(Self::ShowAutoRefresh);
+                        }
+
+                        let days = arguments[1].to_lowercase();
+                        if days == "off" {
+                            Self::SetAutoRefresh(0)
+                        } else if let Ok(n) = days.parse::<u32>() {
+                            Self::SetAutoRefresh(n)
+                        } else {
+                            return Err(ParseCommandError::WrongArgumentsForRefresh);
+                        }
+                    },
+                    id => {
+                        if let Ok(n) = id.parse::<u32>() {
+                            Self::RefreshMovie(n)
+                        } else {
+                            return Err(ParseCommandError::WrongArgumentsForRefresh);
+                        }
+                    }
+                }
+            },
+            EXPORT => {
+                // first argument should be the list, second the file format
+                if arguments.len() < 2 {
+                    return Err(ParseCommandError::NotEnoughArgumentsForExport);
+                }
+
+                match (ExportList::from_str(arguments[0]), ExportFormat::from_str(arguments[1])) {
+                    (Ok(list), Ok(format)) => Self::Export(list, format),
+                    _ => return Err(ParseCommandError::WrongArgumentsForExport),
+                }
+            },
+            IMPORT => {
+                // The list to import is attached to the message, choose picks a movie for an ambiguous row afterwards
+                match arguments {
+                    [] => Self::Import,
+                    [choose, row, option] if choose.to_lowercase() == "choose" => {
+                        match (row.parse::<usize>(), option.parse::<usize>()) {
+                            (Ok(row), Ok(option)) => Self::ChooseImportOption(row, option),
+                            _ => return Err(ParseCommandError::WrongArgumentsForImport),
+                        }
+                    },
+                    _ => return Err(ParseCommandError::WrongArgumentsForImport),
+                }
+            },
+            STATISTICS | STATISTICS_SHORT => Self::Statistics,
+            RATE => {
+                // first argument should be the id, second the score
+                if arguments.len() < 2 {
+                    return Err(ParseCommandError::NotEnoughArgumentsForRate);
+                }
+
+                match (arguments[0].parse::<u32>(), arguments[1].parse::<u8>()) {
+                    (Ok(id), Ok(score)) => Self::Rate(id, score),
+                    _ => return Err(ParseCommandError::WrongArgumentsForRate),
+                }
+            },
+            REVIEW | REVIEW_SHORT => {
+                // first argument is the id, followed by either delete or the text. Both can target another user by mention
+                if arguments.len() < 2 {
+                    return Err(ParseCommandError::NotEnoughArgumentsForReview);
+                }
+
+                let id = match arguments[0].parse::<u32>() {
+                    Ok(id) => id,
+                    Err(_) => return Err(ParseCommandError::WrongArgumentsForReview),
+                };
+
+                if arguments[1].to_lowercase() == "delete" {
+                    match arguments.get(2) {
+                        Some(mention) => match parse_user_mention(mention) {
+                            Some(user_id) => Self::DeleteReview(id, Some(user_id)),
+                            None => return Err(ParseCommandError::WrongArgumentsForReview),
+                        },
+                        None => Self::DeleteReview(id, None),
+                    }
+                } else if let Some(user_id) = parse_user_mention(arguments[1]) {
+                    if arguments.len() < 3 {
+                        return Err(ParseCommandError::NotEnoughArgumentsForReview);
+                    }
+
+                    // The text is taken from the message itself, so its line breaks are kept
+                    Self::WriteReview(id, Some(user_id), get_text_after_words(s, 3))
+                } else {
+                    Self::WriteReview(id, None, get_text_after_words(s, 2))
+                }
+            },
+            REVIEWS => {
+                match arguments.first() {
+                    Some(mention) => match parse_user_mention(mention) {
+                        Some(user_id) => Self::ShowReviews(Some(user_id)),
+                        None => return Err(ParseCommandError::WrongArgumentsForReviews),
+                    },
+                    None => Self::ShowReviews(None),
+                }
+            },
+            EVENT => {
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NotEnoughArgumentsForEvent);
+                }
+
+                match arguments[0].to_lowercase().as_str() {
+                    "cancel" => {
+                        match arguments.get(1).map(|id| id.parse::<u32>()) {
+                            Some(Ok(id)) => Self::CancelEvent(id),
+                            _ => return Err(ParseCommandError::WrongArgumentsForEvent),
+                        }
+                    },
+                    "reminders" => {
+                        // Without further arguments the current reminders are shown
+                        if arguments.len() < 2 {
+                            return Ok(Self::ShowEventReminders);
+                        }
+
+                        if arguments[1].to_lowercase() == "off" {
+                            Self::SetEventReminders(Vec::new())
+                        } else {
+                            let offsets: Option<Vec<u32>> = arguments[1..].iter()
+                                .map(|offset| crate::general_behaviour::parse_duration_minutes(offset))
+                                .collect();
+
+                            match offsets {
+                                Some(offsets) => Self::SetEventReminders(offsets),
+                                None => return Err(ParseCommandError::WrongArgumentsForEvent),
+                            }
+                        }
+                    },
+                    _ => {
+                        // first argument is the date, second the time and the rest is the optional title
+                        if arguments.len() < 2 {
+                            return Err(ParseCommandError::NotEnoughArgumentsForEvent);
+                        }
+
+                        Self::CreateEvent(arguments[0].to_string(), arguments[1].to_string(), arguments[2..].join(" "))
+                    }
+                }
+            },
+            EVENTS => Self::ShowEvents,
+            ATTENDED | ATTENDED_SHORT => {
+                // first argument is the id, followed by an optional remove and the mentioned users
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NotEnoughArgumentsForAttended);
+                }
+
+                let id = match arguments[0].parse::<u32>() {
+                    Ok(id) => id,
+                    Err(_) => return Err(ParseCommandError::WrongArgumentsForAttended),
+                };
+
+                let remove = arguments.get(1).map(|argument| argument.to_lowercase() == "remove").unwrap_or(false);
+                let mentions = if remove { &arguments[2..] } else { &arguments[1..] };
+
+                let user_ids: Option<Vec<u64>> = mentions.iter()
+                    .map(|mention| parse_user_mention(mention))
+                    .collect();
+
+                match (user_ids, remove) {
+                    (Some(user_ids), false) => Self::AddAttended(id, user_ids),
+                    (Some(user_ids), true) => Self::RemoveAttended(id, user_ids),
+                    (None, _) => return Err(ParseCommandError::WrongArgumentsForAttended),
+                }
+            },
+            RANK => {
+                // an optional id of the vote is followed by the emojis of the options in order of preference
+                let (vote_id, arguments) = split_off_vote_id(arguments);
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NotEnoughArgumentsForRank);
+                }
+
+                let emojis: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+
+                // Every option can only be ranked once
+                if emojis.iter().enumerate().any(|(idx, emoji)| emojis[..idx].contains(emoji)) {
+                    return Err(ParseCommandError::WrongArgumentsForRank);
+                }
+
+                Self::Rank(vote_id, emojis)
+            },
+            BALLOT => {
+                // an optional id of the vote is followed by the emojis of the chosen options
+                let (vote_id, arguments) = split_off_vote_id(arguments);
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NotEnoughArgumentsForBallot);
+                }
+
+                let emojis: Vec<String> = arguments.iter().map(|argument| argument.to_string()).collect();
+
+                // Every option can only be chosen once
+                if emojis.iter().enumerate().any(|(idx, emoji)| emojis[..idx].contains(emoji)) {
+                    return Err(ParseCommandError::WrongArgumentsForBallot);
+                }
+
+                Self::Ballot(vote_id, emojis)
+            },
+            SEEN => {
+                // first argument is the id, an optional second argument remove marks the movie as not seen
+                if arguments.is_empty() {
+                    return Err(ParseCommandError::NotEnoughArgumentsForSeen);
+                }
+
+                let seen = match arguments.get(1).map(|argument| argument.to_lowercase()) {
+                    None => true,
+                    Some(argument) if argument == "remove" => false,
+                    Some(_) => return Err(ParseCommandError::WrongArgumentsForSeen),
+                };
+
+                match arguments[0].parse::<u32>() {
+                    Ok(id) => Self::MarkSeen(id, seen),
+                    Err(_) => return Err(ParseCommandError::WrongArgumentsForSeen),
+                }
+            },
+            VOTE => {
+                // an optional id of the vote is followed by the kind of change and the option, the emoji or the title
+                let (vote_id, arguments) = match arguments.first().map(|argument| argument.parse::<u32>()) {
+                    Some(Ok(vote_id)) => (Some(vote_id), &arguments[1..]),
+                    _ => (None, arguments),
+                };
+
+                if arguments.len() < 2 {
+                    return Err(ParseCommandError::NotEnoughArgumentsForVote);
+                }
+
+                let value = arguments[1..].join(" ");
+                match arguments[0].to_lowercase().as_str() {
+                    "add" => Self::AddVoteOption(vote_id, value),
+                    "remove" if arguments.len() == 2 => Self::RemoveVoteOption(vote_id, value),
+                    "title" => Self::RenameVote(vote_id, value),
+                    _ => return Err(ParseCommandError::WrongArgumentsForVote),
+                }
+            },
+            VOTES => {
+                // history shows the latest closed votes, an additional id shows the details of a closed vote
+                match arguments.first().map(|argument| argument.to_lowercase()).as_deref() {
+                    Some("history") => match arguments.get(1).map(|id| id.parse::<u32>()) {
+                        None => Self::ShowVoteHistory,
+                        Some(Ok(id)) => Self::ShowArchivedVote(id),
+                        Some(Err(_)) => return Err(ParseCommandError::WrongArgumentsForVotes),
+                    },
+                    _ => return Err(ParseCommandError::WrongArgumentsForVotes),
+                }
+            },
+            TIE_BREAK | TIE_BREAK_SHORT => {
+                if arguments.is_empty() {
+                    return Ok(Self::ShowTieBreakPolicy);
+                }
+
+                match arguments.join(" ").parse::<TieBreakPolicy>() {
+                    Ok(policy) => Self::SetTieBreakPolicy(policy),
+                    Err(_) => return Err(ParseCommandError::WrongArgumentsForTieBreak),
+                }
+            },
+            SELECTION_POLICY | SELECTION_POLICY_SHORT => {
+                if arguments.is_empty() {
+                    return Ok(Self::ShowSelectionPolicy);
+                } else if arguments.len() == 1 && arguments[0].to_lowercase() == "fairness" {
+                    return Ok(Self::ShowOwnerAppearances);
+                }
+
+                match arguments.join(" ").parse::<SelectionSetting>() {
+                    Ok(setting) => Self::SetSelectionPolicy(setting),
+                    Err(_) => return Err(ParseCommandError::WrongArgumentsForSelectionPolicy),
+                }
+            },
+            VETO => {
+                // an optional id of the vote is followed by the emoji of the vetoed option
+                match arguments {
+                    [] => return Err(ParseCommandError::NotEnoughArgumentsForVeto),
+                    [emoji] => Self::Veto(None, emoji.to_string()),
+                    [vote_id, emoji] => match vote_id.parse::<u32>() {
+                        Ok(vote_id) => Self::Veto(Some(vote_id), emoji.to_string()),
+                        Err(_) => return Err(ParseCommandError::WrongArgumentsForVeto),
+                    },
+                    _ => return Err(ParseCommandError::WrongArgumentsForVeto),
+                }
+            },
+            VETOES => Self::ShowVetoes,
+            SNOOZE => {
+                // first argument is the id, second the duration or the date until the movie is snoozed
+                if arguments.len() < 2 {
+                    return Err(ParseCommandError::NotEnoughArgumentsForSnooze);
+                }
+
+                match (arguments[0].parse::<u32>(), arguments[1..].join(" ").parse::<SnoozeEnd>()) {
+                    (Ok(id), Ok(end)) => Self::Snooze(id, end),
+                    _ => return Err(ParseCommandError::WrongArgumentsForSnooze),
+                }
+            },
+            VETO_LIMIT | VETO_LIMIT_SHORT => {
+                // first argument is the number of vetoes, second the optional period in days
+                if arguments.is_empty() {
+                    return Ok(Self::ShowVetoLimit);
+                }
+
+                let period_days = match arguments.get(1).map(|argument| argument.parse::<u32>()) {
+                    None => None,
+                    Some(Ok(period_days)) => Some(period_days),
+                    Some(Err(_)) => return Err(ParseCommandError::WrongArgumentsForVetoLimit),
+                };
+
+                let limit = arguments[0].to_lowercase();
+                if limit == "off" {
+                    Self::SetVetoLimit(0, period_days)
+                } else if let Ok(n) = limit.parse::<u32>() {
+                    Self::SetVetoLimit(n, period_days)
+                } else {
+                    return Err(ParseCommandError::WrongArgumentsForVetoLimit);
+                }
+            },
+            SEEN_LIMIT | SEEN_LIMIT_SHORT => {
+                // first argument is the share in percent, second the optional policy
+                if arguments.is_empty() {
+                    return Ok(Self::ShowSeenLimit);
+                }
+
+                let policy = match arguments.get(1).map(|argument| SeenPolicy::from_str(argument)) {
+                    None => None,
+                    Some(Ok(policy)) => Some(policy),
+                    Some(Err(_)) => return Err(ParseCommandError::WrongArgumentsForSeenLimit),
+                };
+
+                let share = arguments[0].trim_end_matches('%').to_lowercase();
+                if share == "off" {
+                    Self::SetSeenLimit(0, policy)
+                } else if let Ok(n) = share.parse::<u32>() {
+                    Self::SetSeenLimit(n, policy)
+                } else {
+                    return Err(ParseCommandError::WrongArgumentsForSeenLimit);
+                }
+            },
+            MISSED_NIGHTS_LIMIT | MISSED_NIGHTS_LIMIT_SHORT => {
+                let argument = arguments.join(" ").to_lowercase();
+                if argument.is_empty() {
+                    return Ok(Self::ShowMissedNightsLimit);
+                }
+
+                if argument == "off" {
+                    Self::SetMissedNightsLimit(0)
+                } else if let Ok(n) = argument.parse::<u32>() {
+                    Self::SetMissedNightsLimit(n)
+                } else {
+                    return Err(ParseCommandError::WrongArgumentsForMissedNightsLimit);
+                }
+            },
+            PROFILE | PROFILE_SHORT => {
+                match arguments.first() {
+                    Some(mention) => match parse_user_mention(mention) {
+                        Some(user_id) => Self::Profile(Some(user_id)),
+                        None => return Err(ParseCommandError::WrongArgumentsForProfile),
+                    },
+                    None => Self::Profile(None),
+                }
+            },
             _ => return Err(ParseCommandError::UnknownCommand),
         })
     }
 }
 
+/**
+ * Returns the text of the message after the given number of words, including the command.
+ * Unlike joining the arguments, the line breaks and spaces within the text are kept.
+ */
+fn get_text_after_words(s: &str, word_count: usize) -> String {
+    let mut remainder = s.trim_start();
+    for _ in 0..word_count {
+        let word_end = remainder.find(char::is_whitespace).unwrap_or(remainder.len());
+        remainder = remainder[word_end..].trim_start();
+    }
+
+    remainder.trim_end().to_string()
+}
+
+/**
+ * Splits off the id of a vote if the first argument is a number, emojis of vote options never are
+ */
+fn split_off_vote_id<'a>(arguments: &'a [&'a str]) -> (Option<u32>, &'a [&'a str]) {
+    match arguments.split_first() {
+        Some((first, remainder)) => match first.parse::<u32>() {
+            Ok(vote_id) => (Some(vote_id), remainder),
+            Err(_) => (None, arguments),
+        },
+        None => (None, arguments),
+    }
+}
+
+/**
+ * Parses a user mention like <@123> or <@!123> and returns the user id
+ */
+fn parse_user_mention(argument: &str) -> Option<u64> {
+    argument.strip_prefix("<@")
+        .and_then(|remainder| remainder.strip_suffix(">"))
+        .map(|remainder| remainder.trim_start_matches('!'))
+        .and_then(|user_id| user_id.parse::<u64>().ok())
+}
+
+/**
+ * Parses the arguments of the watch list and history commands. Arguments that look like filter expressions
+ * (e.g. genre:horror) are parsed as filters, `asc` and `desc` set the direction and the first other argument
+ * is the order. Additional orders are ignored. Returns the order, whether it is descending and the filters.
+ */
+fn parse_order_and_filters(
+    arguments: &[&str], 
+    allowed_orders: Vec<MovieSortOrder>, 
+    default_order: MovieSortOrder
+) -> Result<(MovieSortOrder, bool, Vec<MovieFilter>), ()> {
+    let mut order: Option<MovieSortOrder> = None;
+    let mut descending: Option<bool> = None;
+    let mut filters = Vec::new();
+
+    for argument in arguments {
+        if MovieFilter::is_filter_expression(argument) {
+            filters.push(MovieFilter::from_str(argument).map_err(|_| ())?);
+            continue;
+        }
+
+        match argument.to_lowercase().as_str() {
+            "asc" => descending = Some(false),
+            "desc" => descending = Some(true),
+            argument if order.is_none() => {
+                let parsed_order = MovieSortOrder::from_str(argument).map_err(|_| ())?;
+                if !allowed_orders.contains(&parsed_order) {
+                    return Err(());
+                }
+
+                order = Some(parsed_order);
+            },
+            _ => (),
+        }
+    }
+
+    let order = order.unwrap_or(default_order);
+    Ok((order, descending.unwrap_or_else(|| order.is_descending_by_default()), filters))
+}
+
 // Command, Usage | Description
 // General
 pub const QUIT: &str = "quit"; // !quit | Quits the bot and saves all changes
@@ -381,12 +988,12 @@ pub const ADD_MOVIE: &str = "add_movie"; // !add_movie <title|imdb_link> | Adds
 pub const ADD_MOVIE_SHORT: &str = "am"; // !am <title|imdb_link> | Short form for add_movie
 pub const REMOVE_MOVIE: &str = "remove_movie"; // !remove_movie <title|id> | Removes a movie by id or by title from the watch list
 pub const REMOVE_MOVIE_SHORT: &str = "rm"; // !rm <title|id> | Short form for remove_movie
-pub const SHOW_WATCH_LIST: &str = "watch_list"; // !watch_list <optional: order> | Shows the full watch list
-pub const SHOW_WATCH_LIST_SHORT: &str = "wl"; // !wl <optional: order> | Short form for watch_list
+pub const SHOW_WATCH_LIST: &str = "watch_list"; // !watch_list <optional: order> <optional: asc|desc> <optional: filters> | Shows the full watch list
+pub const SHOW_WATCH_LIST_SHORT: &str = "wl"; // !wl <optional: order> <optional: asc|desc> <optional: filters> | Short form for watch_list
 pub const MOVIE_LIMIT: &str = "movie_limit"; // !movie_limit <optional: number> | Sets the maximum amount of movies each user can add
 pub const MOVIE_LIMIT_SHORT: &str = "ml"; // !ml <optional: number> | Short form for movie_limit
-pub const SHOW_HISTORY: &str = "history"; // !history <optional: order> | Shows a list of all movies that have been watched already or that have the status 'removed'
-pub const SHOW_HISTORY_SHORT: &str = "hs"; // !h <optional: order> | Short form for history
+pub const SHOW_HISTORY: &str = "history"; // !history <optional: order> <optional: asc|desc> <optional: filters> | Shows a list of all movies that have been watched already or that have the status 'removed'
+pub const SHOW_HISTORY_SHORT: &str = "hs"; // !h <optional: order> <optional: asc|desc> <optional: filters> | Short form for history
 pub const SET_STATUS: &str = "status"; // !set_status <id> <movie_status> | Sets the status of a movie
 pub const SET_STATUS_SHORT: &str = "st"; // !st <id> <movie_status> | Short form for set_status
 pub const SET_STATUS_UNAVAILABLE: &str = "unavailable"; // !unavailable <id> | Sets the given movie with id to the unavailable status
@@ -399,17 +1006,264 @@ pub const SEARCH_MOVIE: &str = "search_movie"; // !search_movie <title|imdb_link
 pub const SEARCH_MOVIE_SHORT: &str = "search"; // !search <title|imdb_link> | Short form for search_movie
 pub const COUNT_MOVIES: &str = "count"; // !count | Counts the amount of movies the user added
 pub const COUNT_MOVIES_SHORT: &str = "ct"; // !ct | Short form for count
+pub const REFRESH: &str = "refresh"; // !refresh <id|all|auto> <optional: days|off> | Fetches the current TMDb data of movies and shows what changed
+pub const REFRESH_SHORT: &str = "rf"; // !rf <id|all|auto> <optional: days|off> | Short form for refresh
+pub const EXPORT: &str = "export"; // !export <watchlist|history> <csv|json|md> | Uploads the watch list or history as file
+pub const RATE: &str = "rate"; // !rate <id> <score> | Rates a watched movie from 1 to 10
+pub const REVIEW: &str = "review"; // !review <id> <optional: @user> <text|delete> | Writes, edits or deletes a review of a watched movie
+pub const REVIEW_SHORT: &str = "rv"; // !rv <id> <optional: @user> <text|delete> | Short form for review
+pub const REVIEWS: &str = "reviews"; // !reviews <optional: @user> | Shows all reviews of a user
+pub const IMPORT: &str = "import"; // !import <optional: choose <row> <option>> | Adds the movies of an attached Letterboxd or IMDb csv export to the watch list, or chooses a movie for an ambiguous row
+pub const SEEN: &str = "seen"; // !seen <id> <optional: remove> | Marks a movie on the watch list as already seen by the user
+pub const SEEN_LIMIT: &str = "seen_limit"; // !seen_limit <optional: percent|off> <optional: exclude|weight> | Sets the share of active members that may have seen a movie before it is excluded or chosen less often by random movie votes
+pub const SEEN_LIMIT_SHORT: &str = "sl"; // !sl <optional: percent|off> <optional: exclude|weight> | Short form for seen_limit
+pub const STATISTICS: &str = "statistics"; // !statistics | Shows statistics about all watched movies
+pub const STATISTICS_SHORT: &str = "stats"; // !stats | Short form for statistics
+pub const PROFILE: &str = "profile"; // !profile <optional: @user> | Shows the movies and ratings of a user
+pub const PROFILE_SHORT: &str = "pf"; // !pf <optional: @user> | Short form for profile
+
+// Events
+pub const EVENT: &str = "event"; // !event <date> <time> <optional: title> | Creates a movie night with reactions to sign up
+pub const EVENTS: &str = "events"; // !events | Shows all upcoming movie nights
+pub const ATTENDED: &str = "attended"; // !attended <id> <optional: remove> <optional: @users> | Adds or removes the attendees of a watched movie
+pub const ATTENDED_SHORT: &str = "ad"; // !ad <id> <optional: remove> <optional: @users> | Short form for attended
+pub const MISSED_NIGHTS_LIMIT: &str = "missed_nights_limit"; // !missed_nights_limit <optional: number|off> | Sets after how many missed movie nights in a row the movies of an owner are skipped by random movie votes
+pub const MISSED_NIGHTS_LIMIT_SHORT: &str = "mnl"; // !mnl <optional: number|off> | Short form for missed_nights_limit
 
 // Voting
-pub const CREATE_VOTE: &str = "create_vote"; // !create_vote <title>|<option1>|<option2>|... | Creates a new vote and displays its information
-pub const CREATE_VOTE_SHORT: &str = "cv"; // !cv <title>|<option1>|<option2>|... | Short form for create_vote
-pub const SEND_VOTE: &str = "send_vote"; // !send_vote <optional: @OtherUser> | Sends the current vote message of the user again
-pub const SEND_VOTE_SHORT: &str = "sv"; // !sv <optional: @OtherUser> | Short form for send_vote
-pub const CLOSE_VOTE: &str = "close_vote"; // !close_vote | Closes the current vote of the user
-pub const CLOSE_VOTE_SHORT: &str = "xv"; // !xv | Short form for close_vote
+pub const CREATE_VOTE: &str = "create_vote"; // !create_vote <optional: mode:single|mode:ranked|mode:approval|mode:pick:<number>> <optional: duration:<duration>|until:<HH:MM>> <optional: ballot:secret> <title>|<option1>|<option2>|... | Creates a new vote and displays its information
+pub const CREATE_VOTE_SHORT: &str = "cv"; // !cv <optional: mode:single|mode:ranked|mode:approval|mode:pick:<number>> <optional: duration:<duration>|until:<HH:MM>> <optional: ballot:secret> <title>|<option1>|<option2>|... | Short form for create_vote
+pub const SEND_VOTE: &str = "send_vote"; // !send_vote <optional: @OtherUser|id> | Sends the current vote message of the user or the vote with the id again
+pub const SEND_VOTE_SHORT: &str = "sv"; // !sv <optional: @OtherUser|id> | Short form for send_vote
+pub const CLOSE_VOTE: &str = "close_vote"; // !close_vote <optional: id> | Closes the current vote of the user or the vote with the id
+pub const CLOSE_VOTE_SHORT: &str = "xv"; // !xv <optional: id> | Short form for close_vote
 pub const MOVIE_VOTE_LIMIT: &str = "movie_vote_limit"; // !movie_vote_limit <optional: number> | Sets the amount of movies that are selected for a new movie vote
 pub const MOVIE_VOTE_LIMIT_SHORT: &str = "mvl"; // !mvl <optional: number> | Short form for movie_vote_limit
-pub const RANDOM_MOVIE_VOTE: &str = "random_movie_vote"; // !random_movie_vote <optional: number> | Creates a new random movie vote with optional given movie limit
-pub const RANDOM_MOVIE_VOTE_SHORT: &str = "rmv"; // !rmv <optional: number> | Short form for random_movie_vote
+pub const RANDOM_MOVIE_VOTE: &str = "random_movie_vote"; // !random_movie_vote <optional: dry-run> <optional: number> <optional: mode:single|mode:ranked|mode:approval|mode:pick:<number>> <optional: duration:<duration>|until:<HH:MM>> <optional: ballot:secret> | Creates a new random movie vote with optional given movie limit, or shows the chance of every movie with dry-run
+pub const RANDOM_MOVIE_VOTE_SHORT: &str = "rmv"; // !rmv <optional: dry-run> <optional: number> <optional: mode:single|mode:ranked|mode:approval|mode:pick:<number>> <optional: duration:<duration>|until:<HH:MM>> <optional: ballot:secret> | Short form for random_movie_vote
 pub const CLOSE_MOVIE_VOTE: &str = "close_movie_vote"; // !close_movie_vote | Closes the current movie vote, presents the results and sends the watch link in the chat.
-pub const CLOSE_MOVIE_VOTE_SHORT: &str = "cmv"; // !cmv | Short form for close_movie_vote
\ No newline at end of file
+pub const CLOSE_MOVIE_VOTE_SHORT: &str = "cmv"; // !cmv | Short form for close_movie_vote
+pub const RANK: &str = "rank"; // !rank <optional: id> <emoji1> <emoji2> ... | Ranks the options of a ranked vote in order of preference, e.g. in a direct message to the bot
+pub const BALLOT: &str = "ballot"; // !ballot <optional: id> <emoji1> <emoji2> ... | Votes for the options of a vote, e.g. secretly in a direct message to the bot
+pub const TIE_BREAK: &str = "tie_break"; // !tie_break <optional: random|oldest|fewest_watched|runoff> | Sets how ties between the options with the most votes are broken
+pub const TIE_BREAK_SHORT: &str = "tb"; // !tb <optional: random|oldest|fewest_watched|runoff> | Short form for tie_break
+pub const VOTES: &str = "votes"; // !votes history <optional: id> | Shows the latest closed votes or the details of a closed vote
+pub const VOTE: &str = "vote"; // !vote <optional: id> <add|remove|title> <option|emoji|title> | Adds or removes an option of your open vote or changes its title
+pub const SELECTION_POLICY: &str = "selection_policy"; // !selection_policy <optional: weighting|owner|interest|losses|unreleased|fair|fairness> <optional: uniform|time|on|off|rsvp> | Sets how the movies of random movie votes are selected, or shows how often every owner had a movie in them
+pub const SELECTION_POLICY_SHORT: &str = "sp"; // !sp <optional: weighting|owner|interest|losses|unreleased|fair|fairness> <optional: uniform|time|on|off|rsvp> | Short form for selection_policy
+pub const VETO: &str = "veto"; // !veto <optional: id> <emoji> | Vetoes a movie of the random movie vote or of the vote with the id, which removes the movie from the vote
+pub const VETOES: &str = "vetoes"; // !vetoes | Shows how many vetoes the members have used and have left
+pub const VETO_LIMIT: &str = "veto_limit"; // !veto_limit <optional: number|off> <optional: days> | Sets how many vetoes every member has within the given number of days
+pub const VETO_LIMIT_SHORT: &str = "vl"; // !vl <optional: number|off> <optional: days> | Short form for veto_limit
+pub const SNOOZE: &str = "snooze"; // !snooze <id> <duration|date|off> | Skips your movie in random movie votes for the duration or until the date, while it stays on the watch list
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_export() {
+        assert_eq!(Command::from_str("!export history csv"), Ok(Command::Export(ExportList::History, ExportFormat::Csv)));
+        assert_eq!(Command::from_str("!export wl md"), Ok(Command::Export(ExportList::WatchList, ExportFormat::Markdown)));
+        assert_eq!(Command::from_str("!export history"), Err(ParseCommandError::NotEnoughArgumentsForExport));
+        assert_eq!(Command::from_str("!export history pdf"), Err(ParseCommandError::WrongArgumentsForExport));
+    }
+
+    #[test]
+    fn parses_import_choice() {
+        assert_eq!(Command::from_str("!import"), Ok(Command::Import));
+        assert_eq!(Command::from_str("!import choose 2 1"), Ok(Command::ChooseImportOption(2, 1)));
+        assert_eq!(Command::from_str("!import choose 2"), Err(ParseCommandError::WrongArgumentsForImport));
+    }
+
+    #[test]
+    fn parses_rate() {
+        assert_eq!(Command::from_str("!rate 3 8"), Ok(Command::Rate(3, 8)));
+        assert_eq!(Command::from_str("!rate 3"), Err(ParseCommandError::NotEnoughArgumentsForRate));
+        assert_eq!(Command::from_str("!rate 3 gut"), Err(ParseCommandError::WrongArgumentsForRate));
+    }
+
+    #[test]
+    fn parses_reviews_with_line_breaks() {
+        assert_eq!(
+            Command::from_str("!review 5 Toller Film.\nDas Ende war stark."),
+            Ok(Command::WriteReview(5, None, String::from("Toller Film.\nDas Ende war stark.")))
+        );
+        assert_eq!(
+            Command::from_str("!rv 5 <@!42> Erste Zeile\n\nZweite Zeile"),
+            Ok(Command::WriteReview(5, Some(42), String::from("Erste Zeile\n\nZweite Zeile")))
+        );
+        assert_eq!(Command::from_str("!review 5 delete"), Ok(Command::DeleteReview(5, None)));
+        assert_eq!(Command::from_str("!review 5 <@42>"), Err(ParseCommandError::NotEnoughArgumentsForReview));
+        assert_eq!(Command::from_str("!reviews <@42>"), Ok(Command::ShowReviews(Some(42))));
+    }
+
+    #[test]
+    fn parses_events() {
+        assert_eq!(
+            Command::from_str("!event 20.11.2021 20:00 Horrornacht im Keller"),
+            Ok(Command::CreateEvent(String::from("20.11.2021"), String::from("20:00"), String::from("Horrornacht im Keller")))
+        );
+        assert_eq!(Command::from_str("!event 20.11.2021"), Err(ParseCommandError::NotEnoughArgumentsForEvent));
+        assert_eq!(Command::from_str("!event cancel 2"), Ok(Command::CancelEvent(2)));
+        assert_eq!(Command::from_str("!event reminders"), Ok(Command::ShowEventReminders));
+        assert_eq!(Command::from_str("!event reminders 1d 1h"), Ok(Command::SetEventReminders(vec![24 * 60, 60])));
+        assert_eq!(Command::from_str("!event reminders off"), Ok(Command::SetEventReminders(Vec::new())));
+        assert_eq!(Command::from_str("!event reminders soon"), Err(ParseCommandError::WrongArgumentsForEvent));
+        assert_eq!(Command::from_str("!events"), Ok(Command::ShowEvents));
+    }
+
+    #[test]
+    fn parses_attended() {
+        assert_eq!(Command::from_str("!attended 3"), Ok(Command::AddAttended(3, Vec::new())));
+        assert_eq!(Command::from_str("!ad 3 <@1> <@!2>"), Ok(Command::AddAttended(3, vec![1, 2])));
+        assert_eq!(Command::from_str("!attended 3 remove <@1> <@!2>"), Ok(Command::RemoveAttended(3, vec![1, 2])));
+        assert_eq!(Command::from_str("!attended 3 jemand"), Err(ParseCommandError::WrongArgumentsForAttended));
+        assert_eq!(Command::from_str("!attended"), Err(ParseCommandError::NotEnoughArgumentsForAttended));
+    }
+
+    #[test]
+    fn parses_missed_nights_limit() {
+        assert_eq!(Command::from_str("!missed_nights_limit"), Ok(Command::ShowMissedNightsLimit));
+        assert_eq!(Command::from_str("!mnl 3"), Ok(Command::SetMissedNightsLimit(3)));
+        assert_eq!(Command::from_str("!missed_nights_limit off"), Ok(Command::SetMissedNightsLimit(0)));
+        assert_eq!(Command::from_str("!missed_nights_limit oft"), Err(ParseCommandError::WrongArgumentsForMissedNightsLimit));
+    }
+
+    #[test]
+    fn parses_seen() {
+        assert_eq!(Command::from_str("!seen 3"), Ok(Command::MarkSeen(3, true)));
+        assert_eq!(Command::from_str("!seen 3 remove"), Ok(Command::MarkSeen(3, false)));
+        assert_eq!(Command::from_str("!seen 3 maybe"), Err(ParseCommandError::WrongArgumentsForSeen));
+        assert_eq!(Command::from_str("!seen"), Err(ParseCommandError::NotEnoughArgumentsForSeen));
+    }
+
+    #[test]
+    fn parses_seen_limit() {
+        assert_eq!(Command::from_str("!seen_limit"), Ok(Command::ShowSeenLimit));
+        assert_eq!(Command::from_str("!seen_limit 50%"), Ok(Command::SetSeenLimit(50, None)));
+        assert_eq!(Command::from_str("!seen_limit 50 exclude"), Ok(Command::SetSeenLimit(50, Some(SeenPolicy::Exclude))));
+        assert_eq!(Command::from_str("!seen_limit off"), Ok(Command::SetSeenLimit(0, None)));
+        assert_eq!(Command::from_str("!seen_limit 50 ignore"), Err(ParseCommandError::WrongArgumentsForSeenLimit));
+    }
+
+    #[test]
+    fn parses_rank() {
+        assert_eq!(Command::from_str("!rank 🐼 🌵"), Ok(Command::Rank(None, vec![String::from("🐼"), String::from("🌵")])));
+        assert_eq!(Command::from_str("!rank 4 🐼"), Ok(Command::Rank(Some(4), vec![String::from("🐼")])));
+        assert_eq!(Command::from_str("!rank 🐼 🐼"), Err(ParseCommandError::WrongArgumentsForRank));
+        assert_eq!(Command::from_str("!rank"), Err(ParseCommandError::NotEnoughArgumentsForRank));
+        assert_eq!(Command::from_str("!rank 4"), Err(ParseCommandError::NotEnoughArgumentsForRank));
+    }
+
+    #[test]
+    fn parses_create_vote_settings_before_the_title() {
+        let settings = VoteSettings {
+            mode: crate::voting_behaviour::VoteMode::PickUpTo(2),
+            deadline: Some(crate::voting_behaviour::VoteDeadline::Duration(30)),
+            secret: true,
+        };
+
+        assert_eq!(
+            Command::from_str("!create_vote mode:pick:2 duration:30m ballot:secret Pizza | Salami | Funghi"),
+            Ok(Command::CreateVote(String::from("Pizza"), vec![String::from("Salami"), String::from("Funghi")], settings))
+        );
+        assert_eq!(
+            Command::from_str("!create_vote Pizza | Salami"),
+            Ok(Command::CreateVote(String::from("Pizza"), vec![String::from("Salami")], VoteSettings::default()))
+        );
+        assert_eq!(Command::from_str("!create_vote mode:best Pizza | Salami"), Err(ParseCommandError::WrongArgumentsForCreateVote));
+        assert_eq!(Command::from_str("!create_vote until:25:00 Pizza | Salami"), Err(ParseCommandError::WrongArgumentsForCreateVote));
+    }
+
+    #[test]
+    fn parses_random_movie_vote_arguments_in_any_order() {
+        let settings = VoteSettings {
+            mode: crate::voting_behaviour::VoteMode::Approval,
+            deadline: Some(crate::voting_behaviour::VoteDeadline::Duration(24 * 60)),
+            secret: false,
+        };
+
+        assert_eq!(Command::from_str("!random_movie_vote mode:approval 5 duration:1d"), Ok(Command::RandomMovieVote(Some(5), settings)));
+        assert_eq!(Command::from_str("!random_movie_vote"), Ok(Command::RandomMovieVote(None, VoteSettings::default())));
+        assert_eq!(Command::from_str("!random_movie_vote five"), Err(ParseCommandError::WrongArgumentForRandomMovieVote));
+    }
+
+    #[test]
+    fn parses_ballot() {
+        assert_eq!(Command::from_str("!ballot 🐼 🌵"), Ok(Command::Ballot(None, vec![String::from("🐼"), String::from("🌵")])));
+        assert_eq!(Command::from_str("!ballot 2 🐼"), Ok(Command::Ballot(Some(2), vec![String::from("🐼")])));
+        assert_eq!(Command::from_str("!ballot 🐼 🐼"), Err(ParseCommandError::WrongArgumentsForBallot));
+        assert_eq!(Command::from_str("!ballot"), Err(ParseCommandError::NotEnoughArgumentsForBallot));
+    }
+
+    #[test]
+    fn parses_tie_break() {
+        assert_eq!(Command::from_str("!tie_break"), Ok(Command::ShowTieBreakPolicy));
+        assert_eq!(Command::from_str("!tie_break oldest"), Ok(Command::SetTieBreakPolicy(TieBreakPolicy::OldestMovie)));
+        assert_eq!(Command::from_str("!tie_break Runoff"), Ok(Command::SetTieBreakPolicy(TieBreakPolicy::Runoff)));
+        assert_eq!(Command::from_str("!tie_break coin"), Err(ParseCommandError::WrongArgumentsForTieBreak));
+    }
+
+    #[test]
+    fn parses_vote_history() {
+        assert_eq!(Command::from_str("!votes history"), Ok(Command::ShowVoteHistory));
+        assert_eq!(Command::from_str("!votes History 7"), Ok(Command::ShowArchivedVote(7)));
+        assert_eq!(Command::from_str("!votes history neu"), Err(ParseCommandError::WrongArgumentsForVotes));
+        assert_eq!(Command::from_str("!votes"), Err(ParseCommandError::WrongArgumentsForVotes));
+    }
+
+    #[test]
+    fn parses_close_vote_and_vote_changes() {
+        assert_eq!(Command::from_str("!close_vote"), Ok(Command::CloseVote(None)));
+        assert_eq!(Command::from_str("!close_vote 3"), Ok(Command::CloseVote(Some(3))));
+        assert_eq!(Command::from_str("!close_vote x"), Err(ParseCommandError::WrongArgumentsForCloseVote));
+        assert_eq!(Command::from_str("!vote add Kino am See"), Ok(Command::AddVoteOption(None, String::from("Kino am See"))));
+        assert_eq!(Command::from_str("!vote 2 remove 🐼"), Ok(Command::RemoveVoteOption(Some(2), String::from("🐼"))));
+        assert_eq!(Command::from_str("!vote 2 title Neuer Titel"), Ok(Command::RenameVote(Some(2), String::from("Neuer Titel"))));
+        assert_eq!(Command::from_str("!vote remove 🐼 🌵"), Err(ParseCommandError::WrongArgumentsForVote));
+        assert_eq!(Command::from_str("!vote 2 add"), Err(ParseCommandError::NotEnoughArgumentsForVote));
+    }
+
+    #[test]
+    fn parses_random_movie_vote_dry_run_and_selection_policy() {
+        assert_eq!(Command::from_str("!random_movie_vote dry-run"), Ok(Command::RandomMovieVoteDryRun(None)));
+        assert_eq!(Command::from_str("!rmv dry-run 3"), Ok(Command::RandomMovieVoteDryRun(Some(3))));
+        assert_eq!(Command::from_str("!rmv dry-run 3 mode:ranked"), Err(ParseCommandError::WrongArgumentForRandomMovieVote));
+        assert_eq!(Command::from_str("!selection_policy"), Ok(Command::ShowSelectionPolicy));
+        assert_eq!(Command::from_str("!sp owner on"), Ok(Command::SetSelectionPolicy(SelectionSetting::OnePerOwner(true))));
+        assert_eq!(Command::from_str("!sp owner"), Err(ParseCommandError::WrongArgumentsForSelectionPolicy));
+    }
+
+    #[test]
+    fn parses_fair_share() {
+        assert_eq!(
+            Command::from_str("!selection_policy fair rsvp"),
+            Ok(Command::SetSelectionPolicy(SelectionSetting::FairShare(crate::selection_behaviour::FairShare::RsvpOwners)))
+        );
+        assert_eq!(Command::from_str("!sp fairness"), Ok(Command::ShowOwnerAppearances));
+        assert_eq!(Command::from_str("!sp fair always"), Err(ParseCommandError::WrongArgumentsForSelectionPolicy));
+    }
+
+    #[test]
+    fn parses_veto_commands() {
+        assert_eq!(Command::from_str("!veto 🐼"), Ok(Command::Veto(None, String::from("🐼"))));
+        assert_eq!(Command::from_str("!veto 3 🐼"), Ok(Command::Veto(Some(3), String::from("🐼"))));
+        assert_eq!(Command::from_str("!veto x 🐼"), Err(ParseCommandError::WrongArgumentsForVeto));
+        assert_eq!(Command::from_str("!veto"), Err(ParseCommandError::NotEnoughArgumentsForVeto));
+        assert_eq!(Command::from_str("!vetoes"), Ok(Command::ShowVetoes));
+        assert_eq!(Command::from_str("!veto_limit"), Ok(Command::ShowVetoLimit));
+        assert_eq!(Command::from_str("!vl 2 14"), Ok(Command::SetVetoLimit(2, Some(14))));
+        assert_eq!(Command::from_str("!veto_limit off"), Ok(Command::SetVetoLimit(0, None)));
+        assert_eq!(Command::from_str("!veto_limit 2 zwei"), Err(ParseCommandError::WrongArgumentsForVetoLimit));
+    }
+
+    #[test]
+    fn parses_snooze() {
+        assert_eq!(Command::from_str("!snooze 4 2d"), Ok(Command::Snooze(4, SnoozeEnd::Minutes(2 * 24 * 60))));
+        assert_eq!(Command::from_str("!snooze 4 off"), Ok(Command::Snooze(4, SnoozeEnd::Off)));
+        assert_eq!(Command::from_str("!snooze 4"), Err(ParseCommandError::NotEnoughArgumentsForSnooze));
+        assert_eq!(Command::from_str("!snooze 4 bald"), Err(ParseCommandError::WrongArgumentsForSnooze));
+        assert_eq!(Command::from_str("!snooze alien 2d"), Err(ParseCommandError::WrongArgumentsForSnooze));
+    }
+}
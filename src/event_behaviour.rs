@@ -0,0 +1,495 @@
+use crate::general_behaviour::{current_timestamp, format_duration_minutes, is_user_administrator, timestamp_to_string};
+use crate::send_message;
+use chrono::{DateTime, TimeZone};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const PAST_EVENT_DAYS: i64 = 30;
+
+/**
+ * The answer of a member to the invitation of a movie night
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Rsvp {
+    Yes,
+    Maybe,
+    No,
+}
+
+impl Rsvp {
+    /**
+     * Returns all answers in the order of the reactions under the event message
+     */
+    pub fn all() -> [Rsvp; 3] {
+        [Rsvp::Yes, Rsvp::Maybe, Rsvp::No]
+    }
+
+    pub fn get_emoji(&self) -> &str {
+        match self {
+            Rsvp::Yes => "✅",
+            Rsvp::Maybe => "❔",
+            Rsvp::No => "❌",
+        }
+    }
+
+    pub fn get_description(&self) -> &str {
+        match self {
+            Rsvp::Yes => "Zusagen",
+            Rsvp::Maybe => "Vielleicht",
+            Rsvp::No => "Absagen",
+        }
+    }
+
+    /**
+     * Returns the answer that belongs to the reaction, or None if the emoji is no answer
+     */
+    fn from_emoji(emoji: &discord::model::ReactionEmoji) -> Option<Rsvp> {
+        if let discord::model::ReactionEmoji::Unicode(emoji) = emoji {
+            Rsvp::all().iter().find(|rsvp| rsvp.get_emoji() == emoji).copied()
+        } else {
+            None
+        }
+    }
+}
+
+/**
+ * A scheduled movie night. The event is linked to the random movie vote that decides the movie and to the winner of this vote.
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MovieEvent {
+    pub id: u32,
+    pub title: String,
+    pub date: DateTime<chrono::FixedOffset>,
+    pub creator_id: discord::model::UserId,
+    pub channel_id: discord::model::ChannelId,
+    pub message_id: discord::model::MessageId,
+    pub rsvps: HashMap<u64, Rsvp>, // Keys are the user ids
+    pub sent_reminders: Vec<u32>, // The reminder offsets in minutes that were already sent
+    pub vote_message_id: Option<u64>,
+    pub movie_id: Option<u32>,
+}
+
+impl MovieEvent {
+    /**
+     * Returns the ids of all users that gave the answer, sorted to keep the order stable
+     */
+    pub fn get_user_ids(&self, rsvp: Rsvp) -> Vec<u64> {
+        self.rsvps.iter()
+            .filter(|(_, user_rsvp)| **user_rsvp == rsvp)
+            .map(|(user_id, _)| *user_id)
+            .sorted()
+            .collect()
+    }
+
+    pub fn is_upcoming(&self) -> bool {
+        self.date > current_timestamp()
+    }
+}
+
+/**
+ * Formats the date of an event, e.g. Samstag, 20.11.2021 um 20:00 Uhr
+ */
+pub fn format_event_date(date: &DateTime<chrono::FixedOffset>) -> String {
+    format!("{} um {} Uhr", timestamp_to_string(date, true), date.format("%H:%M"))
+}
+
+/**
+ * Parses a date in the format DD.MM.YYYY and a time in the format HH:MM in the local time zone
+ */
+pub fn parse_event_date(date: &str, time: &str) -> Option<DateTime<chrono::FixedOffset>> {
+    let naive_date = chrono::NaiveDateTime::parse_from_str(format!("{} {}", date, time).as_str(), "%d.%m.%Y %H:%M").ok()?;
+    let local_date = chrono::Local.from_local_datetime(&naive_date).single()?;
+
+    Some(local_date.with_timezone(local_date.offset()))
+}
+
+/**
+ * Builds the description of the event message with the date, the answers of all members and the linked vote and movie
+ */
+fn build_event_embed_description(bot_data: &crate::BotData, event: &MovieEvent) -> String {
+    let mut description = format!("📅 {}\n\n", format_event_date(&event.date));
+
+    for rsvp in Rsvp::all().iter() {
+        let user_ids = event.get_user_ids(*rsvp);
+        description += format!(
+            "{} **{}** ({}): {}\n",
+            rsvp.get_emoji(),
+            rsvp.get_description(),
+            user_ids.len(),
+            if user_ids.is_empty() {
+                String::from("-")
+            } else {
+                user_ids.iter().map(|user_id| format!("<@{}>", user_id)).join(", ")
+            }
+        ).as_str();
+    }
+
+    if let Some(movie) = event.movie_id.and_then(|id| bot_data.watch_list.get(&id).map(|entry| (id, entry))) {
+        description += format!("\n🎬 **Film:** {} (`{:0>4}`)", movie.1.movie.movie_title, movie.0).as_str();
+    } else if event.vote_message_id.is_some() {
+        description += "\n🗳️ Der Film wird gerade abgestimmt.";
+    } else {
+        description += "\n🗳️ Der Film wird mit der nächsten zufälligen Filmabstimmung bestimmt.";
+    }
+
+    description
+}
+
+/**
+ * Edits the event message, so it shows the current answers and the linked vote and movie
+ */
+fn update_event_message(bot_data: &crate::BotData, event: &MovieEvent) {
+    let description = build_event_embed_description(bot_data, event);
+
+    let _ = bot_data.bot.edit_embed(
+        event.channel_id,
+        event.message_id,
+        |embed| embed
+            .title(format!(":calendar: {}", event.title).as_str())
+            .description(description.as_str())
+            .footer(|footer| footer.text(format!("Event-ID: {}", event.id).as_str()))
+            .color(crate::COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Creates a new movie night at the given date and time and sends the event message with the answer reactions
+ */
+pub fn create_event(bot_data: &mut crate::BotData, date: String, time: String, title: String) {
+    let message = bot_data.message.clone().expect("Passing message to create_event function failed.");
+
+    let event_date = match parse_event_date(date.as_str(), time.as_str()) {
+        Some(event_date) => event_date,
+        None => return send_message::event_date_wrong_format_error(bot_data, date, time),
+    };
+
+    if event_date <= current_timestamp() {
+        return send_message::event_date_in_past_error(bot_data);
+    }
+
+    let mut event = MovieEvent {
+        id: bot_data.next_event_id,
+        title: if title.is_empty() { String::from("Filmabend") } else { title },
+        date: event_date,
+        creator_id: message.author.id,
+        channel_id: message.channel_id,
+        message_id: discord::model::MessageId(0),
+        rsvps: HashMap::new(),
+        sent_reminders: Vec::new(),
+        vote_message_id: None,
+        movie_id: None,
+    };
+
+    let description = build_event_embed_description(bot_data, &event);
+
+    match bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed
+            .title(format!(":calendar: {}", event.title).as_str())
+            .description(description.as_str())
+            .footer(|footer| footer.text(format!("Event-ID: {}", event.id).as_str()))
+            .color(crate::COLOR_INFORMATION)
+    ) {
+        Ok(event_message) => {
+            for rsvp in Rsvp::all().iter() {
+                let _ = bot_data.bot.add_reaction(
+                    event_message.channel_id,
+                    event_message.id,
+                    discord::model::ReactionEmoji::Unicode(rsvp.get_emoji().to_string())
+                );
+            }
+
+            event.message_id = event_message.id;
+            bot_data.events.insert(event.id, event);
+            bot_data.next_event_id += 1;
+        },
+        Err(_) => send_message::event_message_failed_to_send_error(bot_data),
+    }
+}
+
+/**
+ * Cancels an event. Only the creator of the event or an administrator can cancel it.
+ */
+pub fn cancel_event(bot_data: &mut crate::BotData, id: u32) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to cancel_event function failed.").author.id;
+
+    let event = match bot_data.events.get(&id) {
+        Some(event) => event.clone(),
+        None => return send_message::event_not_found_error(bot_data, id),
+    };
+
+    if event.creator_id != author_id && !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    for rsvp in Rsvp::all().iter() {
+        let _ = bot_data.bot.delete_reaction(
+            event.channel_id,
+            event.message_id,
+            None,
+            discord::model::ReactionEmoji::Unicode(rsvp.get_emoji().to_string())
+        );
+    }
+
+    let _ = bot_data.bot.edit_embed(
+        event.channel_id,
+        event.message_id,
+        |embed| embed
+            .title(format!(":calendar: {} (abgesagt)", event.title).as_str())
+            .description(format!("~~{}~~\n\nDieser Filmabend wurde abgesagt.", format_event_date(&event.date)).as_str())
+            .footer(|footer| footer.text(format!("Event-ID: {}", event.id).as_str()))
+            .color(crate::COLOR_ERROR)
+    );
+
+    bot_data.events.remove(&id);
+    send_message::event_cancelled_successfully(bot_data, &event);
+}
+
+/**
+ * Sends a list of all upcoming events
+ */
+pub fn show_events(bot_data: &crate::BotData) {
+    let event_lines: Vec<String> = bot_data.events.values()
+        .filter(|event| event.is_upcoming())
+        .sorted_by_key(|event| event.date)
+        .map(|event| format!(
+            "`{}` **{}** | {} | ✅ {} ❔ {}",
+            event.id,
+            event.title,
+            format_event_date(&event.date),
+            event.get_user_ids(Rsvp::Yes).len(),
+            event.get_user_ids(Rsvp::Maybe).len()
+        ))
+        .collect();
+
+    send_message::upcoming_events(bot_data, &event_lines);
+}
+
+/**
+ * Sets the offsets in minutes before an event at which reminders are sent. Only administrators can change them.
+ */
+pub fn set_event_reminders(bot_data: &mut crate::BotData, offsets: Vec<u32>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_event_reminders function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    bot_data.event_reminder_offsets = offsets.into_iter().unique().sorted_by(|first, second| second.cmp(first)).collect();
+    send_message::event_reminders_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current reminder offsets
+ */
+pub fn show_event_reminders(bot_data: &crate::BotData) {
+    send_message::event_reminders_information(bot_data, false);
+}
+
+/**
+ * Stores the answer of a reaction on an event message. If the user answered before, the previous
+ * reaction gets removed. Returns false if the reaction does not belong to an event message.
+ */
+pub fn handle_rsvp_reaction(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction) -> bool {
+    let event = match bot_data.events.values_mut().find(|event| event.message_id == reaction.message_id) {
+        Some(event) => event,
+        None => return false,
+    };
+
+    let rsvp = match Rsvp::from_emoji(&reaction.emoji) {
+        Some(rsvp) => rsvp,
+        None => {
+            let _ = bot_data.bot.delete_reaction(reaction.channel_id, reaction.message_id, Some(reaction.user_id), reaction.emoji.clone());
+            return true;
+        }
+    };
+
+    if let Some(previous_rsvp) = event.rsvps.insert(reaction.user_id.0, rsvp) {
+        if previous_rsvp != rsvp {
+            let _ = bot_data.bot.delete_reaction(
+                reaction.channel_id,
+                reaction.message_id,
+                Some(reaction.user_id),
+                discord::model::ReactionEmoji::Unicode(previous_rsvp.get_emoji().to_string())
+            );
+        }
+    }
+
+    let event = event.clone();
+    update_event_message(bot_data, &event);
+    true
+}
+
+/**
+ * Removes the answer of a user who removed the reaction. Reactions that were removed by the bot
+ * because the user changed the answer are ignored. Returns false if the reaction does not belong to an event message.
+ */
+pub fn handle_rsvp_reaction_removed(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction) -> bool {
+    let event = match bot_data.events.values_mut().find(|event| event.message_id == reaction.message_id) {
+        Some(event) => event,
+        None => return false,
+    };
+
+    let removed_rsvp = Rsvp::from_emoji(&reaction.emoji);
+    if removed_rsvp.is_none() || event.rsvps.get(&reaction.user_id.0).copied() != removed_rsvp {
+        return true;
+    }
+
+    event.rsvps.remove(&reaction.user_id.0);
+    let event = event.clone();
+    update_event_message(bot_data, &event);
+    true
+}
+
+/**
+ * Sends the reminders of all upcoming events that are due. If the bot was offline and several reminders
+ * of one event are due at once, only the latest one is sent. Returns true if a reminder was sent.
+ */
+pub fn send_due_reminders(bot_data: &mut crate::BotData) -> bool {
+    let now = current_timestamp();
+    let mut reminders: Vec<(u32, u32)> = Vec::new();
+
+    for event in bot_data.events.values_mut().filter(|event| event.date > now) {
+        let minutes_left = (event.date - now).num_minutes().max(0) as u32;
+
+        let due_offsets: Vec<u32> = bot_data.event_reminder_offsets.iter()
+            .filter(|offset| **offset >= minutes_left && !event.sent_reminders.contains(offset))
+            .copied()
+            .collect();
+
+        if let Some(smallest_offset) = due_offsets.iter().min() {
+            reminders.push((event.id, *smallest_offset));
+            event.sent_reminders.extend(due_offsets.iter());
+        }
+    }
+
+    for (id, _) in reminders.iter() {
+        if let Some(event) = bot_data.events.get(id) {
+            let minutes_left = (event.date - now).num_minutes().max(0) as u32;
+            send_message::event_reminder(bot_data, event, format_duration_minutes(minutes_left));
+        }
+    }
+
+    !reminders.is_empty()
+}
+
+/**
+ * Removes the events that are over. An event is kept until its movie left the watch list, since its date is used
+ * as watched date of the movie, but at most PAST_EVENT_DAYS after its date. Returns true if an event was removed.
+ */
+pub fn remove_past_events(bot_data: &mut crate::BotData) -> bool {
+    let now = current_timestamp();
+    let event_count = bot_data.events.len();
+    let watch_list = &bot_data.watch_list;
+
+    bot_data.events.retain(|_, event| {
+        let movie_watched = match event.movie_id {
+            Some(id) => !matches!(watch_list.get(&id), Some(entry) if entry.status.is_watch_list_status()),
+            None => false,
+        };
+
+        event.date > now || (!movie_watched && now.signed_duration_since(event.date) < chrono::Duration::days(PAST_EVENT_DAYS))
+    });
+
+    bot_data.events.len() != event_count
+}
+
+/**
+ * Returns the next upcoming event that has no movie yet
+ */
+pub fn get_next_event(bot_data: &crate::BotData) -> Option<&MovieEvent> {
+    bot_data.events.values()
+        .filter(|event| event.is_upcoming() && event.movie_id.is_none())
+        .min_by_key(|event| event.date)
+}
+
+/**
+ * Links a random movie vote to the next upcoming event that has no movie yet
+ */
+pub fn link_vote_to_next_event(bot_data: &mut crate::BotData, vote_message_id: u64) {
+    let next_event_id = get_next_event(bot_data).map(|event| event.id);
+
+    if let Some(event) = next_event_id.and_then(|id| bot_data.events.get_mut(&id)) {
+        event.vote_message_id = Some(vote_message_id);
+        let event = event.clone();
+        update_event_message(bot_data, &event);
+    }
+}
+
+/**
+ * Links the winner of a random movie vote to the event the vote belongs to. Returns true if the vote belongs to an event.
+ */
+pub fn link_winner_to_event(bot_data: &mut crate::BotData, vote_message_id: u64, movie_id: u32) -> bool {
+    let linked_event = bot_data.events.values_mut()
+        .find(|event| event.vote_message_id == Some(vote_message_id));
+
+    if let Some(event) = linked_event {
+        event.movie_id = Some(movie_id);
+        let event = event.clone();
+        update_event_message(bot_data, &event);
+        true
+    } else {
+        false
+    }
+}
+
+/**
+ * Returns the latest event the movie is linked to. The date and the answers of this event are used
+ * as watched date and attendees of the movie.
+ */
+pub fn get_latest_event_of_movie(bot_data: &crate::BotData, movie_id: u32) -> Option<&MovieEvent> {
+    bot_data.events.values()
+        .filter(|event| event.movie_id == Some(movie_id))
+        .max_by_key(|event| event.date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_event(id: u32, days_from_now: i64, movie_id: Option<u32>) -> MovieEvent {
+        MovieEvent {
+            id,
+            title: String::from("Filmabend"),
+            date: current_timestamp() + chrono::Duration::days(days_from_now),
+            creator_id: discord::model::UserId(1),
+            channel_id: discord::model::ChannelId(0),
+            message_id: discord::model::MessageId(0),
+            rsvps: HashMap::new(),
+            sent_reminders: Vec::new(),
+            vote_message_id: None,
+            movie_id,
+        }
+    }
+
+    #[test]
+    fn parses_event_dates() {
+        let date = parse_event_date("20.11.2021", "20:15").map(|date| date.format("%d.%m.%Y %H:%M").to_string());
+
+        assert_eq!(date, Some(String::from("20.11.2021 20:15")));
+        assert!(parse_event_date("2021-11-20", "20:15").is_none());
+        assert!(parse_event_date("31.02.2021", "20:15").is_none());
+        assert!(parse_event_date("20.11.2021", "25:00").is_none());
+    }
+
+    #[test]
+    fn removes_past_events() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.watch_list.insert(1, crate::get_test_watch_list_entry("Alien", 42));
+        let mut watched_entry = crate::get_test_watch_list_entry("Aliens", 42);
+        watched_entry.status = crate::movie_behaviour::MovieStatus::Watched;
+        bot_data.watch_list.insert(2, watched_entry);
+
+        bot_data.events.insert(1, test_event(1, 2, None));
+        bot_data.events.insert(2, test_event(2, -1, Some(1)));
+        bot_data.events.insert(3, test_event(3, -1, Some(2)));
+        bot_data.events.insert(4, test_event(4, -1, Some(3)));
+        bot_data.events.insert(5, test_event(5, -PAST_EVENT_DAYS - 1, None));
+
+        assert!(remove_past_events(&mut bot_data));
+        assert_eq!(bot_data.events.keys().copied().sorted().collect::<Vec<u32>>(), vec![1, 2]);
+        assert!(!remove_past_events(&mut bot_data));
+    }
+}
@@ -0,0 +1,358 @@
+use crate::movie_behaviour::WatchListEntry;
+use crate::send_message;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExportList {
+    WatchList,
+    History,
+}
+
+impl FromStr for ExportList {
+    type Err = ExportErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "watchlist" | "watch_list" | "wl" => Self::WatchList,
+            "history" | "hs" => Self::History,
+            _ => return Err(ExportErr::UnknownList),
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Markdown,
+}
+
+impl ExportFormat {
+    /**
+     * Returns the file extension of the format
+     */
+    pub fn get_file_extension(&self) -> &str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+impl FromStr for ExportFormat {
+    type Err = ExportErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "csv" => Self::Csv,
+            "json" => Self::Json,
+            "md" | "markdown" => Self::Markdown,
+            _ => return Err(ExportErr::UnknownFormat),
+        })
+    }
+}
+
+pub enum ExportErr {
+    UnknownList,
+    UnknownFormat,
+}
+
+/**
+ * One exported movie. Dates are formatted as yyyy-mm-dd
+ */
+#[derive(Serialize)]
+struct ExportEntry {
+    id: u32,
+    title: String,
+    original_title: String,
+    release_year: String,
+    tmdb_id: u64,
+    imdb_id: Option<String>,
+    owner: String,
+    owner_id: u64,
+    status: String,
+    added: String,
+    watched_or_removed: Option<String>,
+}
+
+impl ExportEntry {
+    fn new(id: u32, entry: &WatchListEntry) -> ExportEntry {
+        ExportEntry {
+            id,
+            title: entry.movie.movie_title.clone(),
+            original_title: entry.movie.original_title.clone(),
+            release_year: format!("{}", entry.movie.release_date.format("%Y")),
+            tmdb_id: entry.movie.tmdb_id,
+            imdb_id: entry.movie.imdb_id.clone(),
+            owner: entry.user.clone(),
+            owner_id: entry.user_id.0,
+            status: format!("{:?}", entry.status),
+            added: format!("{}", entry.added_timestamp.format("%Y-%m-%d")),
+            watched_or_removed: entry
+                .watched_or_removed_timestamp
+                .map(|timestamp| format!("{}", timestamp.format("%Y-%m-%d"))),
+        }
+    }
+
+    /**
+     * Returns all values of the entry in the order of the csv and markdown columns
+     */
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            format!("{:0>4}", self.id),
+            self.title.clone(),
+            self.original_title.clone(),
+            self.release_year.clone(),
+            self.tmdb_id.to_string(),
+            self.imdb_id.clone().unwrap_or_default(),
+            self.owner.clone(),
+            self.owner_id.to_string(),
+            self.status.clone(),
+            self.added.clone(),
+            self.watched_or_removed.clone().unwrap_or_default(),
+        ]
+    }
+}
+
+const EXPORT_COLUMNS: [&str; 11] = [
+    "id",
+    "title",
+    "original_title",
+    "release_year",
+    "tmdb_id",
+    "imdb_id",
+    "owner",
+    "owner_id",
+    "status",
+    "added",
+    "watched_or_removed",
+];
+
+/**
+ * Collects the entries of the watch list or the history. The watch list is sorted by id,
+ * the history by the date the movies were watched or removed.
+ */
+fn collect_export_entries(
+    watch_list: &HashMap<u32, WatchListEntry>,
+    list: ExportList,
+) -> Vec<ExportEntry> {
+    let mut entries: Vec<(&u32, &WatchListEntry)> = watch_list
+        .iter()
+        .filter(|(_, entry)| match list {
+            ExportList::WatchList => entry.status.is_watch_list_status(),
+            ExportList::History => entry.status.is_history_status(),
+        })
+        .collect();
+
+    match list {
+        ExportList::WatchList => entries.sort_by_key(|(id, _)| **id),
+        ExportList::History => {
+            entries.sort_by_key(|(id, entry)| (entry.watched_or_removed_timestamp, **id))
+        }
+    }
+
+    entries
+        .iter()
+        .map(|(id, entry)| ExportEntry::new(**id, entry))
+        .collect()
+}
+
+/**
+ * Quotes a csv value if it contains a separator, a quote or a line break
+ */
+fn escape_csv_value(value: &str) -> String {
+    if value.contains(|c| c == ',' || c == '"' || c == '\n' || c == '\r') {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/**
+ * Escapes the pipe character, so the value does not break the markdown table
+ */
+fn escape_markdown_value(value: &str) -> String {
+    value.replace('|', "\\|")
+}
+
+/**
+ * Generates the content of the export file for the given list and format
+ */
+pub fn generate_export(
+    watch_list: &HashMap<u32, WatchListEntry>,
+    list: ExportList,
+    format: ExportFormat,
+) -> Result<String, String> {
+    let entries = collect_export_entries(watch_list, list);
+
+    match format {
+        ExportFormat::Csv => {
+            let mut csv = EXPORT_COLUMNS.join(",") + "\n";
+
+            for entry in entries.iter() {
+                csv += entry
+                    .get_values()
+                    .iter()
+                    .map(|value| escape_csv_value(value))
+                    .collect::<Vec<String>>()
+                    .join(",")
+                    .as_str();
+                csv += "\n";
+            }
+
+            Ok(csv)
+        }
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(&entries).map_err(|error| format!("{}", error))
+        }
+        ExportFormat::Markdown => {
+            let mut markdown = format!(
+                "# {}\n\n| {} |\n|{}\n",
+                match list {
+                    ExportList::WatchList => "Filmliste",
+                    ExportList::History => "Verlauf",
+                },
+                EXPORT_COLUMNS.join(" | "),
+                " --- |".repeat(EXPORT_COLUMNS.len())
+            );
+
+            for entry in entries.iter() {
+                markdown += format!(
+                    "| {} |\n",
+                    entry
+                        .get_values()
+                        .iter()
+                        .map(|value| escape_markdown_value(value))
+                        .collect::<Vec<String>>()
+                        .join(" | ")
+                )
+                .as_str();
+            }
+
+            Ok(markdown)
+        }
+    }
+}
+
+/**
+ * Returns the file name of an export, e.g. watchlist_2021-06-30.csv
+ */
+fn get_export_file_name(list: ExportList, format: ExportFormat) -> String {
+    format!(
+        "{}_{}.{}",
+        match list {
+            ExportList::WatchList => "watchlist",
+            ExportList::History => "history",
+        },
+        crate::general_behaviour::current_timestamp().format("%Y-%m-%d"),
+        format.get_file_extension()
+    )
+}
+
+/**
+ * Exports the watch list or the history and uploads the file to the channel of the message
+ */
+pub fn export_list(bot_data: &crate::BotData, list: ExportList, format: ExportFormat) {
+    let message = bot_data
+        .message
+        .as_ref()
+        .expect("Passing message to export_list function failed.");
+
+    match generate_export(&bot_data.watch_list, list, format) {
+        Ok(content) => {
+            if let Err(error) = bot_data.bot.send_file(
+                message.channel_id,
+                "",
+                content.as_bytes(),
+                get_export_file_name(list, format).as_str(),
+            ) {
+                send_message::export_failed_error(bot_data, format!("{}", error));
+            }
+        }
+        Err(error) => send_message::export_failed_error(bot_data, error),
+    }
+}
+
+/**
+ * Runs the export from the command line without connecting to discord. The arguments are
+ * the list, the format and an optional output file. Without output file the export is printed.
+ */
+pub fn run_command_line_export(arguments: &[String]) {
+    let usage = "Usage: discord_movie_night export <watchlist|history> <csv|json|md> <optional: output file>";
+
+    if arguments.len() < 2 {
+        println!("{}", usage);
+        return;
+    }
+
+    let list = ExportList::from_str(arguments[0].as_str());
+    let format = ExportFormat::from_str(arguments[1].as_str());
+    let (list, format) = match (list, format) {
+        (Ok(list), Ok(format)) => (list, format),
+        _ => {
+            println!("{}", usage);
+            return;
+        }
+    };
+
+    let bot_data = match crate::serde_behaviour::read_bot_data() {
+        Ok(bot_data) => bot_data,
+        Err(error) => {
+            println!("Reading the bot data failed: {}", error);
+            return;
+        }
+    };
+
+    let content = match generate_export(&bot_data.watch_list, list, format) {
+        Ok(content) => content,
+        Err(error) => {
+            println!("Export failed: {}", error);
+            return;
+        }
+    };
+
+    if let Some(output_file) = arguments.get(2) {
+        match std::fs::write(output_file, content) {
+            Ok(_) => println!("Export written to {}", output_file),
+            Err(error) => println!("Writing the export to {} failed: {}", output_file, error),
+        }
+    } else {
+        print!("{}", content);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_csv_values() {
+        assert_eq!(escape_csv_value("Alien"), "Alien");
+        assert_eq!(escape_csv_value("Alien, Aliens"), "\"Alien, Aliens\"");
+        assert_eq!(escape_csv_value("Der \"Pate\""), "\"Der \"\"Pate\"\"\"");
+        assert_eq!(escape_csv_value("Zeile\nZeile"), "\"Zeile\nZeile\"");
+    }
+
+    #[test]
+    fn escapes_markdown_values() {
+        assert_eq!(escape_markdown_value("Alien"), "Alien");
+        assert_eq!(escape_markdown_value("Alien | Aliens"), "Alien \\| Aliens");
+    }
+
+    #[test]
+    fn exports_only_the_entries_of_the_list() {
+        let mut watch_list = HashMap::new();
+        watch_list.insert(2, crate::get_test_watch_list_entry("Alien, der Film", 42));
+        let mut watched_entry = crate::get_test_watch_list_entry("Aliens", 42);
+        watched_entry.status = crate::movie_behaviour::MovieStatus::Watched;
+        watch_list.insert(1, watched_entry);
+
+        let csv = generate_export(&watch_list, ExportList::WatchList, ExportFormat::Csv).ok();
+        let expected = EXPORT_COLUMNS.join(",")
+            + "\n0002,\"Alien, der Film\",\"Alien, der Film\",2000,0,,Tester,42,NotWatched,2021-01-01,\n";
+        assert_eq!(csv, Some(expected));
+    }
+}
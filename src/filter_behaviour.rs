@@ -0,0 +1,306 @@
+use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+
+const FILTER_OPERATORS: [char; 4] = [':', '<', '>', '='];
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MovieFilter {
+    Genre(String),
+    Runtime(/*min*/ Option<u32>, /*max*/ Option<u32>),
+    Year(/*min*/ Option<u32>, /*max*/ Option<u32>),
+    Language(String),
+    UserId(u64),
+    UserName(String),
+    Status(MovieStatus),
+}
+
+pub enum MovieFilterErr {
+    UnknownFilter,
+    WrongValue,
+}
+
+impl MovieFilter {
+    /**
+     * Returns true if the given argument looks like a filter expression (e.g. genre:horror or runtime<100)
+     */
+    pub fn is_filter_expression(argument: &str) -> bool {
+        argument.contains(&FILTER_OPERATORS[..])
+    }
+
+    /**
+     * Returns true if the watch list entry fulfills the filter
+     */
+    pub fn matches(&self, entry: &WatchListEntry) -> bool {
+        match self {
+            MovieFilter::Genre(genre) => get_genre_names(entry)
+                .iter()
+                .any(|name| name.to_lowercase().contains(genre.as_str())),
+            MovieFilter::Runtime(min, max) => is_in_range(entry.movie.runtime, min, max),
+            MovieFilter::Year(min, max) => {
+                is_in_range(entry.movie.release_date.year() as u32, min, max)
+            }
+            MovieFilter::Language(language) => {
+                entry.movie.original_language.to_lowercase() == *language
+            }
+            MovieFilter::UserId(user_id) => entry.user_id.0 == *user_id,
+            MovieFilter::UserName(user_name) => entry.user.to_lowercase() == *user_name,
+            MovieFilter::Status(status) => entry.status == *status,
+        }
+    }
+}
+
+impl FromStr for MovieFilter {
+    type Err = MovieFilterErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Split the expression into key, operator and value, e.g. runtime, <=, 100
+        let operator_index = s.find(&FILTER_OPERATORS[..]).ok_or(MovieFilterErr::UnknownFilter)?;
+        let (key, remainder) = s.split_at(operator_index);
+        let operator = if remainder.starts_with("<=") || remainder.starts_with(">=") {
+            &remainder[..2]
+        } else {
+            &remainder[..1]
+        };
+        let value = remainder[operator.len()..].trim().to_lowercase();
+
+        if value.is_empty() {
+            return Err(MovieFilterErr::WrongValue);
+        }
+
+        let is_equality = operator == ":" || operator == "=";
+
+        Ok(match key.to_lowercase().as_str() {
+            "genre" if is_equality => Self::Genre(value),
+            "lang" | "language" if is_equality => Self::Language(value),
+            "status" if is_equality => match MovieStatus::from_str(value.as_str()) {
+                Ok(status) => Self::Status(status),
+                Err(_) => return Err(MovieFilterErr::WrongValue),
+            },
+            "user" if is_equality => {
+                // A mentioned user looks like <@123> or <@!123>
+                if let Some(remainder) = value.strip_prefix("<@") {
+                    let remainder = remainder.trim_start_matches('!');
+                    if let Some(user_id) = remainder.strip_suffix(">") {
+                        if let Ok(user_id) = user_id.parse::<u64>() {
+                            return Ok(Self::UserId(user_id));
+                        }
+                    }
+
+                    return Err(MovieFilterErr::WrongValue);
+                }
+
+                Self::UserName(value.trim_start_matches('@').to_string())
+            }
+            "runtime" => {
+                let (min, max) = parse_range(operator, value.as_str())?;
+                Self::Runtime(min, max)
+            }
+            "year" => {
+                let (min, max) = parse_range(operator, value.as_str())?;
+                Self::Year(min, max)
+            }
+            _ => return Err(MovieFilterErr::UnknownFilter),
+        })
+    }
+}
+
+impl fmt::Display for MovieFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MovieFilter::Genre(genre) => write!(f, "genre:{}", genre),
+            MovieFilter::Runtime(min, max) => write!(f, "{}", format_range("runtime", min, max)),
+            MovieFilter::Year(min, max) => write!(f, "{}", format_range("year", min, max)),
+            MovieFilter::Language(language) => write!(f, "lang:{}", language.to_uppercase()),
+            MovieFilter::UserId(user_id) => write!(f, "user:<@{}>", user_id),
+            MovieFilter::UserName(user_name) => write!(f, "user:{}", user_name),
+            MovieFilter::Status(status) => write!(f, "status:{:?}", status),
+        }
+    }
+}
+
+/**
+ * Parses the value of a numeric filter into an inclusive range. Possible values are
+ * <100, <=100, >90, >=90, :1990-1999, :1995, :1990- and :-1999
+ */
+fn parse_range(operator: &str, value: &str) -> Result<(Option<u32>, Option<u32>), MovieFilterErr> {
+    let parse_number = |number: &str| -> Result<u32, MovieFilterErr> {
+        number.trim().parse::<u32>().map_err(|_| MovieFilterErr::WrongValue)
+    };
+
+    match operator {
+        "<" => Ok((None, Some(parse_number(value)?.saturating_sub(1)))),
+        "<=" => Ok((None, Some(parse_number(value)?))),
+        ">" => Ok((Some(parse_number(value)?.saturating_add(1)), None)),
+        ">=" => Ok((Some(parse_number(value)?), None)),
+        _ => {
+            if let Some((min, max)) = value.split_once('-') {
+                let min = if min.trim().is_empty() { None } else { Some(parse_number(min)?) };
+                let max = if max.trim().is_empty() { None } else { Some(parse_number(max)?) };
+
+                if min.is_none() && max.is_none() {
+                    return Err(MovieFilterErr::WrongValue);
+                }
+
+                Ok((min, max))
+            } else {
+                let number = parse_number(value)?;
+                Ok((Some(number), Some(number)))
+            }
+        }
+    }
+}
+
+/**
+ * Formats an inclusive range back into the filter syntax
+ */
+fn format_range(key: &str, min: &Option<u32>, max: &Option<u32>) -> String {
+    match (min, max) {
+        (Some(min), Some(max)) if min == max => format!("{}:{}", key, min),
+        (Some(min), Some(max)) => format!("{}:{}-{}", key, min, max),
+        (Some(min), None) => format!("{}>={}", key, min),
+        (None, Some(max)) => format!("{}<={}", key, max),
+        (None, None) => format!("{}:-", key),
+    }
+}
+
+/**
+ * Returns true if the value lies inside the inclusive range. Missing bounds are ignored.
+ */
+fn is_in_range(value: u32, min: &Option<u32>, max: &Option<u32>) -> bool {
+    min.map_or(true, |min| value >= min) && max.map_or(true, |max| value <= max)
+}
+
+/**
+ * Returns the names of all genres of the movie. Movies that were added before the genres were stored
+ * separately only have the formatted genre string, so that one is split up instead.
+ */
+pub fn get_genre_names(entry: &WatchListEntry) -> Vec<String> {
+    if entry.movie.genre_list.is_empty() {
+        entry
+            .movie
+            .genres
+            .split(", ")
+            .map(|genre| genre.to_string())
+            .collect()
+    } else {
+        entry.movie.genre_list.clone()
+    }
+}
+
+/**
+ * Returns true if the entry fulfills all given filters
+ */
+pub fn matches_all_filters(entry: &WatchListEntry, filters: &Vec<MovieFilter>) -> bool {
+    filters.iter().all(|filter| filter.matches(entry))
+}
+
+/**
+ * Formats the filters as comma separated list for messages
+ */
+pub fn describe_filters(filters: &Vec<MovieFilter>) -> String {
+    filters
+        .iter()
+        .map(|filter| match filter {
+            // Mentions are not rendered inside of code blocks
+            MovieFilter::UserId(_) => filter.to_string(),
+            _ => format!("`{}`", filter),
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/**
+ * Generates the first line of a watch list or history page, which contains the number of movies
+ * and the filters that were applied
+ */
+pub fn generate_movie_count_string(movie_count: usize, filters: &Vec<MovieFilter>, is_history: bool) -> String {
+    let location = if is_history { "im Verlauf" } else { "auf der Liste" };
+
+    if filters.is_empty() {
+        if movie_count == 1 {
+            format!("Es ist zur Zeit **{}** Film {}\n\n", movie_count, location)
+        } else {
+            format!("Es sind zur Zeit **{}** Filme {}\n\n", movie_count, location)
+        }
+    } else if movie_count == 1 {
+        format!("Es ist zur Zeit **{}** Film {}, der den Filtern {} entspricht\n\n", movie_count, location, describe_filters(filters))
+    } else {
+        format!("Es sind zur Zeit **{}** Filme {}, die den Filtern {} entsprechen\n\n", movie_count, location, describe_filters(filters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(expression: &str) -> Option<MovieFilter> {
+        MovieFilter::from_str(expression).ok()
+    }
+
+    #[test]
+    fn parses_text_filters() {
+        assert_eq!(parse("genre:Horror"), Some(MovieFilter::Genre(String::from("horror"))));
+        assert_eq!(parse("lang=EN"), Some(MovieFilter::Language(String::from("en"))));
+        assert_eq!(parse("status:Watched"), Some(MovieFilter::Status(MovieStatus::Watched)));
+        assert_eq!(parse("user:<@!42>"), Some(MovieFilter::UserId(42)));
+        assert_eq!(parse("user:@Tester"), Some(MovieFilter::UserName(String::from("tester"))));
+    }
+
+    #[test]
+    fn parses_ranges() {
+        assert_eq!(parse("runtime<100"), Some(MovieFilter::Runtime(None, Some(99))));
+        assert_eq!(parse("runtime<=100"), Some(MovieFilter::Runtime(None, Some(100))));
+        assert_eq!(parse("runtime>=90"), Some(MovieFilter::Runtime(Some(90), None)));
+        assert_eq!(parse("year:1990-1999"), Some(MovieFilter::Year(Some(1990), Some(1999))));
+        assert_eq!(parse("year:1995"), Some(MovieFilter::Year(Some(1995), Some(1995))));
+        assert_eq!(parse("year:-1999"), Some(MovieFilter::Year(None, Some(1999))));
+    }
+
+    #[test]
+    fn rejects_invalid_filters() {
+        assert!(matches!(MovieFilter::from_str("director:nolan"), Err(MovieFilterErr::UnknownFilter)));
+        assert!(matches!(MovieFilter::from_str("genre<horror"), Err(MovieFilterErr::UnknownFilter)));
+        assert!(matches!(MovieFilter::from_str("genre:"), Err(MovieFilterErr::WrongValue)));
+        assert!(matches!(MovieFilter::from_str("runtime<long"), Err(MovieFilterErr::WrongValue)));
+        assert!(matches!(MovieFilter::from_str("year:-"), Err(MovieFilterErr::WrongValue)));
+        assert!(matches!(MovieFilter::from_str("status:gone"), Err(MovieFilterErr::WrongValue)));
+        assert!(matches!(MovieFilter::from_str("user:<@jemand>"), Err(MovieFilterErr::WrongValue)));
+    }
+
+    #[test]
+    fn formats_filters_the_way_they_are_written() {
+        for expression in ["genre:horror", "runtime<=99", "year:1990-1999", "year>=1990", "lang:EN", "user:<@42>"].iter() {
+            assert_eq!(parse(expression).map(|filter| filter.to_string()), Some(expression.to_string()));
+        }
+    }
+
+    #[test]
+    fn matches_entries() {
+        let mut entry = crate::get_test_watch_list_entry("Alien", 42);
+        entry.movie.genre_list = vec![String::from("Horror"), String::from("Science Fiction")];
+        entry.movie.runtime = 117;
+
+        assert!(MovieFilter::Genre(String::from("science")).matches(&entry));
+        assert!(!MovieFilter::Genre(String::from("drama")).matches(&entry));
+        assert!(MovieFilter::Runtime(Some(90), Some(120)).matches(&entry));
+        assert!(!MovieFilter::Runtime(None, Some(116)).matches(&entry));
+        assert!(MovieFilter::Year(Some(2000), None).matches(&entry));
+        assert!(!MovieFilter::Year(None, Some(1999)).matches(&entry));
+        assert!(MovieFilter::Language(String::from("en")).matches(&entry));
+        assert!(MovieFilter::UserId(42).matches(&entry));
+        assert!(MovieFilter::UserName(String::from("tester")).matches(&entry));
+        assert!(!MovieFilter::Status(MovieStatus::Watched).matches(&entry));
+    }
+
+    #[test]
+    fn matches_the_formatted_genres_of_old_entries() {
+        let mut entry = crate::get_test_watch_list_entry("Alien", 42);
+        entry.movie.genres = String::from("Horror, Science Fiction");
+
+        assert!(MovieFilter::Genre(String::from("horror")).matches(&entry));
+        assert!(matches_all_filters(&entry, &vec![MovieFilter::Genre(String::from("fiction")), MovieFilter::UserId(42)]));
+        assert!(!matches_all_filters(&entry, &vec![MovieFilter::Genre(String::from("horror")), MovieFilter::UserId(7)]));
+    }
+}
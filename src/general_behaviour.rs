@@ -1,4 +1,4 @@
-use crate::COLOR_INFORMATION;
+use crate::{COLOR_BOT, COLOR_INFORMATION};
 use regex::Regex;
 
 use serde::{Serialize, Deserialize};
@@ -7,9 +7,14 @@ use serde::{Serialize, Deserialize};
 pub enum WaitingForReaction {
     AddMovie(discord::model::Message, crate::movie_behaviour::WatchListEntry),
     Vote(discord::model::Message),
-    AddMovieToWatched(discord::model::Message, crate::movie_behaviour::Movie),
+    AddMovieToWatched(discord::model::Message, crate::movie_behaviour::Movie, /*participants:*/ Vec<u64>),
     WatchListPagination(discord::model::Message, crate::movie_behaviour::SortedMovieList, /*curr_page:*/ usize),
     HistoryPagination(discord::model::Message, crate::movie_behaviour::SortedMovieList, /*curr_page:*/ usize),
+    Import(discord::model::Message, crate::import_behaviour::PendingImport),
+    Rating(discord::model::Message, /*watch_list_id:*/ u32),
+    TextPagination(discord::model::Message, /*title:*/ String, /*pages:*/ Vec<String>, /*curr_page:*/ usize),
+    Seen(discord::model::Message, /*watch_list_id:*/ u32),
+    AddWatchedMovie(discord::model::Message, crate::movie_behaviour::WatchListEntry, /*vote_message_id:*/ u64),
 }
 
 /**
@@ -37,6 +42,14 @@ pub fn timestamp_to_string(timestamp: &chrono::DateTime<chrono::FixedOffset>, in
     }
 }
 
+/**
+ * Returns the current local time as timestamp with a fixed offset, like the timestamps of discord messages
+ */
+pub fn current_timestamp() -> chrono::DateTime<chrono::FixedOffset> {
+    let now = chrono::Local::now();
+    now.with_timezone(now.offset())
+}
+
 /**
  * Takes a movie release date in the format yyyy-mm-dd and parses it to the chrono datetime format
  */
@@ -218,10 +231,16 @@ fn is_role_administrator(role: &discord::model::Role) -> bool {
 pub fn remove_all_reactions_on_all_waiting_for_reaction_messages(bot_data: &crate::BotData) {
     for waiting in bot_data.wait_for_reaction.iter() {
         match waiting {
-            WaitingForReaction::AddMovie(message, _) | WaitingForReaction::AddMovieToWatched(message, _) => 
+            WaitingForReaction::AddMovie(message, _) | WaitingForReaction::AddMovieToWatched(message, _, _) 
+            | WaitingForReaction::Import(message, _) | WaitingForReaction::AddWatchedMovie(message, _, _) => 
                 remove_reactions_on_message(bot_data, &message, vec!["✅", "❎"]),
-            WaitingForReaction::HistoryPagination(message, _, _) | WaitingForReaction::WatchListPagination(message, _, _) =>
+            WaitingForReaction::HistoryPagination(message, _, _) | WaitingForReaction::WatchListPagination(message, _, _) 
+            | WaitingForReaction::TextPagination(message, _, _, _) =>
                 remove_reactions_on_message(bot_data, &message, vec!["⬅️", "➡️"]),
+            WaitingForReaction::Rating(message, _) =>
+                remove_reactions_on_message(bot_data, &message, crate::rating_behaviour::RATING_EMOJIS.to_vec()),
+            WaitingForReaction::Seen(message, _) =>
+                remove_reactions_on_message(bot_data, message, vec![crate::seen_behaviour::SEEN_EMOJI, crate::seen_behaviour::INTEREST_EMOJI]),
             WaitingForReaction::Vote(message) => {
                 let vote = bot_data.votes.get(&message.id.0);
 
@@ -250,4 +269,151 @@ pub fn remove_reactions_on_message(bot_data: &crate::BotData, message: &discord:
             discord::model::ReactionEmoji::Unicode(emoji.to_string())
         );
     }
-}
\ No newline at end of file
+}
+
+/**
+ * Joins the entries to pages with the given number of entries per page
+ */
+pub fn split_into_pages(entries: &Vec<String>, entries_per_page: usize) -> Vec<String> {
+    entries
+        .chunks(entries_per_page)
+        .map(|chunk| chunk.join("\n"))
+        .collect()
+}
+
+/**
+ * Sends the first page of a paginated message. If there is more than one page, the arrow reactions are added
+ * and the message waits for reactions to show the other pages.
+ */
+pub fn send_paginated_message(bot_data: &mut crate::BotData, channel_id: discord::model::ChannelId, title: String, pages: Vec<String>) {
+    if pages.is_empty() {
+        return;
+    }
+
+    if let Ok(message) = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title(title.as_str())
+            .description(pages[0].as_str())
+            .color(COLOR_BOT)
+            .footer(|footer| footer.text(format!("Seite {}/{}", 1, pages.len()).as_str()))
+    ) {
+        if pages.len() > 1 {
+            let _ = bot_data.bot.add_reaction(message.channel_id, message.id, discord::model::ReactionEmoji::Unicode("⬅️".to_string()));
+            let _ = bot_data.bot.add_reaction(message.channel_id, message.id, discord::model::ReactionEmoji::Unicode("➡️".to_string()));
+
+            bot_data.wait_for_reaction.push(WaitingForReaction::TextPagination(message, title, pages, 1));
+        }
+    }
+}
+
+/**
+ * Deletes the users reaction and shows the previous or next page of a paginated message.
+ * Returns the new current page.
+ */
+pub fn handle_text_pagination_reaction(
+    bot_data: &crate::BotData,
+    reaction: &discord::model::Reaction,
+    title: &str,
+    pages: &Vec<String>,
+    previous_page: usize
+) -> usize {
+    let _ = bot_data.bot.delete_reaction(reaction.channel_id, reaction.message_id, Some(reaction.user_id), reaction.emoji.clone());
+
+    let new_page = if reaction_emoji_equals(&reaction.emoji, "⬅️".to_string()) && previous_page > 1 {
+        previous_page - 1
+    } else if reaction_emoji_equals(&reaction.emoji, "➡️".to_string()) && previous_page < pages.len() {
+        previous_page + 1
+    } else {
+        return previous_page;
+    };
+
+    let _ = bot_data.bot.edit_embed(
+        reaction.channel_id,
+        reaction.message_id,
+        |embed| embed
+            .title(title)
+            .description(pages[new_page - 1].as_str())
+            .color(COLOR_BOT)
+            .footer(|footer| footer.text(format!("Seite {}/{}", new_page, pages.len()).as_str()))
+    );
+
+    new_page
+}
+
+/**
+ * Parses a duration like 30m, 2h or 1d to minutes. A number without unit is interpreted as minutes.
+ */
+pub fn parse_duration_minutes(duration: &str) -> Option<u32> {
+    let duration = duration.trim().to_lowercase();
+    let number_end = duration.find(|c: char| !c.is_ascii_digit()).unwrap_or(duration.len());
+    let (number, unit) = duration.split_at(number_end);
+    let number = number.parse::<u32>().ok()?;
+
+    let factor = match unit {
+        "" | "m" | "min" => 1,
+        "h" | "std" => 60,
+        "d" | "t" => 24 * 60,
+        _ => return None,
+    };
+
+    number.checked_mul(factor)
+}
+
+/**
+ * Formats a duration in minutes in german, e.g. 1 Tag, 2 Std. or 1 Std. 30 Min.
+ */
+pub fn format_duration_minutes(minutes: u32) -> String {
+    let days = minutes / (24 * 60);
+    let hours = (minutes % (24 * 60)) / 60;
+    let minutes = minutes % 60;
+
+    let mut parts: Vec<String> = Vec::new();
+    if days > 0 {
+        parts.push(format!("{} {}", days, if days == 1 { "Tag" } else { "Tage" }));
+    }
+    if hours > 0 {
+        parts.push(format!("{} Std.", hours));
+    }
+    if minutes > 0 || parts.is_empty() {
+        parts.push(format!("{} Min.", minutes));
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_minutes_reads_units() {
+        assert_eq!(parse_duration_minutes("45"), Some(45));
+        assert_eq!(parse_duration_minutes("30m"), Some(30));
+        assert_eq!(parse_duration_minutes("30min"), Some(30));
+        assert_eq!(parse_duration_minutes("2h"), Some(120));
+        assert_eq!(parse_duration_minutes("2Std"), Some(120));
+        assert_eq!(parse_duration_minutes("1d"), Some(24 * 60));
+        assert_eq!(parse_duration_minutes("3T"), Some(3 * 24 * 60));
+        assert_eq!(parse_duration_minutes(" 10m "), Some(10));
+    }
+
+    #[test]
+    fn parse_duration_minutes_rejects_invalid_durations() {
+        assert_eq!(parse_duration_minutes(""), None);
+        assert_eq!(parse_duration_minutes("h"), None);
+        assert_eq!(parse_duration_minutes("2w"), None);
+        assert_eq!(parse_duration_minutes("-5m"), None);
+        assert_eq!(parse_duration_minutes("1.5h"), None);
+        assert_eq!(parse_duration_minutes("4294967295d"), None);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration_minutes(0), "0 Min.");
+        assert_eq!(format_duration_minutes(90), "1 Std. 30 Min.");
+        assert_eq!(format_duration_minutes(24 * 60), "1 Tag");
+        assert_eq!(format_duration_minutes(2 * 24 * 60 + 60), "2 Tage 1 Std.");
+    }
+}
@@ -21,23 +21,49 @@ pub fn show_help(bot_data: &crate::BotData) {
     **Filme**
     `add_movie`
     `count`
+    `export`
     `history`
+    `import`
     `movie_limit`
+    `profile`
+    `rate`
+    `refresh`
     `remove_movie`
+    `review`
+    `reviews`
     `search_movie`
+    `seen`
+    `seen_limit`
     `status`
     `show_movie`
+    `snooze`
+    `statistics`
     `unavailable`
     `watched`
     `watch_list`
     
     **Abstimmungen**
+    `ballot`
     `close_movie_vote`
     `close_vote`
     `create_vote`
     `movie_vote_limit`
     `random_movie_vote`
-    `send_vote`";
+    `rank`
+    `selection_policy`
+    `send_vote`
+    `tie_break`
+    `veto`
+    `veto_limit`
+    `vetoes`
+    `vote`
+    `votes`
+    
+    **Filmabende**
+    `attended`
+    `event`
+    `events`
+    `missed_nights_limit`";
 
     let _ = bot_data.bot.send_embed(
         message.channel_id,
@@ -162,15 +188,29 @@ pub fn show_help_watchlist(bot_data: &crate::BotData) {
     "Zeigt die Filmliste an.
     Mit dem Sortierparameter `id` wird die Liste nach ID sortiert.
     Mit dem Sortierparameter `user` wird die Liste nach Nutzer sortiert, anschließend nach ID.
-    Wird der Parameter weggelassen wird die Liste in beliebiger Reihenfolge angezeigt.
+    Außerdem kann nach `title`, `year`, `runtime`, `added` (Hinzufügedatum), `rating` (TMDb-Bewertung) und `lang` (Originalsprache) sortiert werden.
+    Mit `asc` oder `desc` wird aufsteigend bzw. absteigend sortiert. Nach Bewertung wird standardmäßig absteigend sortiert, sonst aufsteigend.
+    Wird der Parameter weggelassen wird die Liste nach ID sortiert angezeigt.
+    Zusätzlich können beliebig viele Filter angegeben werden. Es werden nur Filme angezeigt, die alle Filter erfüllen.
+    
+    **Filter**
+    `genre:<Genre>` Filme mit diesem Genre
+    `runtime<100`, `runtime>90`, `runtime:90-120` Filme mit dieser Laufzeit in Minuten
+    `year:1990-1999`, `year:1995`, `year>2010` Filme aus diesen Jahren
+    `lang:<Sprache>` Filme mit dieser Originalsprache (z.B. EN)
+    `user:@Nutzer` Filme, die von diesem Nutzer hinzugefügt wurden
+    `status:<Status>` Filme mit diesem Status
     
     **Nutzung**
-    !watch_list <Optional: Sortierung>
+    !watch_list <Optional: Sortierung> <Optional: asc|desc> <Optional: Filter>
     
     **Beispiel**
     !watch_list
     !watch_list user
     !watch_list id
+    !watch_list runtime desc
+    !watch_list genre:horror runtime<100
+    !watch_list user lang:EN year:1990-1999
     
     **Aliase**
     `watch_list`, `wl`";
@@ -217,15 +257,21 @@ pub fn show_help_history(bot_data: &crate::BotData) {
     "Zeigt einen Verlauf aller bereits geschauten Filme an, sowie Filme die den Status *gelöscht* haben.
     Mit dem Sortierparameter `date` wird die Liste nach Datum sortiert angezeigt.
     Mit dem Sortierparameter `user` wird die Liste nach Nutzer sortiert, anschließend nach Datum.
-    Wird der Parameter weggelassen wird die Liste nach Datum sortiert angezeigt.
+    Außerdem kann wie bei `watch_list` nach `id`, `title`, `year`, `runtime`, `added`, `rating` und `lang` sortiert werden.
+    Mit `asc` oder `desc` wird aufsteigend bzw. absteigend sortiert. Nach Datum und Bewertung wird standardmäßig absteigend sortiert, sonst aufsteigend.
+    Wird der Parameter weggelassen wird die Liste nach Datum sortiert angezeigt, die zuletzt geschauten Filme zuerst.
+    Zusätzlich können die gleichen Filter wie bei `watch_list` angegeben werden (z.B. `genre:horror` oder `status:removed`).
     
     **Nutzung**
-    !history <Optional: Sortierung>
+    !history <Optional: Sortierung> <Optional: asc|desc> <Optional: Filter>
     
     **Beispiel**
     !history
-    !history date
+    !history date asc
     !history user
+    !history rating
+    !history status:watched year:2000-2009
+    !history user:@Nutzer
     
     **Aliase**
     `history`, `hs`";
@@ -393,12 +439,33 @@ pub fn show_help_create_vote(bot_data: &crate::BotData) {
     "Erstellt eine neue Abstimmung, die sowohl Filme, als auch generelle Optionen enthalten kann. 
     Leerzeichen am Anfang und Ende der einzelnen Optionen werden ignoriert.
     
+    Vor dem Titel kann der Modus der Abstimmung angegeben werden. Bei `mode:single` (Standard) wählt jedes Mitglied eine Option.
+    Bei `mode:ranked` bringt jedes Mitglied die Optionen in eine Rangfolge, indem es nacheinander reagiert oder den Befehl `rank`
+    nutzt. Der Gewinner wird dann per Stichwahl (Instant Runoff) ermittelt.
+    Bei `mode:approval` kann jedes Mitglied beliebig viele Optionen wählen, bei `mode:pick:<Zahl>` bis zu so viele Optionen wie
+    angegeben. Erneutes Reagieren auf eine gewählte Option entfernt die Stimme wieder.
+    
+    Mit `duration:<Dauer>` (z.B. `duration:2h`) oder `until:<HH:MM>` (z.B. `until:20:00`) wird die Abstimmung automatisch
+    geschlossen. Kurz vorher erhalten alle aktiven Mitglieder, die noch nicht abgestimmt haben, eine Erinnerung per Direktnachricht.
+    
+    Bei `ballot:secret` zeigt die Abstimmung nur die Anzahl der abgegebenen Stimmen. Die Ergebnisse werden erst beim Schließen
+    gezeigt. Abgestimmt wird per Reaktion oder geheim per Direktnachricht mit dem Befehl `ballot`.
+    
+    Filme von der Filmliste werden mit `id:<ID>` oder `t:<Titel>` angegeben. Filme, die nicht auf der Filmliste stehen, können mit
+    `tmdb:<TMDb ID oder Link>` oder `imdb:<IMDb ID oder Link>` angegeben werden. Gewinnt ein solcher Film, bietet der Bot an,
+    ihn direkt als geschaut zur Filmliste hinzuzufügen.
+    
     **Nutzung**
-    !create_vote <Titel>|<Liste von Optionen getrennt durch '|'>
+    !create_vote <Optional: mode:single|mode:ranked|mode:approval|mode:pick:<Zahl>> <Optional: duration:<Dauer>|until:<HH:MM>> <Optional: ballot:secret> <Titel>|<Liste von Optionen getrennt durch '|'>
     
     **Beispiel**
     !create_vote Das hier ist eine Abstimmung|Option 1|Option 2
     !create_vote Test|Option 1 |  Option 2   | Option 3
+    !create_vote mode:ranked Bester Film|id:12|id:15|id:20
+    !create_vote mode:pick:2 Snacks|Popcorn|Chips|Nachos
+    !create_vote duration:30m Pizza oder Burger?|Pizza|Burger
+    !create_vote ballot:secret Bester Film|id:12|id:15
+    !create_vote Filmabend|id:12|tmdb:9806|imdb:tt0816692
     
     **Aliase**
     `create_vote`, `cv`";
@@ -419,14 +486,16 @@ pub fn show_help_send_vote(bot_data: &crate::BotData) {
     let help_str =
     "Sendet deine bestehende Abstimmung erneut. Wenn der Parameter leer gelassen wird, wird deine eigene
     Abstimmung erneut gesendet, sofern du eine besitzt. Wenn du einen Nutzer mit dem @-Zeichen angibst, wird
-    die Abstimmung des angegebenen Users erneut gesendet, sofern dieser eine besitzt.
+    die Abstimmung des angegebenen Users erneut gesendet, sofern dieser eine besitzt. Mit einer ID wird die
+    Abstimmung mit dieser ID erneut gesendet. Die ID steht unter jeder Abstimmung. Wer mehrere Abstimmungen besitzt, muss die ID angeben.
     
     **Nutzung**
-    !send_vote <Optional: @AndererUser>
+    !send_vote <Optional: @AndererUser|ID>
     
     **Beispiel**
     !send_vote
     !send_vote @J4YB3
+    !send_vote 12
     
     **Aliase**
     `send_vote`, `sv`";
@@ -445,13 +514,15 @@ pub fn show_help_close_vote(bot_data: &crate::BotData) {
     let message = bot_data.message.as_ref().expect("Passing message to show_help_close_vote function failed.");
 
     let help_str =
-    "Beendet deine eigene Abstimmung, sofern du eine hast.
+    "Beendet deine eigene Abstimmung, sofern du eine hast. Wenn du mehrere Abstimmungen besitzt, gib die ID der
+    Abstimmung an. Die ID steht unter jeder Abstimmung. Administratoren können jede Abstimmung über ihre ID beenden.
     
     **Nutzung**
-    !close_vote
+    !close_vote <Optional: ID>
     
     **Beispiel**
     !close_vote
+    !close_vote 12
     
     **Aliase**
     `close_vote`, `xv`";
@@ -529,14 +600,23 @@ pub fn show_help_random_movie_vote(bot_data: &crate::BotData) {
     
     Wenn eine positive Zahl als Parameter 
     angegeben wird, werden so viele Filme wie angegeben zur Abstimmung ausgewählt. Ansonsten wird das gesetzte Limit
-    benutzt.
+    benutzt. Mit `mode:` kann der Modus der Abstimmung festgelegt werden, mit `duration:` oder `until:` wann sie automatisch
+    geschlossen wird und mit `ballot:secret`, dass die Ergebnisse erst beim Schließen gezeigt werden (siehe `create_vote`).
+    
+    Welche Filme ausgewählt werden, legt die Auswahlregel fest (siehe `selection_policy`). Mit `dry-run` wird keine Abstimmung
+    erstellt, sondern angezeigt, mit welcher Wahrscheinlichkeit jeder Film ausgewählt würde.
     
     **Nutzung**
-    !random_movie_vote <Optional: positive ganze Zahl>
+    !random_movie_vote <Optional: positive ganze Zahl> <Optional: mode:single|mode:ranked|mode:approval|mode:pick:<Zahl>> <Optional: duration:<Dauer>|until:<HH:MM>> <Optional: ballot:secret>
+    !random_movie_vote dry-run <Optional: positive ganze Zahl>
     
     **Beispiel**
     !random_movie_vote
     !random_movie_vote 5
+    !random_movie_vote 5 mode:ranked
+    !random_movie_vote mode:approval
+    !random_movie_vote until:20:00
+    !random_movie_vote dry-run 3
     
     **Aliase**
     `random_movie_vote`, `rmv`";
@@ -649,3 +729,659 @@ pub fn show_help_count_movies(bot_data: &crate::BotData) {
         |embed| embed.title(":information_source: Count - Hilfe").description(help_str).color(COLOR_INFORMATION)
     );
 }
+
+/**
+ * Shows a help message about the refresh command
+ */
+pub fn show_help_refresh(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_refresh function failed.");
+
+    let help_str =
+    "Lädt die aktuellen Daten eines Films von TMDb und zeigt an, was sich seit dem Hinzufügen geändert hat.
+    Mit `all` werden alle Filme auf der Filmliste aktualisiert. Die Zusammenfassung folgt, sobald alle Filme geladen wurden. Dies können nur Administratoren.
+    Mit `auto` wird angezeigt oder gesetzt, nach wie vielen Tagen die Filmdaten automatisch aktualisiert werden.
+    Mit `auto off` wird die automatische Aktualisierung ausgeschaltet. Das Setzen können nur Administratoren.
+    Änderungen durch die automatische Aktualisierung werden in dem Kanal gemeldet, in dem sie eingeschaltet wurde.
+    
+    **Nutzung**
+    !refresh <ID>
+    !refresh all
+    !refresh auto <Optional: Anzahl Tage | off>
+    
+    **Beispiel**
+    !refresh 12
+    !refresh all
+    !refresh auto 7
+    !refresh auto off
+    
+    **Aliase**
+    `refresh`, `rf`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Refresh - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the export command
+ */
+pub fn show_help_export(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_export function failed.");
+
+    let help_str =
+    "Exportiert die Filmliste oder den Verlauf als Datei und lädt sie in den Kanal hoch.
+    Die Datei enthält ID, Titel, TMDb- und IMDb-ID, den Nutzer der den Film hinzugefügt hat, den Status, sowie das Datum an dem der Film hinzugefügt und geschaut wurde.
+    Verfügbare Formate sind `csv`, `json` und `md` (Markdown).
+    
+    **Nutzung**
+    !export <watchlist|history> <Format>
+    
+    **Beispiel**
+    !export watchlist csv
+    !export history md
+    
+    **Aliase**
+    `export`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Export - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the import command
+ */
+pub fn show_help_import(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_import function failed.");
+
+    let help_str =
+    "Fügt die Filme einer Liste von Letterboxd oder IMDb zur Filmliste hinzu. Die exportierte CSV-Datei muss an die Nachricht angehängt werden.
+    Zu jeder Zeile wird der passende Film auf TMDb gesucht. Anschließend wird eine Übersicht der gefundenen, mehrdeutigen und bereits vorhandenen Filme angezeigt.
+    Die gefundenen Filme werden erst hinzugefügt, wenn du mit ✅ bestätigst. Dabei gilt das Filmlimit pro Nutzer.
+    Für mehrdeutige Zeilen werden mehrere Filme angeboten. Mit `choose` wählst du vor der Bestätigung einen davon aus.
+    
+    **Nutzung**
+    !import (mit angehängter CSV-Datei)
+    !import choose <Zeile> <Option>
+    
+    **Beispiel**
+    !import
+    !import choose 2 1
+    
+    **Aliase**
+    `import`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Import - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the rate command
+ */
+pub fn show_help_rate(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_rate function failed.");
+
+    let help_str =
+    "Bewertet einen geschauten Film mit einer Punktzahl von 1 bis 10. Eine frühere Bewertung wird dabei überschrieben.
+    Sobald ein Film auf `Watched` gesetzt wird, schickt der Bot außerdem eine Nachricht, auf die alle mit 1️⃣ bis 🔟 reagieren können.
+    Der Durchschnitt aller Bewertungen wird bei `show_movie` und im Verlauf angezeigt.
+    
+    **Nutzung**
+    !rate <ID> <Punktzahl>
+    
+    **Beispiel**
+    !rate 12 8
+    
+    **Aliase**
+    `rate`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Rate - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the review and reviews commands
+ */
+pub fn show_help_review(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_review function failed.");
+
+    let help_str =
+    "Schreibt eine kurze Review zu einem geschauten Film. Jeder Nutzer kann pro Film eine Review schreiben. Eine erneute Review überschreibt die vorherige.
+    Mit `delete` wird die eigene Review gelöscht. Administratoren können mit einer Erwähnung auch die Reviews anderer Nutzer bearbeiten oder löschen.
+    Die Reviews eines Films werden bei `show_movie` angezeigt. Mit `reviews` werden alle Reviews eines Nutzers angezeigt.
+    
+    **Nutzung**
+    !review <ID> <Text>
+    !review <ID> delete
+    !review <ID> <@Nutzer> <Text|delete>
+    !reviews <Optional: @Nutzer>
+    
+    **Beispiel**
+    !review 12 Spannend bis zur letzten Minute.
+    !review 12 delete
+    !reviews @Nutzer
+    
+    **Aliase**
+    `review`, `rv`, `reviews`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Review - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the statistics command
+ */
+pub fn show_help_statistics(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_statistics function failed.");
+
+    let help_str =
+    "Zeigt Statistiken über alle geschauten Filme an. Mit den Pfeilen unter der Nachricht kann zwischen den Ansichten geblättert werden.
+    Die Übersicht enthält die Anzahl der geschauten Filme, die gesamte Laufzeit und wie lange ein Film durchschnittlich auf der Filmliste stand.
+    Außerdem werden die Verteilung der Genres und Originalsprachen, die Monate mit den meisten Filmen und der Anteil jedes Mitglieds an den geschauten Filmen angezeigt.
+    
+    **Nutzung**
+    !statistics
+    
+    **Beispiel**
+    !stats
+    
+    **Aliase**
+    `statistics`, `stats`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Statistics - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the profile command
+ */
+pub fn show_help_profile(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_profile function failed.");
+
+    let help_str =
+    "Zeigt das Profil eines Nutzers an. Ohne Erwähnung wird das eigene Profil angezeigt.
+    Das Profil enthält die Anzahl der Filme auf der Filmliste im Vergleich zum Filmlimit, wie viele der hinzugefügten Filme bereits geschaut wurden und wie oft die Filme des Nutzers eine zufällige Filmabstimmung gewonnen haben.
+    Außerdem werden die durchschnittlich vergebene und erhaltene Bewertung sowie die Lieblingsgenres angezeigt.
+    
+    **Nutzung**
+    !profile <Optional: @Nutzer>
+    
+    **Beispiel**
+    !profile
+    !profile @Nutzer
+    
+    **Aliase**
+    `profile`, `pf`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Profile - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the event and events commands
+ */
+pub fn show_help_event(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_event function failed.");
+
+    let help_str =
+    "Plant einen Filmabend am angegebenen Datum und zur angegebenen Uhrzeit. Mit den Reaktionen ✅, ❔ und ❌ kann jeder zusagen, vielleicht kommen oder absagen.
+    Vor dem Filmabend werden Erinnerungen gesendet, in denen alle erwähnt werden, die nicht abgesagt haben. Administratoren können mit `reminders` festlegen, wie lange vorher die Erinnerungen gesendet werden.
+    Die nächste zufällige Filmabstimmung wird mit dem nächsten Filmabend verknüpft. Der Gewinner wird als Film des Abends angezeigt und bekommt das Datum des Filmabends als Datum, an dem er geschaut wurde.
+    Ein Filmabend kann vom Ersteller oder einem Administrator abgesagt werden. Mit `events` werden alle geplanten Filmabende angezeigt.
+    
+    **Nutzung**
+    !event <TT.MM.JJJJ> <HH:MM> <Optional: Titel>
+    !event cancel <Event-ID>
+    !event reminders <Optional: Zeiten|off>
+    !events
+    
+    **Beispiel**
+    !event 20.11.2021 20:00 Horrorabend
+    !event cancel 3
+    !event reminders 1d 2h 15m
+    
+    **Aliase**
+    `event`, `events`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Event - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the attended command
+ */
+pub fn show_help_attended(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_attended function failed.");
+
+    let help_str =
+    "Trägt ein, wer bei einem geschauten Film dabei war. Ohne Erwähnung trägst du dich selbst ein. Mit `remove` werden Nutzer wieder ausgetragen.
+    Andere Nutzer können nur vom Besitzer des Films oder einem Administrator ein- oder ausgetragen werden.
+    Bei Filmen eines Filmabends werden automatisch alle eingetragen, die zugesagt haben. Ohne Filmabend werden alle eingetragen, die an der zufälligen Filmabstimmung teilgenommen haben.
+    Die Anwesenheit wird bei `show_movie`, im Profil und in den Statistiken angezeigt.
+    
+    **Nutzung**
+    !attended <ID> <Optional: @Nutzer...>
+    !attended <ID> remove <Optional: @Nutzer...>
+    
+    **Beispiel**
+    !attended 12
+    !attended 12 @Nutzer1 @Nutzer2
+    !attended 12 remove @Nutzer1
+    
+    **Aliase**
+    `attended`, `ad`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Attended - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the missed_nights_limit command
+ */
+pub fn show_help_missed_nights_limit(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_missed_nights_limit function failed.");
+
+    let help_str =
+    "Legt fest, wie viele Filmabende ein Nutzer hintereinander verpassen darf, bevor seine Filme bei zufälligen Filmabstimmungen übersprungen werden.
+    Gezählt werden die Filmabende seit dem ersten Film, den der Nutzer hinzugefügt hat, auch wenn er noch nie dabei war.
+    Sobald der Nutzer wieder bei einem Filmabend dabei ist, werden seine Filme wieder berücksichtigt. Mit `off` wird die Grenze ausgeschaltet.
+    Ohne Argument wird die aktuelle Grenze angezeigt. Nur Administratoren können die Grenze ändern.
+    
+    **Nutzung**
+    !missed_nights_limit <Optional: Zahl|off>
+    
+    **Beispiel**
+    !missed_nights_limit 3
+    !missed_nights_limit off
+    
+    **Aliase**
+    `missed_nights_limit`, `mnl`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Missed Nights Limit - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the seen command
+ */
+pub fn show_help_seen(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_seen function failed.");
+
+    let help_str =
+    "Markiert einen Film auf der Filmliste als bereits gesehen, z.B. weil du ihn schon alleine geschaut hast. Mit `remove` wird die Markierung wieder entfernt.
+    Alternativ kannst du mit 👁️ auf die Nachricht von `show_movie` reagieren. Die Filmliste zeigt an, wie viele Mitglieder einen Film bereits gesehen haben.
+    Filme, die zu viele Mitglieder bereits gesehen haben, werden bei zufälligen Filmabstimmungen seltener oder gar nicht ausgewählt (siehe `seen_limit`).
+    Mit 🙋 zeigst du Interesse an einem Film. Je nach Auswahlregel werden solche Filme bei zufälligen Filmabstimmungen häufiger ausgewählt (siehe `selection_policy`).
+    
+    **Nutzung**
+    !seen <ID> <Optional: remove>
+    
+    **Beispiel**
+    !seen 12
+    !seen 12 remove
+    
+    **Aliase**
+    `seen`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Seen - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the seen_limit command
+ */
+pub fn show_help_seen_limit(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_seen_limit function failed.");
+
+    let help_str =
+    "Legt fest, ab welchem Anteil der aktiven Mitglieder ein Film als von zu vielen gesehen gilt. Aktive Mitglieder sind alle mit Filmen auf der Filmliste und alle, die bei einem der letzten Filmabende dabei waren.
+    Mit `exclude` werden solche Filme nicht mehr in zufällige Filmabstimmungen aufgenommen, mit `weight` werden sie seltener aufgenommen. Mit `off` wird die Grenze ausgeschaltet.
+    Ohne Argument wird die aktuelle Einstellung angezeigt. Nur Administratoren können die Einstellung ändern.
+    
+    **Nutzung**
+    !seen_limit <Optional: Prozent|off> <Optional: exclude|weight>
+    
+    **Beispiel**
+    !seen_limit 50 exclude
+    !seen_limit 30%
+    !seen_limit off
+    
+    **Aliase**
+    `seen_limit`, `sl`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Seen Limit - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the rank command
+ */
+pub fn show_help_rank(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_rank function failed.");
+
+    let help_str =
+    "Legt deine Rangfolge für eine Abstimmung mit Rangfolge fest. Gib dazu die Emojis der Optionen in der Reihenfolge deiner
+    Präferenz an, getrennt durch Leerzeichen. Die Abstimmung wird anhand der Emojis erkannt. Sind mehrere Abstimmungen offen,
+    gib die ID der Abstimmung vor den Emojis an. Der Befehl funktioniert auch per Direktnachricht an den Bot, so dass niemand
+    deine Rangfolge sieht.
+    Alternativ kannst du nacheinander auf die Abstimmung reagieren.
+    
+    **Nutzung**
+    !rank <Optional: ID der Abstimmung> <Emojis der Optionen getrennt durch Leerzeichen>
+    
+    **Beispiel**
+    !rank 🐼 🌵 🐨
+    !rank 4 🐼 🌵 🐨
+    
+    **Aliase**
+    `rank`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Rank - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the ballot command
+ */
+pub fn show_help_ballot(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_ballot function failed.");
+
+    let help_str =
+    "Gibt deine Stimme für eine Abstimmung ab und ersetzt deine bisherige Wahl. Gib dazu die Emojis der gewählten Optionen an,
+    getrennt durch Leerzeichen. Die Abstimmung wird anhand der Emojis erkannt. Sind mehrere Abstimmungen offen, gib die ID
+    der Abstimmung vor den Emojis an. Bei Abstimmungen mit Rangfolge gilt die Reihenfolge der Emojis als Rangfolge.
+    Schicke den Befehl per Direktnachricht an den Bot, damit niemand sieht, wie du abgestimmt hast.
+    
+    **Nutzung**
+    !ballot <Optional: ID der Abstimmung> <Emojis der Optionen getrennt durch Leerzeichen>
+    
+    **Beispiel**
+    !ballot 🐼
+    !ballot 🐼 🌵
+    !ballot 4 🐼
+    
+    **Aliase**
+    `ballot`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Ballot - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the tie_break command
+ */
+pub fn show_help_tie_break(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_tie_break function failed.");
+
+    let help_str =
+    "Legt fest, wie ein Gleichstand zwischen den Optionen mit den meisten Stimmen entschieden wird. Die Zusammenfassung einer Abstimmung nennt immer die Regel, die entschieden hat.
+    `random`: Zufällige Wahl, der verwendete Seed wird veröffentlicht
+    `oldest`: Der älteste Film auf der Filmliste (niedrigste ID) gewinnt
+    `fewest_watched`: Der Film des Mitglieds mit den wenigsten geschauten Filmen gewinnt
+    `runoff`: Es wird automatisch eine Stichwahl mit den gleichauf liegenden Optionen gestartet
+    Ohne Filme unter den Optionen wird zufällig entschieden. Ohne Argument wird die aktuelle Einstellung angezeigt. Nur Administratoren können die Einstellung ändern.
+    
+    **Nutzung**
+    !tie_break <Optional: random|oldest|fewest_watched|runoff>
+    
+    **Beispiel**
+    !tie_break runoff
+    !tie_break
+    
+    **Aliase**
+    `tie_break`, `tb`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Tie Break - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the votes command
+ */
+pub fn show_help_votes(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_votes function failed.");
+
+    let help_str =
+    "Zeigt die zuletzt geschlossenen Abstimmungen mit ihren Gewinnern. Mit einer ID werden die Optionen, die Stimmen aller Mitglieder und das Ergebnis der geschlossenen Abstimmung angezeigt.
+    Wie oft ein Film bereits in Abstimmungen war und verloren hat, steht in den Filminformationen (siehe `show_movie`).
+    
+    **Nutzung**
+    !votes history <Optional: ID>
+    
+    **Beispiel**
+    !votes history
+    !votes history 3
+    
+    **Aliase**
+    `votes`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Votes - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the vote command
+ */
+pub fn show_help_vote(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_vote function failed.");
+
+    let help_str =
+    "Ändert deine offene Abstimmung, ohne dass bereits abgegebene Stimmen verloren gehen. Wenn du mehrere Abstimmungen besitzt, gib zuerst die ID der Abstimmung an. Administratoren können jede Abstimmung über ihre ID ändern.
+    `add`: Fügt eine Option mit einem neuen Emoji hinzu. Wie bei `create_vote` kann ein Film mit `id:`, `t:`, `tmdb:` oder `imdb:` angegeben werden.
+    `remove`: Entfernt die Option mit dem angegebenen Emoji.
+    `title`: Ändert den Titel der Abstimmung.
+    
+    **Nutzung**
+    !vote <Optional: ID> add <Option>
+    !vote <Optional: ID> remove <Emoji>
+    !vote <Optional: ID> title <Titel>
+    
+    **Beispiel**
+    !vote add Pizza
+    !vote 3 add id:12
+    !vote remove 🐼
+    !vote title Was essen wir heute?
+    
+    **Aliase**
+    `vote`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Vote - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the selection_policy command
+ */
+pub fn show_help_selection_policy(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_selection_policy function failed.");
+
+    let help_str =
+    "Legt fest, wie die Filme einer zufälligen Filmabstimmung ausgewählt werden. Mit `random_movie_vote dry-run` siehst du die Wahrscheinlichkeit jedes Films.
+    `weighting uniform`: Alle Filme sind gleich wahrscheinlich, die drei ältesten Filme werden mit etwas Glück eingetauscht (Standard)
+    `weighting time`: Filme werden umso wahrscheinlicher, je länger sie auf der Filmliste stehen
+    `owner on|off`: Jedes Mitglied bekommt höchstens einen Film in der Abstimmung
+    `interest on|off`: Filme, an denen Mitglieder mit 🙋 Interesse gezeigt haben, werden häufiger ausgewählt
+    `losses on|off`: Filme, die in den letzten 30 Tagen Abstimmungen verloren haben, werden seltener ausgewählt
+    `unreleased on|off`: Filme, die noch nicht erschienen sind, werden nicht ausgewählt
+    `fair on|rsvp|off`: Zuerst bekommen zufällige Mitglieder je einen Platz mit einem ihrer Filme, übrige Plätze werden normal vergeben. Mit `rsvp` nur Mitglieder, die dem nächsten Filmabend zugesagt haben
+    `fairness`: Zeigt, wie oft jedes Mitglied bisher einen Film in zufälligen Filmabstimmungen hatte
+    Filme mit dem Status `Unavailable` werden nie ausgewählt. Ohne Argument wird die aktuelle Einstellung angezeigt. Nur Administratoren können die Einstellung ändern.
+    
+    **Nutzung**
+    !selection_policy <Optional: weighting|owner|interest|losses|unreleased|fair> <Optional: uniform|time|on|off|rsvp>
+    !selection_policy fairness
+    
+    **Beispiel**
+    !selection_policy weighting time
+    !selection_policy owner on
+    !selection_policy fair rsvp
+    !selection_policy
+    
+    **Aliase**
+    `selection_policy`, `sp`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Selection policy - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the veto command
+ */
+pub fn show_help_veto(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_veto function failed.");
+
+    let help_str =
+    "Legt ein Veto gegen einen Film einer offenen Abstimmung ein, der dadurch aus der Abstimmung entfernt wird.
+    Ohne ID gilt das Veto der zufälligen Filmabstimmung, dort wird der Film durch einen anderen Film der Filmliste ersetzt.
+    Alternativ kannst du mit 🚫 auf die Abstimmung reagieren, deine nächste Reaktion auf eine Option ist dann ein Veto.
+    Wie viele Vetos jedes Mitglied hat, legt `veto_limit` fest. Mit `vetoes` siehst du deine verbleibenden Vetos.
+    
+    **Nutzung**
+    !veto <Optional: ID der Abstimmung> <Emoji des Films>
+    
+    **Beispiel**
+    !veto 🐼
+    !veto 3 🐼
+    
+    **Aliase**
+    `veto`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Veto - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the vetoes command
+ */
+pub fn show_help_vetoes(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_vetoes function failed.");
+
+    let help_str =
+    "Zeigt, wie viele Vetos die Mitglieder im aktuellen Zeitraum verbraucht haben, wie viele ihnen noch bleiben
+    und gegen welche Filme sie eingelegt wurden.
+    
+    **Nutzung**
+    !vetoes
+    
+    **Aliase**
+    `vetoes`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Vetoes - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the veto_limit command
+ */
+pub fn show_help_veto_limit(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_veto_limit function failed.");
+
+    let help_str =
+    "Legt fest, wie viele Vetos jedes Mitglied innerhalb eines Zeitraums hat. Der Zeitraum wird in Tagen angegeben
+    und beträgt standardmäßig 30 Tage. Mit `off` werden Vetos ausgeschaltet.
+    Ohne Argument wird die aktuelle Einstellung angezeigt. Nur Administratoren können die Einstellung ändern.
+    
+    **Nutzung**
+    !veto_limit <Optional: Anzahl|off> <Optional: Tage>
+    
+    **Beispiel**
+    !veto_limit 2
+    !veto_limit 1 14
+    !veto_limit off
+    
+    **Aliase**
+    `veto_limit`, `vl`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Veto limit - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Shows help on the snooze command
+ */
+pub fn show_help_snooze(bot_data: &crate::BotData) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_help_snooze function failed.");
+
+    let help_str =
+    "Pausiert einen deiner Filme für eine Dauer (z.B. `1d` oder `12h`) oder bis zu einem Datum im Format TT.MM.JJJJ.
+    Der Film bleibt mit 💤 auf der Filmliste, wird aber bis dahin nicht in zufällige Filmabstimmungen aufgenommen.
+    Danach endet die Pause automatisch, mit `off` kannst du sie vorher beenden. Administratoren können alle Filme pausieren.
+    
+    **Nutzung**
+    !snooze <ID> <Dauer|Datum|off>
+    
+    **Beispiel**
+    !snooze 12 1d
+    !snooze 12 24.12.2026
+    !snooze 12 off
+    
+    **Aliase**
+    `snooze`";
+
+    let _ = bot_data.bot.send_embed(
+        message.channel_id,
+        "",
+        |embed| embed.title(":information_source: Snooze - Hilfe").description(help_str).color(COLOR_INFORMATION)
+    );
+}
\ No newline at end of file
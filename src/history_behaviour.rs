@@ -1,15 +1,23 @@
+use crate::filter_behaviour::{generate_movie_count_string, matches_all_filters, MovieFilter};
 use crate::general_behaviour::timestamp_to_string;
 use crate::movie_behaviour::{
-    get_movie_link, MovieStatus, SortedMovieList, UserSortedMovieListVectorEntry, WatchListEntry,
+    get_movie_link, sort_movie_entries, MovieSortOrder, MovieStatus, SortedMovieList,
+    UserSortedMovieListVectorEntry, WatchListEntry,
 };
 use crate::COLOR_BOT;
 use itertools::Itertools;
 use std::collections::HashMap;
 
 /**
- * Formats the watch list hash map as a movie history and sends it as an embedded message
+ * Formats the watch list hash map as a movie history and sends it as an embedded message. Only movies
+ * that fulfill all given filters are shown.
  */
-pub fn show_history(bot_data: &mut crate::BotData, order: String, reverse: bool) {
+pub fn show_history(
+    bot_data: &mut crate::BotData,
+    order: MovieSortOrder,
+    descending: bool,
+    filters: Vec<MovieFilter>,
+) {
     let message = bot_data
         .message
         .as_ref()
@@ -42,35 +50,38 @@ pub fn show_history(bot_data: &mut crate::BotData, order: String, reverse: bool)
     }
 
     let mut history_string = String::new();
-    let history_count = count_history_movies(bot_data);
+    let history_count = count_history_movies(bot_data, &filters);
     let sorted_movie_list_enum_option: Option<SortedMovieList>;
     let total_pages: usize;
 
     if history_count > 0 {
         // If the ordering by user is demanded
-        if order == "user" {
-            let user_sorted_history = create_user_sorted_history_vector(bot_data);
+        if order == MovieSortOrder::User {
+            let user_sorted_history =
+                create_user_sorted_history_vector(bot_data, &filters, descending);
             total_pages = user_sorted_history
                 .iter()
                 .map(|x| x.number_of_pages_required)
                 .sum();
 
             history_string +=
-                generate_user_sorted_history_page_string(&user_sorted_history, 1).as_str();
+                generate_user_sorted_history_page_string(&user_sorted_history, &filters, 1)
+                    .as_str();
 
             sorted_movie_list_enum_option = Some(SortedMovieList::HistoryUserSorted(
                 total_pages,
                 user_sorted_history,
+                filters,
             ));
         }
-        // If the ordering should be by date
+        // Otherwise sort the whole history by the given order
         else {
             // Get all movies from the history
-            let mut date_sorted_history: Vec<(u32, WatchListEntry)> = bot_data
+            let mut sorted_history: Vec<(u32, WatchListEntry)> = bot_data
                 .watch_list
                 .iter()
                 .filter_map(|(id, entry)| {
-                    if entry.status.is_history_status() {
+                    if entry.status.is_history_status() && matches_all_filters(entry, &filters) {
                         Some((*id, entry.clone()))
                     } else {
                         None
@@ -78,32 +89,32 @@ pub fn show_history(bot_data: &mut crate::BotData, order: String, reverse: bool)
                 })
                 .collect();
 
-            // And sort them by date
-            date_sorted_history.sort_by(|a, b| {
-                a.1.watched_or_removed_timestamp
-                    .expect("Movie did not have a watched_or_removed timestamp in show_history")
-                    .cmp(&b.1.watched_or_removed_timestamp.expect(
-                        "Movie did not have a watched_or_removed_timestamp in show_history",
-                    ))
-            });
-            if reverse {
-                date_sorted_history.reverse();
-            }
-            total_pages = (date_sorted_history.len() as f64 / crate::MAX_ENTRIES_PER_PAGE as f64)
-                .ceil() as usize;
+            // And sort them by the order
+            sort_movie_entries(&mut sorted_history, order, descending);
+            total_pages =
+                (sorted_history.len() as f64 / crate::MAX_ENTRIES_PER_PAGE as f64).ceil() as usize;
 
-            history_string +=
-                generate_date_sorted_history_page_string(&date_sorted_history, 1).as_str();
-            sorted_movie_list_enum_option = Some(SortedMovieList::HistoryDateSorted(
+            history_string += generate_sorted_history_page_string(
+                &sorted_history,
+                &order,
+                descending,
+                &filters,
+                1,
+            )
+            .as_str();
+            sorted_movie_list_enum_option = Some(SortedMovieList::HistorySorted(
                 total_pages,
-                date_sorted_history,
+                order,
+                descending,
+                sorted_history,
+                filters,
             ));
         }
     } else {
         let _ = bot_data.bot.send_embed(message.channel_id, "", |embed| {
             embed
                 .title("Verlauf")
-                .description("Es sind zur Zeit **0** Filme im Verlauf")
+                .description(generate_movie_count_string(0, &filters, true).as_str())
                 .color(COLOR_BOT)
         });
         return;
@@ -146,21 +157,13 @@ pub fn show_history(bot_data: &mut crate::BotData, order: String, reverse: bool)
  */
 pub fn generate_user_sorted_history_page_string(
     user_sorted_history: &Vec<UserSortedMovieListVectorEntry>,
+    filters: &Vec<MovieFilter>,
     page_to_show: usize,
 ) -> String {
     let mut history_string = String::new();
 
     let history_count: usize = user_sorted_history.iter().map(|x| x.entries.len()).sum();
-    if history_count == 1 {
-        history_string +=
-            format!("Es ist zur Zeit **{}** Film im Verlauf\n\n", history_count).as_str();
-    } else {
-        history_string += format!(
-            "Es sind zur Zeit **{}** Filme im Verlauf\n\n",
-            history_count
-        )
-        .as_str();
-    }
+    history_string += generate_movie_count_string(history_count, filters, true).as_str();
 
     if history_count == 0 {
         return history_string;
@@ -214,7 +217,7 @@ pub fn generate_user_sorted_history_page_string(
         // For each of those append the string to the watch list
         .for_each(|(_, entry)| {
             history_string += format!(
-                " {} [**{}**]({})\n> `{:0>4}` | {} am {}\n\n",
+                " {} [**{}**]({})\n> `{:0>4}` | {} am {}{}\n\n",
                 entry.1.status.get_emoji(),
                 entry.1.movie.movie_title,
                 get_movie_link(entry.1.movie.tmdb_id, false),
@@ -229,7 +232,8 @@ pub fn generate_user_sorted_history_page_string(
                         "Movie did not have a watched_or_removed_timestamp in show_history"
                     ),
                     false
-                )
+                ),
+                format_rating_suffix(&entry.1)
             )
             .as_str();
         });
@@ -238,16 +242,17 @@ pub fn generate_user_sorted_history_page_string(
 }
 
 /**
- * Collects all movie entries from the watch list that have a history status
+ * Collects all movie entries from the watch list that have a history status and fulfill the filters
  * and returns them in a new HashMap that contains the user as key.
  */
-fn create_user_sorted_history(
-    bot_data: &crate::BotData,
-) -> HashMap<&String, Vec<(u32, &WatchListEntry)>> {
+fn create_user_sorted_history<'a>(
+    bot_data: &'a crate::BotData,
+    filters: &Vec<MovieFilter>,
+) -> HashMap<&'a String, Vec<(u32, &'a WatchListEntry)>> {
     // Create a hashmap that stores the user as key and a tuple containing the id and the watch list entry of every movie
     let mut user_movies: HashMap<&String, Vec<(u32, &WatchListEntry)>> = HashMap::new();
     for (id, entry) in bot_data.watch_list.iter().sorted() {
-        if entry.status.is_history_status() {
+        if entry.status.is_history_status() && matches_all_filters(entry, filters) {
             if entry.watched_or_removed_timestamp.is_none() {
                 continue;
             }
@@ -271,15 +276,17 @@ fn create_user_sorted_history(
  */
 fn create_user_sorted_history_vector(
     bot_data: &crate::BotData,
+    filters: &Vec<MovieFilter>,
+    descending: bool,
 ) -> Vec<UserSortedMovieListVectorEntry> {
-    let mut user_sorted_history_hash_map = create_user_sorted_history(bot_data);
+    let mut user_sorted_history_hash_map = create_user_sorted_history(bot_data, filters);
     let get_number_pages_as_usize = |entries_len: usize| -> usize {
         (entries_len as f64 / crate::MAX_ENTRIES_PER_PAGE as f64).ceil() as usize
     };
 
-    user_sorted_history_hash_map
+    let mut user_sorted_history: Vec<UserSortedMovieListVectorEntry> = user_sorted_history_hash_map
         .iter_mut()
-        .sorted()
+        .sorted_by_key(|(name, _)| name.to_lowercase())
         .map(|(name, entries)| {
             // Sort the entries of each user by the date they have been watched (or removed)
             entries.sort_by(|a, b| {
@@ -299,40 +306,45 @@ fn create_user_sorted_history_vector(
                     .collect(),
             }
         })
-        .collect()
+        .collect();
+
+    if descending {
+        user_sorted_history.reverse();
+    }
+
+    user_sorted_history
 }
 
 /**
- * Generates the string to the given page for the paginated history
+ * Generates the string to the given page for the paginated history sorted by the given order
  */
-pub fn generate_date_sorted_history_page_string(
-    date_sorted_history: &Vec<(u32, WatchListEntry)>,
+pub fn generate_sorted_history_page_string(
+    sorted_history: &Vec<(u32, WatchListEntry)>,
+    order: &MovieSortOrder,
+    descending: bool,
+    filters: &Vec<MovieFilter>,
     page_to_show: usize,
 ) -> String {
     let mut history_string = String::new();
 
-    let history_count = date_sorted_history.len();
-    if history_count == 1 {
-        history_string +=
-            format!("Es ist zur Zeit **{}** Film im Verlauf\n\n", history_count).as_str();
-    } else {
-        history_string += format!(
-            "Es sind zur Zeit **{}** Filme im Verlauf\n\n",
-            history_count
-        )
-        .as_str();
-    }
+    let history_count = sorted_history.len();
+    history_string += generate_movie_count_string(history_count, filters, true).as_str();
 
     if history_count == 0 {
         return history_string;
     }
 
-    history_string += format!("Die Filme werden geordnet nach Datum angezeigt.\n\n").as_str();
+    history_string += format!(
+        "Die Filme werden geordnet nach {}{} angezeigt.\n\n",
+        order.get_description(),
+        if descending { " (absteigend)" } else { "" }
+    )
+    .as_str();
 
     let first_index_to_show = (page_to_show - 1) * crate::MAX_ENTRIES_PER_PAGE;
 
     // For every movie entry
-    date_sorted_history
+    sorted_history
         .iter()
         // Get the index of every element
         .enumerate()
@@ -349,7 +361,7 @@ pub fn generate_date_sorted_history_page_string(
         // Now build the history_string for those
         .for_each(|(_, (id, entry))| {
             history_string += format!(
-                " {} [**{}**]({})\n> `{:0>4}` | hinzugefügt von **{}**, {} am {}\n\n",
+                " {} [**{}**]({})\n> `{:0>4}` | hinzugefügt von **{}**, {} am {}{}{}\n\n",
                 entry.status.get_emoji(),
                 entry.movie.movie_title,
                 get_movie_link(entry.movie.tmdb_id, false),
@@ -365,7 +377,12 @@ pub fn generate_date_sorted_history_page_string(
                         "Movie did not have a watched_or_removed_timestamp in show_history"
                     ),
                     false
-                )
+                ),
+                format_rating_suffix(entry),
+                order
+                    .get_sort_value_string(entry)
+                    .map(|value| format!(" | {}", value))
+                    .unwrap_or_default()
             )
             .as_str();
         });
@@ -374,12 +391,22 @@ pub fn generate_date_sorted_history_page_string(
 }
 
 /**
- * Counts all movies from the list with history status and returns the count
+ * Returns the average rating of the members for the history line, or nothing if nobody rated the movie
+ */
+fn format_rating_suffix(entry: &WatchListEntry) -> String {
+    match crate::rating_behaviour::get_average_rating(entry) {
+        Some(average) => format!(" | ⭐ {:.1}", average),
+        None => String::new(),
+    }
+}
+
+/**
+ * Counts all movies from the list with history status that fulfill the filters and returns the count
  */
-fn count_history_movies(bot_data: &crate::BotData) -> usize {
+fn count_history_movies(bot_data: &crate::BotData, filters: &Vec<MovieFilter>) -> usize {
     bot_data
         .watch_list
         .iter()
-        .filter(|(_, entry)| entry.status.is_history_status())
+        .filter(|(_, entry)| entry.status.is_history_status() && matches_all_filters(entry, filters))
         .count()
 }
@@ -0,0 +1,547 @@
+use crate::general_behaviour::{current_timestamp, reaction_emoji_equals, WaitingForReaction};
+use crate::movie_behaviour::{count_user_watch_list_movies, find_id_by_tmdb_id, movie_from_tmdb, Movie, MovieStatus, WatchListEntry};
+use crate::send_message;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::mpsc;
+use tmdb::themoviedb::*;
+
+/**
+ * The amount of search results that are offered for a row that could not be matched to exactly one movie
+ */
+const MAX_OPTIONS_PER_AMBIGUOUS_ROW: usize = 3;
+
+/**
+ * A TMDb movie that is offered for a row of an imported list
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ImportCandidate {
+    pub tmdb_id: u64,
+    pub title: String,
+}
+
+/**
+ * A row of an imported list that matched several TMDb movies. The user can choose one of the options.
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AmbiguousRow {
+    pub description: String,
+    pub options: Vec<ImportCandidate>,
+    pub chosen: bool,
+}
+
+/**
+ * All movies of an import that wait for the confirmation of the user who started the import
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PendingImport {
+    pub user_id: discord::model::UserId,
+    pub user_name: String,
+    pub candidates: Vec<Movie>, // The movies are already loaded from TMDb, so confirming needs no further requests
+    pub ambiguous: Vec<AmbiguousRow>,
+}
+
+/**
+ * The result of resolving all rows of an imported list
+ */
+#[derive(Default)]
+pub struct ImportSummary {
+    pub matched: Vec<Movie>,
+    pub ambiguous: Vec<AmbiguousRow>,
+    pub duplicates: Vec<String>,
+    pub not_found: Vec<String>,
+    pub over_limit: Vec<String>,
+    pub skipped_rows: usize,
+}
+
+/**
+ * An import whose list is downloaded and resolved on a separate thread, so the bot keeps answering in the meantime
+ */
+pub struct ImportJob {
+    channel_id: discord::model::ChannelId,
+    user_id: discord::model::UserId,
+    user_name: String,
+    receiver: mpsc::Receiver<Result<ResolvedRows, String>>,
+}
+
+/**
+ * The resolved rows of an imported list and the amount of rows that were skipped because of the row limit
+ */
+struct ResolvedRows {
+    rows: Vec<(ImportRow, RowResult)>,
+    skipped_rows: usize,
+}
+
+/**
+ * One row of the csv file. Letterboxd exports contain title and year, IMDb exports additionally the IMDb id
+ */
+struct ImportRow {
+    title: String,
+    year: Option<u64>,
+    imdb_id: Option<String>,
+}
+
+impl ImportRow {
+    fn describe(&self) -> String {
+        match self.year {
+            Some(year) => format!("{} ({})", self.title, year),
+            None => self.title.clone(),
+        }
+    }
+}
+
+enum RowResult {
+    Matched(Movie),
+    Ambiguous(Vec<ImportCandidate>),
+    NotFound,
+}
+
+/**
+ * Splits the content of a csv file into rows and fields. Quoted fields may contain separators,
+ * line breaks and escaped quotes.
+ */
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut characters = content.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(character) = characters.next() {
+        if in_quotes {
+            if character == '"' {
+                // Two quotes inside of a quoted field are an escaped quote
+                if characters.peek() == Some(&'"') {
+                    field.push('"');
+                    characters.next();
+                } else {
+                    in_quotes = false;
+                }
+            } else {
+                field.push(character);
+            }
+        } else {
+            match character {
+                '"' => in_quotes = true,
+                ',' => row.push(std::mem::take(&mut field)),
+                '\r' => (),
+                '\n' => {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                _ => field.push(character),
+            }
+        }
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+
+    // Remove empty lines
+    rows.into_iter()
+        .filter(|row| row.iter().any(|field| !field.trim().is_empty()))
+        .collect()
+}
+
+/**
+ * Reads the rows of a Letterboxd or IMDb export. The format is determined by the column names of the header.
+ */
+fn read_import_rows(content: &str) -> Result<Vec<ImportRow>, String> {
+    let csv = parse_csv(content);
+    let (header, rows) = match csv.split_first() {
+        Some(split) => split,
+        None => return Err(String::from("Die Datei enthält keine Einträge.")),
+    };
+
+    let find_column = |names: &[&str]| header.iter()
+        .position(|column| names.contains(&column.trim().to_lowercase().as_str()));
+
+    // Letterboxd uses the column Name, IMDb the column Title
+    let title_column = find_column(&["name", "title"]);
+    let year_column = find_column(&["year"]);
+    let imdb_column = find_column(&["const"]);
+
+    let title_column = match title_column {
+        Some(column) => column,
+        None => return Err(String::from("Die Datei ist weder ein Letterboxd- noch ein IMDb-Export. Es wurde keine Spalte `Name` oder `Title` gefunden.")),
+    };
+
+    let get_field = |row: &Vec<String>, column: Option<usize>| -> Option<String> {
+        column.and_then(|column| row.get(column))
+            .map(|field| field.trim().to_string())
+            .filter(|field| !field.is_empty())
+    };
+
+    Ok(rows.iter()
+        .filter_map(|row| {
+            let title = get_field(row, Some(title_column))?;
+
+            Some(ImportRow {
+                title,
+                year: get_field(row, year_column).and_then(|year| year.parse::<u64>().ok()),
+                imdb_id: get_field(row, imdb_column).filter(|imdb_id| imdb_id.starts_with("tt")),
+            })
+        })
+        .collect())
+}
+
+/**
+ * Loads the movie with the TMDb id in the format of the watch list
+ */
+fn fetch_import_movie(tmdb: &TMDb, tmdb_id: u64) -> Option<Movie> {
+    tmdb.fetch().id(tmdb_id).execute().ok().map(|tmdb_movie| movie_from_tmdb(&tmdb_movie, current_timestamp()))
+}
+
+/**
+ * Resolves a row to a TMDb movie. Rows with an IMDb id use the find request, all others search
+ * for the title in the given year. Only a single exact title match or a single search result counts as match,
+ * otherwise the best search results are offered to choose from.
+ */
+fn resolve_row(tmdb: &TMDb, row: &ImportRow) -> RowResult {
+    if let Some(imdb_id) = &row.imdb_id {
+        if let Ok(result) = tmdb.find().imdb_id(imdb_id.as_str()).execute() {
+            if let Some(movie) = result.movie_results.first().and_then(|movie| fetch_import_movie(tmdb, movie.id)) {
+                return RowResult::Matched(movie);
+            }
+        }
+    }
+
+    let mut search = tmdb.search();
+    search.title(row.title.as_str());
+    if let Some(year) = row.year {
+        search.year(year);
+    }
+
+    let results = match search.execute() {
+        Ok(result) => result.results,
+        Err(_) => return RowResult::NotFound,
+    };
+
+    let lowercase_title = row.title.to_lowercase();
+    let exact_matches: Vec<&tmdb::model::SearchMovie> = results.iter()
+        .filter(|movie| movie.title.to_lowercase() == lowercase_title
+            || movie.original_title.to_lowercase() == lowercase_title)
+        .collect();
+
+    let movie = match (exact_matches.len(), results.len()) {
+        (1, _) => exact_matches[0],
+        (0, 1) => &results[0],
+        (_, 0) => return RowResult::NotFound,
+        _ => {
+            // Exact matches are the better options, if there are any
+            let options = if exact_matches.is_empty() { results.iter().collect() } else { exact_matches };
+            return RowResult::Ambiguous(options.iter()
+                .take(MAX_OPTIONS_PER_AMBIGUOUS_ROW)
+                .map(|movie| ImportCandidate {
+                    tmdb_id: movie.id,
+                    title: movie.title.clone(),
+                })
+                .collect());
+        }
+    };
+
+    match fetch_import_movie(tmdb, movie.id) {
+        Some(movie) => RowResult::Matched(movie),
+        None => RowResult::NotFound,
+    }
+}
+
+/**
+ * Downloads the csv file and resolves its rows. Runs on a separate thread, since every row needs requests to TMDb.
+ */
+fn download_and_resolve_rows(url: &str) -> Result<ResolvedRows, String> {
+    let content = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .map_err(|error| format!("{}", error))?;
+
+    let rows = read_import_rows(content.as_str())?;
+    let skipped_rows = rows.len().saturating_sub(crate::MAX_ROWS_PER_IMPORT);
+    let tmdb = crate::get_tmdb_struct();
+
+    Ok(ResolvedRows {
+        rows: rows.into_iter()
+            .take(crate::MAX_ROWS_PER_IMPORT)
+            .map(|row| {
+                let result = resolve_row(&tmdb, &row);
+                (row, result)
+            })
+            .collect(),
+        skipped_rows,
+    })
+}
+
+/**
+ * Sorts the resolved rows into matched, ambiguous, duplicate, not found and over limit rows
+ */
+fn summarize_rows(bot_data: &crate::BotData, resolved_rows: ResolvedRows, user_id: discord::model::UserId) -> ImportSummary {
+    let mut summary = ImportSummary {
+        skipped_rows: resolved_rows.skipped_rows,
+        ..ImportSummary::default()
+    };
+    let already_added = count_user_watch_list_movies(bot_data, user_id);
+    let remaining_movies = (bot_data.movie_limit_per_user as usize).saturating_sub(already_added);
+
+    for (row, result) in resolved_rows.rows {
+        match result {
+            RowResult::Matched(movie) => {
+                let already_imported = summary.matched.iter()
+                    .any(|matched| matched.tmdb_id == movie.tmdb_id);
+
+                if already_imported {
+                    summary.duplicates.push(row.describe());
+                } else if let Some(id) = find_id_by_tmdb_id(movie.tmdb_id, &bot_data.watch_list) {
+                    summary.duplicates.push(format!("{} (`{:0>4}`)", row.describe(), id));
+                } else if summary.matched.len() >= remaining_movies {
+                    summary.over_limit.push(row.describe());
+                } else {
+                    summary.matched.push(movie);
+                }
+            },
+            RowResult::Ambiguous(options) => summary.ambiguous.push(AmbiguousRow {
+                description: row.describe(),
+                options,
+                chosen: false,
+            }),
+            RowResult::NotFound => summary.not_found.push(row.describe()),
+        }
+    }
+
+    summary
+}
+
+/**
+ * Starts to download and resolve the csv file attached to the message on a separate thread.
+ * Once the import is resolved, a summary is sent by 'finish_import_jobs'.
+ */
+pub fn import_movies(bot_data: &mut crate::BotData) {
+    let message = bot_data.message.clone().expect("Passing message to import_movies function failed.");
+
+    let url = match message.attachments.first() {
+        Some(attachment) if attachment.filename.to_lowercase().ends_with(".csv") => attachment.url.clone(),
+        _ => return send_message::import_no_csv_attachment_error(bot_data),
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = sender.send(download_and_resolve_rows(url.as_str()));
+    });
+
+    bot_data.import_jobs.push(ImportJob {
+        channel_id: message.channel_id,
+        user_id: message.author.id,
+        user_name: message.author.name.clone(),
+        receiver,
+    });
+    send_message::import_started_information(bot_data, message.channel_id);
+}
+
+/**
+ * Sends the summaries of the imports that were resolved in the meantime. The matched movies are added
+ * once the user confirms the summary. Returns true if an import was finished.
+ */
+pub fn finish_import_jobs(bot_data: &mut crate::BotData) -> bool {
+    let mut finished: Vec<(ImportJob, Result<ResolvedRows, String>)> = Vec::new();
+    let mut running: Vec<ImportJob> = Vec::new();
+
+    for job in std::mem::take(&mut bot_data.import_jobs) {
+        match job.receiver.try_recv() {
+            Ok(result) => finished.push((job, result)),
+            Err(mpsc::TryRecvError::Empty) => running.push(job),
+            Err(mpsc::TryRecvError::Disconnected) => {
+                finished.push((job, Err(String::from("Der Import wurde unerwartet beendet."))))
+            },
+        }
+    }
+    bot_data.import_jobs = running;
+
+    for (job, result) in finished.iter_mut() {
+        let resolved_rows = match std::mem::replace(result, Err(String::new())) {
+            Ok(resolved_rows) => resolved_rows,
+            Err(error) => {
+                send_message::import_failed_error(bot_data, job.channel_id, error);
+                continue;
+            }
+        };
+
+        let summary = summarize_rows(bot_data, resolved_rows, job.user_id);
+        let pending_import = PendingImport {
+            user_id: job.user_id,
+            user_name: job.user_name.clone(),
+            candidates: summary.matched.clone(),
+            ambiguous: summary.ambiguous.clone(),
+        };
+
+        if let Ok(summary_message) = send_message::import_summary(bot_data, job.channel_id, &summary) {
+            // Only ask for confirmation if there is something to add or to choose
+            if !pending_import.candidates.is_empty() || !pending_import.ambiguous.is_empty() {
+                let _ = bot_data.bot.add_reaction(summary_message.channel_id, summary_message.id, discord::model::ReactionEmoji::Unicode("✅".to_string()));
+                let _ = bot_data.bot.add_reaction(summary_message.channel_id, summary_message.id, discord::model::ReactionEmoji::Unicode("❎".to_string()));
+
+                bot_data.wait_for_reaction.push(WaitingForReaction::Import(summary_message, pending_import));
+            }
+        }
+    }
+
+    !finished.is_empty()
+}
+
+/**
+ * Chooses one of the offered movies for an ambiguous row of the pending import of the user.
+ * The row and the option are counted from 1, like in the summary of the import.
+ */
+pub fn choose_import_option(bot_data: &mut crate::BotData, row: usize, option: usize) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to choose_import_option function failed.").author.id;
+
+    let (index, mut pending_import) = match bot_data.wait_for_reaction.iter().enumerate().find_map(|(index, waiting)| match waiting {
+        WaitingForReaction::Import(_, pending_import) if pending_import.user_id == author_id => Some((index, pending_import.clone())),
+        _ => None,
+    }) {
+        Some(found) => found,
+        None => return send_message::no_pending_import_error(bot_data),
+    };
+
+    let candidate = match pending_import.ambiguous.get(row.wrapping_sub(1)) {
+        Some(ambiguous_row) if !ambiguous_row.chosen => match ambiguous_row.options.get(option.wrapping_sub(1)) {
+            Some(candidate) => candidate.clone(),
+            None => return send_message::import_choice_failed_error(bot_data, format!("Zeile `{}` hat keine Option `{}`.", row, option)),
+        },
+        Some(_) => return send_message::import_choice_failed_error(bot_data, format!("Für Zeile `{}` wurde bereits ein Film gewählt.", row)),
+        None => return send_message::import_choice_failed_error(bot_data, format!("Es gibt keine mehrdeutige Zeile `{}`.", row)),
+    };
+
+    if find_id_by_tmdb_id(candidate.tmdb_id, &bot_data.watch_list).is_some()
+        || pending_import.candidates.iter().any(|movie| movie.tmdb_id == candidate.tmdb_id)
+    {
+        return send_message::import_choice_failed_error(bot_data, format!("**{}** ist bereits auf der Liste oder im Import.", candidate.title));
+    }
+
+    if count_user_watch_list_movies(bot_data, author_id) + pending_import.candidates.len() >= bot_data.movie_limit_per_user as usize {
+        return send_message::import_choice_failed_error(bot_data, String::from("Mit diesem Film würde dein Filmlimit überschritten."));
+    }
+
+    let movie = match fetch_import_movie(&bot_data.tmdb, candidate.tmdb_id) {
+        Some(movie) => movie,
+        None => return send_message::import_choice_failed_error(bot_data, format!("**{}** konnte nicht von TMDb geladen werden.", candidate.title)),
+    };
+
+    pending_import.candidates.push(movie);
+    pending_import.ambiguous[row - 1].chosen = true;
+    let candidate_count = pending_import.candidates.len();
+
+    if let WaitingForReaction::Import(_, waiting_import) = &mut bot_data.wait_for_reaction[index] {
+        *waiting_import = pending_import;
+    }
+    send_message::import_option_chosen_information(bot_data, &candidate.title, candidate_count);
+}
+
+/**
+ * Adds all matched and chosen movies of the import if the user who started it confirms with ✅. Returns true if
+ * the import was handled and can be removed from wait_for_reaction. Reactions of other users are ignored.
+ */
+pub fn handle_import_reaction(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, pending_import: &PendingImport) -> bool {
+    if reaction.user_id != pending_import.user_id {
+        return false;
+    }
+
+    if !reaction_emoji_equals(&reaction.emoji, "✅".to_string()) {
+        send_message::import_cancelled_information(bot_data, reaction.channel_id);
+        return true;
+    }
+
+    let mut added: Vec<(u32, String)> = Vec::new();
+    let mut failed: Vec<String> = Vec::new();
+
+    for movie in pending_import.candidates.iter() {
+        // The watch list could have changed since the summary was sent
+        if find_id_by_tmdb_id(movie.tmdb_id, &bot_data.watch_list).is_some()
+            || count_user_watch_list_movies(bot_data, pending_import.user_id) >= bot_data.movie_limit_per_user as usize
+        {
+            failed.push(movie.movie_title.clone());
+            continue;
+        }
+
+        let timestamp = current_timestamp();
+        let new_entry = WatchListEntry {
+            movie: movie.clone(),
+            user: pending_import.user_name.clone(),
+            user_id: pending_import.user_id,
+            status: MovieStatus::NotWatched,
+            added_timestamp: timestamp,
+            watched_or_removed_timestamp: None,
+            ratings: HashMap::new(),
+            reviews: HashMap::new(),
+            vote_wins: 0,
+            attendees: Vec::new(),
+            seen_by: Vec::new(),
+            interested: Vec::new(),
+            snoozed_until: None,
+        };
+
+        added.push((bot_data.next_movie_id, new_entry.movie.movie_title.clone()));
+        bot_data.watch_list.insert(bot_data.next_movie_id, new_entry);
+        bot_data.next_movie_id += 1;
+    }
+
+    send_message::movies_imported_information(bot_data, reaction.channel_id, &added, &failed);
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_tuples(rows: Vec<ImportRow>) -> Vec<(String, Option<u64>, Option<String>)> {
+        rows.into_iter()
+            .map(|row| (row.title, row.year, row.imdb_id))
+            .collect()
+    }
+
+    #[test]
+    fn parses_quoted_fields() {
+        let csv = parse_csv("Name,Year\n\"Alien, der Film\",1979\n\"Der \"\"Pate\"\"\",1972\r\n\"Zwei\nZeilen\",\n\n");
+
+        assert_eq!(csv, vec![
+            vec![String::from("Name"), String::from("Year")],
+            vec![String::from("Alien, der Film"), String::from("1979")],
+            vec![String::from("Der \"Pate\""), String::from("1972")],
+            vec![String::from("Zwei\nZeilen"), String::new()],
+        ]);
+    }
+
+    #[test]
+    fn ignores_the_byte_order_mark() {
+        let csv = parse_csv("\u{feff}Name,Year\nAlien,1979");
+
+        assert_eq!(csv[0][0], "Name");
+        assert_eq!(csv.len(), 2);
+    }
+
+    #[test]
+    fn reads_letterboxd_exports() {
+        let rows = read_import_rows("Date,Name,Year,Letterboxd URI\n2021-01-01,Alien,1979,https://boxd.it/1\n2021-01-02,Solaris,,https://boxd.it/2\n2021-01-03,,2000,https://boxd.it/3");
+
+        assert_eq!(to_tuples(rows.unwrap_or_default()), vec![
+            (String::from("Alien"), Some(1979), None),
+            (String::from("Solaris"), None, None),
+        ]);
+    }
+
+    #[test]
+    fn reads_imdb_exports() {
+        let rows = read_import_rows("\u{feff}Position,Const,Created,Modified,Description,Title,URL,Title Type,IMDb Rating,Runtime (mins),Year\n1,tt0078748,2021-01-01,2021-01-01,,Alien,https://www.imdb.com/title/tt0078748/,movie,8.4,117,1979\n2,nm0000244,2021-01-01,2021-01-01,,Sigourney Weaver,,,,,");
+
+        assert_eq!(to_tuples(rows.unwrap_or_default()), vec![
+            (String::from("Alien"), Some(1979), Some(String::from("tt0078748"))),
+            (String::from("Sigourney Weaver"), None, None),
+        ]);
+    }
+
+    #[test]
+    fn rejects_unknown_exports() {
+        assert!(read_import_rows("Film,Jahr\nAlien,1979").is_err());
+        assert!(read_import_rows("").is_err());
+    }
+}
@@ -1,17 +1,32 @@
 extern crate external_data;
 use commands::{Command, ParseCommandError, SimpleCommand};
-use discord::{self, model as Model, model::ServerId, Discord, State};
+use discord::{self, model as Model, model::ServerId, Connection, Discord, State};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, str::FromStr};
+use std::{collections::HashMap, str::FromStr, sync::mpsc};
 use tmdb::themoviedb::*;
 
+mod attendance_behaviour;
 mod commands;
+mod event_behaviour;
+mod export_behaviour;
+mod filter_behaviour;
 mod general_behaviour;
 mod help_behaviour;
 mod history_behaviour;
+mod import_behaviour;
 mod movie_behaviour;
+mod profile_behaviour;
+mod rating_behaviour;
+mod review_behaviour;
+mod seen_behaviour;
+mod selection_behaviour;
 mod send_message;
+mod statistics_behaviour;
+mod tie_break_behaviour;
 mod serde_behaviour;
+mod snooze_behaviour;
+mod veto_behaviour;
+mod vote_history_behaviour;
 mod voting_behaviour;
 mod watch_list_behaviour;
 
@@ -32,6 +47,14 @@ pub struct BotData {
     #[serde(default)]
     wait_for_reaction: Vec<general_behaviour::WaitingForReaction>,
 
+    #[serde(skip)]
+    #[serde(default)]
+    refresh_jobs: Vec<movie_behaviour::RefreshJob>,
+
+    #[serde(skip)]
+    #[serde(default)]
+    import_jobs: Vec<import_behaviour::ImportJob>,
+
     #[serde(default)]
     votes: HashMap<u64, voting_behaviour::Vote>, // Keys are the message_ids
 
@@ -51,6 +74,55 @@ pub struct BotData {
     #[serde(default)]
     adding_movie: Option<std::time::Instant>,
 
+    #[serde(default)]
+    auto_refresh_days: u32, // 0 disables the automatic refresh of the movie data
+
+    #[serde(default)]
+    auto_refresh_channel_id: u64, // The channel the automatic refresh reports to, 0 if it reports nowhere
+
+    #[serde(default)]
+    events: HashMap<u32, event_behaviour::MovieEvent>, // Keys are the event ids
+
+    #[serde(default)]
+    next_event_id: u32,
+
+    #[serde(default = "get_default_event_reminder_offsets")]
+    event_reminder_offsets: Vec<u32>, // Minutes before an event at which reminders are sent
+
+    #[serde(default)]
+    missed_nights_limit: u32, // 0 disables skipping the movies of owners who missed movie nights
+
+    #[serde(default)]
+    seen_share_limit: u32, // Share of active members in percent, 0 disables the limit
+
+    #[serde(default)]
+    seen_policy: seen_behaviour::SeenPolicy,
+
+    #[serde(default)]
+    tie_break_policy: tie_break_behaviour::TieBreakPolicy,
+
+    #[serde(default)]
+    vote_archive: HashMap<u32, vote_history_behaviour::ArchivedVote>, // Keys are the vote ids
+
+    #[serde(default)]
+    #[serde(alias = "next_archived_vote_id")]
+    next_vote_id: u32, // The id of the latest vote, ids start at 1
+
+    #[serde(default)]
+    selection_policy: selection_behaviour::SelectionPolicy,
+
+    #[serde(default)]
+    selection_statistics: selection_behaviour::SelectionStatistics,
+
+    #[serde(default)]
+    veto_limit: u32, // Vetoes per member and period, 0 disables vetoes
+
+    #[serde(default = "get_default_veto_period_days")]
+    veto_period_days: u32,
+
+    #[serde(default)]
+    vetoes: Vec<veto_behaviour::Veto>,
+
     custom_prefix: char,
     movie_limit_per_user: u32,
     movie_vote_limit: u32,
@@ -82,6 +154,50 @@ fn get_default_server_id() -> discord::model::ServerId {
     discord::model::ServerId(0)
 }
 
+/**
+ * Creates bot data with the defaults of a new server for the unit tests
+ */
+#[cfg(test)]
+fn get_test_bot_data() -> BotData {
+    serde_json::from_str(r#"{"custom_prefix": ".", "movie_limit_per_user": 10, "movie_vote_limit": 2, "next_movie_id": 0}"#)
+        .expect("Creation of the test bot data failed.")
+}
+
+/**
+ * Creates a watch list entry of the user for the unit tests. The tests change the fields they depend on.
+ */
+#[cfg(test)]
+fn get_test_watch_list_entry(title: &str, user_id: u64) -> movie_behaviour::WatchListEntry {
+    serde_json::from_value(serde_json::json!({
+        "movie": {
+            "movie_title": title,
+            "original_title": title,
+            "original_language": "EN",
+            "tmdb_id": 0,
+            "overview": "",
+            "poster_path": null,
+            "release_date": "2000-01-01T20:00:00+01:00",
+            "genres": "Drama",
+            "runtime": 100,
+            "budget": "Unbekannt"
+        },
+        "user": "Tester",
+        "user_id": user_id,
+        "status": "NotWatched",
+        "added_timestamp": "2021-01-01T20:00:00+01:00",
+        "watched_or_removed_timestamp": null
+    }))
+    .expect("Creation of the test watch list entry failed.")
+}
+
+fn get_default_event_reminder_offsets() -> Vec<u32> {
+    vec![24 * 60, 60]
+}
+
+fn get_default_veto_period_days() -> u32 {
+    30
+}
+
 const COLOR_ERROR: u64 = 0xff0000; // red
 const COLOR_SUCCESS: u64 = 0x7ef542; // green
 const COLOR_WARNING: u64 = 0xf5d442; // yellow
@@ -89,12 +205,89 @@ const COLOR_BOT: u64 = 0xe91e63; // color of the bot role (pink)
 const COLOR_INFORMATION: u64 = 0x3b88c3; // blue
 
 const MAX_ENTRIES_PER_PAGE: usize = 10;
+const MAX_EMBED_DESCRIPTION_LENGTH: usize = 4000;
+const MAX_MOVIES_PER_AUTO_REFRESH: usize = 10;
+const MAX_ROWS_PER_IMPORT: usize = 100;
+const MAX_REVIEW_LENGTH: usize = 1000;
+const MAX_REVIEWS_PER_PAGE: usize = 3;
+const MAX_STATISTIC_LINES_PER_PAGE: usize = 10;
 const VERSION: &str = "0.5.8";
+const TIMER_INTERVAL_SECONDS: u64 = 10;
+
+/**
+ * Everything that wakes up the main loop: an event of discord, a new connection after the old one was dropped,
+ * or the timer that drives the scheduled jobs
+ */
+enum LoopEvent {
+    Discord(Box<Model::Event>),
+    Reconnected(Model::ReadyEvent),
+    Tick,
+}
+
+/**
+ * Receives the events of discord on a separate thread and passes them to the main loop.
+ * Reconnects when the websocket connection is dropped.
+ */
+fn spawn_event_receiver(mut connection: Connection, sender: mpsc::Sender<LoopEvent>) {
+    std::thread::spawn(move || loop {
+        let loop_event = match connection.recv_event() {
+            Ok(event) => LoopEvent::Discord(Box::new(event)),
+            Err(err) => {
+                println!("[Warning] Receive error: {:?}", err);
+                if let discord::Error::WebSocket(..) = err {
+                    // Try to reconnect when websocket connection is dropped.
+                    // If that doesn't work don't do anything, we'll try again with the next receive error.
+                    match get_default_discord_struct().connect() {
+                        Ok((new_connection, ready_event)) => {
+                            connection = new_connection;
+                            println!("[Ready] Reconnected successfully.");
+                            LoopEvent::Reconnected(ready_event)
+                        }
+                        Err(_) => {
+                            println!("[Warning] Failed to reconnect.");
+                            continue;
+                        }
+                    }
+                } else {
+                    if let discord::Error::Closed(..) = err {
+                        println!("Discord Error Closed");
+                    }
+                    continue;
+                }
+            }
+        };
+
+        // The main loop only stops receiving when the bot shuts down
+        if sender.send(loop_event).is_err() {
+            break;
+        }
+    });
+}
+
+/**
+ * Wakes up the main loop regularly, so the scheduled jobs run on time even if discord sends no events
+ */
+fn spawn_timer(sender: mpsc::Sender<LoopEvent>) {
+    std::thread::spawn(move || loop {
+        std::thread::sleep(std::time::Duration::from_secs(TIMER_INTERVAL_SECONDS));
+
+        if sender.send(LoopEvent::Tick).is_err() {
+            break;
+        }
+    });
+}
 
 fn main() {
+    // The export can also be run offline on the data file: discord_movie_night export <list> <format> <file>
+    let arguments: Vec<String> = std::env::args().collect();
+    if arguments.get(1).map(|argument| argument.as_str()) == Some("export") {
+        export_behaviour::run_command_line_export(&arguments[2..]);
+        return;
+    }
+
     let bot = get_default_discord_struct();
 
-    let (mut connection, ready_event) = bot
+    let (connection, ready_event) = bot
         .connect()
         .expect("Establishing connection to server failed");
 
@@ -118,6 +311,9 @@ fn main() {
                 bot: state_user.bot,
             };
             bot_data.tmdb = tmdb;
+
+            // Votes stored before votes had ids get one now, without reusing the ids of archived votes
+            voting_behaviour::assign_missing_vote_ids(&mut bot_data);
         }
         Err(string) => {
             println!("{}\n", string);
@@ -144,10 +340,28 @@ fn main() {
                     custom_prefix: '.',
                     tmdb: tmdb,
                     wait_for_reaction: vec![],
+                    refresh_jobs: vec![],
+                    import_jobs: vec![],
                     votes: HashMap::new(),
                     movie_limit_per_user: 10,
                     movie_vote_limit: 2,
                     adding_movie: None,
+                    auto_refresh_days: 0,
+                    auto_refresh_channel_id: 0,
+                    events: HashMap::new(),
+                    next_event_id: 0,
+                    event_reminder_offsets: get_default_event_reminder_offsets(),
+                    missed_nights_limit: 0,
+                    seen_share_limit: 0,
+                    seen_policy: seen_behaviour::SeenPolicy::default(),
+                    tie_break_policy: tie_break_behaviour::TieBreakPolicy::default(),
+                    vote_archive: HashMap::new(),
+                    next_vote_id: 0,
+                    selection_policy: selection_behaviour::SelectionPolicy::default(),
+                    selection_statistics: selection_behaviour::SelectionStatistics::default(),
+                    veto_limit: 0,
+                    veto_period_days: get_default_veto_period_days(),
+                    vetoes: Vec::new(),
                 };
                 println!("Bot is running now.");
             } else {
@@ -157,9 +371,19 @@ fn main() {
         }
     };
 
+    // The events of discord and the ticks of the timer arrive in the same channel, so the
+    // scheduled jobs below run at least every TIMER_INTERVAL_SECONDS, no matter how quiet the server is
+    let (sender, receiver) = mpsc::channel();
+    spawn_timer(sender.clone());
+    spawn_event_receiver(connection, sender);
+
     let thirty_seconds = std::time::Duration::from_secs(30);
+    let one_minute = std::time::Duration::from_secs(60);
     let one_hour = std::time::Duration::from_secs(3600);
     let mut last_save = std::time::Instant::now();
+    let mut last_auto_refresh = std::time::Instant::now();
+    let mut last_rating_check = std::time::Instant::now();
+    let mut last_reminder_check = std::time::Instant::now();
     let mut something_changed = false;
 
     loop {
@@ -174,6 +398,58 @@ fn main() {
             }
         }
 
+        // Refresh the data of movies that have not been refreshed for a while
+        if last_auto_refresh.elapsed() >= one_hour {
+            last_auto_refresh = std::time::Instant::now();
+            movie_behaviour::refresh_stale_movies(&mut bot_data);
+        }
+
+        // Replace the data of the movies whose refresh finished in the meantime
+        if movie_behaviour::finish_refresh_jobs(&mut bot_data) {
+            something_changed = true;
+        }
+
+        // Send the summaries of the imports that were resolved in the meantime
+        if import_behaviour::finish_import_jobs(&mut bot_data) {
+            something_changed = true;
+        }
+
+        // Close the rating messages that were sent too long ago
+        if last_rating_check.elapsed() >= one_hour {
+            last_rating_check = std::time::Instant::now();
+
+            if rating_behaviour::close_expired_rating_messages(&mut bot_data) {
+                something_changed = true;
+            }
+        }
+
+        // Close the votes whose deadline passed on every tick, so they close within seconds of the deadline
+        if voting_behaviour::close_expired_votes(&mut bot_data) {
+            something_changed = true;
+        }
+
+        // Send the reminders of upcoming movie nights and votes, update the countdowns of the votes, remove the movie nights that are over
+        // and end the snoozes that ran out
+        if last_reminder_check.elapsed() >= one_minute {
+            last_reminder_check = std::time::Instant::now();
+
+            if event_behaviour::send_due_reminders(&mut bot_data) {
+                something_changed = true;
+            }
+
+            if event_behaviour::remove_past_events(&mut bot_data) {
+                something_changed = true;
+            }
+
+            if voting_behaviour::update_vote_deadlines(&mut bot_data) {
+                something_changed = true;
+            }
+
+            if snooze_behaviour::end_expired_snoozes(&mut bot_data) {
+                something_changed = true;
+            }
+        }
+
         // See if an add_movie command is waiting too long
         if let Some(start_time) = bot_data.adding_movie {
             if start_time.elapsed() >= thirty_seconds {
@@ -209,25 +485,15 @@ fn main() {
             }
         }
 
-        let event = match connection.recv_event() {
-            Ok(event) => event,
-            Err(err) => {
-                println!("[Warning] Receive error: {:?}", err);
-                if let discord::Error::WebSocket(..) = err {
-                    // Try to reconnect when websocket connection is dropped.
-                    // If that doesn't work don't do anything, we'll try again in the next loop
-                    // iteration.
-                    if let Ok((_connection, ready_event)) = bot_data.bot.connect() {
-                        state = State::new(ready_event);
-                        println!("[Ready] Reconnected successfully.");
-                    } else {
-                        println!("[Warning] Failed to reconnect.");
-                    }
-                } else if let discord::Error::Closed(..) = err {
-                    println!("Discord Error Closed");
-                }
+        let event = match receiver.recv() {
+            Ok(LoopEvent::Discord(event)) => *event,
+            Ok(LoopEvent::Reconnected(ready_event)) => {
+                state = State::new(ready_event);
                 continue;
             }
+            // The scheduled jobs at the start of the loop are all a tick has to trigger
+            Ok(LoopEvent::Tick) => continue,
+            Err(_) => break,
         };
 
         state.update(&event);
@@ -289,6 +555,12 @@ fn main() {
                     continue;
                 }
 
+                // Answers to events are stored with the event, so they keep working after a restart
+                if event_behaviour::handle_rsvp_reaction(&mut bot_data, &reaction) {
+                    something_changed = true;
+                    continue;
+                }
+
                 // Determine if a command is waiting for a reaction
                 if bot_data.wait_for_reaction.len() > 0 {
                     for waiting_idx in 0..bot_data.wait_for_reaction.len() {
@@ -327,12 +599,13 @@ fn main() {
                                     break;
                                 }
                             }
-                            WaitingForReaction::AddMovieToWatched(message, movie) => {
+                            WaitingForReaction::AddMovieToWatched(message, movie, participants) => {
                                 if reaction.message_id == message.id {
                                     movie_behaviour::handle_add_movie_to_watched_after_movie_vote(
                                         &mut bot_data,
                                         &reaction,
                                         &movie,
+                                        &participants,
                                     );
 
                                     // Vote does not get removed from the wait_for_reaction vector since
@@ -374,15 +647,124 @@ fn main() {
                                     something_changed = true;
                                 }
                             }
+                            WaitingForReaction::Import(message, pending_import) => {
+                                if reaction.message_id == message.id {
+                                    // Only the user who started the import can confirm it
+                                    if import_behaviour::handle_import_reaction(
+                                        &mut bot_data,
+                                        &reaction,
+                                        &pending_import,
+                                    ) {
+                                        general_behaviour::remove_reactions_on_message(
+                                            &bot_data,
+                                            &message,
+                                            vec!["✅", "❎"],
+                                        );
+                                        bot_data.wait_for_reaction.remove(waiting_idx);
+                                        something_changed = true;
+                                    }
+                                    break;
+                                }
+                            }
+                            WaitingForReaction::TextPagination(message, title, pages, curr_page) => {
+                                if reaction.message_id == message.id {
+                                    let new_page = general_behaviour::handle_text_pagination_reaction(
+                                        &bot_data,
+                                        &reaction,
+                                        title.as_str(),
+                                        &pages,
+                                        curr_page,
+                                    );
+
+                                    // Store the new page, so the next reaction continues from there
+                                    bot_data.wait_for_reaction[waiting_idx] =
+                                        WaitingForReaction::TextPagination(message, title, pages, new_page);
+                                    break;
+                                }
+                            }
+                            WaitingForReaction::Rating(message, id) => {
+                                if reaction.message_id == message.id {
+                                    rating_behaviour::handle_rating_reaction(
+                                        &mut bot_data,
+                                        &reaction,
+                                        id,
+                                    );
+
+                                    // The rating message stays open, so members can rate later on
+                                    something_changed = true;
+                                    break;
+                                }
+                            }
+                            WaitingForReaction::Seen(message, id) => {
+                                if reaction.message_id == message.id {
+                                    seen_behaviour::handle_seen_reaction(&mut bot_data, &reaction, id, true);
+
+                                    // The message stays open, so other members can mark the movie as seen as well
+                                    something_changed = true;
+                                    break;
+                                }
+                            }
+                            WaitingForReaction::AddWatchedMovie(message, new_entry, vote_message_id) => {
+                                if reaction.message_id == message.id {
+                                    if movie_behaviour::add_watched_movie_by_reaction(
+                                        &mut bot_data,
+                                        &reaction,
+                                        &new_entry,
+                                        vote_message_id,
+                                    ) {
+                                        general_behaviour::remove_reactions_on_message(
+                                            &bot_data,
+                                            &message,
+                                            vec!["✅", "❎"],
+                                        );
+                                        bot_data.wait_for_reaction.remove(waiting_idx);
+                                        something_changed = true;
+                                    }
+                                    break;
+                                }
+                            }
                         }
                     }
                 }
             }
+            Model::Event::ReactionRemove(reaction) => {
+                // If the reaction was removed by the bot itself skip this event
+                if reaction.user_id == state.user().id {
+                    continue;
+                }
+
+                if event_behaviour::handle_rsvp_reaction_removed(&mut bot_data, &reaction) {
+                    something_changed = true;
+                    continue;
+                }
+
+                // Removing the reaction of the current score withdraws the rating
+                let rated_movie_id = bot_data.wait_for_reaction.iter().find_map(|waiting| match waiting {
+                    general_behaviour::WaitingForReaction::Rating(message, id) if message.id == reaction.message_id => Some(*id),
+                    _ => None,
+                });
+
+                if let Some(id) = rated_movie_id {
+                    if rating_behaviour::handle_rating_reaction_removed(&mut bot_data, &reaction, id) {
+                        something_changed = true;
+                    }
+                    continue;
+                }
+
+                // Removing the seen or the interest reaction takes the mark back
+                let seen_movie_id = bot_data.wait_for_reaction.iter().find_map(|waiting| match waiting {
+                    general_behaviour::WaitingForReaction::Seen(message, id) if message.id == reaction.message_id => Some(*id),
+                    _ => None,
+                });
+
+                if let Some(id) = seen_movie_id {
+                    seen_behaviour::handle_seen_reaction(&mut bot_data, &reaction, id, false);
+                    something_changed = true;
+                }
+            }
             _ => {}
         }
     }
-
-    let _ = connection.shutdown();
 }
 
 /**
@@ -411,7 +793,7 @@ fn handle_command(bot_data: &mut BotData, command: Command) {
         RemoveMovieByTitle(title) => {
             movie_behaviour::remove_movie_by_title(bot_data, title.as_str())
         }
-        ShowWatchlist(order) => watch_list_behaviour::show_watch_list(bot_data, order),
+        ShowWatchlist(order, descending, filters) => watch_list_behaviour::show_watch_list(bot_data, order, descending, filters),
         Help(simple_command) => match simple_command {
             SimpleCommand::General => help_behaviour::show_help(bot_data),
             SimpleCommand::Help => help_behaviour::show_help_help(bot_data),
@@ -438,6 +820,28 @@ fn handle_command(bot_data: &mut BotData, command: Command) {
             SimpleCommand::Info => help_behaviour::show_help_info(bot_data),
             SimpleCommand::Save => help_behaviour::show_help_save(bot_data),
             SimpleCommand::Count => help_behaviour::show_help_count_movies(bot_data),
+            SimpleCommand::Refresh => help_behaviour::show_help_refresh(bot_data),
+            SimpleCommand::Export => help_behaviour::show_help_export(bot_data),
+            SimpleCommand::Import => help_behaviour::show_help_import(bot_data),
+            SimpleCommand::Rate => help_behaviour::show_help_rate(bot_data),
+            SimpleCommand::Review => help_behaviour::show_help_review(bot_data),
+            SimpleCommand::Statistics => help_behaviour::show_help_statistics(bot_data),
+            SimpleCommand::Profile => help_behaviour::show_help_profile(bot_data),
+            SimpleCommand::Event => help_behaviour::show_help_event(bot_data),
+            SimpleCommand::Attended => help_behaviour::show_help_attended(bot_data),
+            SimpleCommand::MissedNightsLimit => help_behaviour::show_help_missed_nights_limit(bot_data),
+            SimpleCommand::Seen => help_behaviour::show_help_seen(bot_data),
+            SimpleCommand::SeenLimit => help_behaviour::show_help_seen_limit(bot_data),
+            SimpleCommand::Rank => help_behaviour::show_help_rank(bot_data),
+            SimpleCommand::Ballot => help_behaviour::show_help_ballot(bot_data),
+            SimpleCommand::TieBreak => help_behaviour::show_help_tie_break(bot_data),
+            SimpleCommand::Vote => help_behaviour::show_help_vote(bot_data),
+            SimpleCommand::Votes => help_behaviour::show_help_votes(bot_data),
+            SimpleCommand::SelectionPolicy => help_behaviour::show_help_selection_policy(bot_data),
+            SimpleCommand::Veto => help_behaviour::show_help_veto(bot_data),
+            SimpleCommand::Vetoes => help_behaviour::show_help_vetoes(bot_data),
+            SimpleCommand::VetoLimit => help_behaviour::show_help_veto_limit(bot_data),
+            SimpleCommand::Snooze => help_behaviour::show_help_snooze(bot_data),
             SimpleCommand::Unknown(parameters) => {
                 let _ = bot_data.bot.send_embed(
                     bot_data.message.clone().unwrap().channel_id,
@@ -454,32 +858,78 @@ fn handle_command(bot_data: &mut BotData, command: Command) {
             }
         },
         Prefix(new_prefix) => general_behaviour::set_new_prefix(bot_data, new_prefix),
-        History(order) => history_behaviour::show_history(bot_data, order, true),
+        History(order, descending, filters) => history_behaviour::show_history(bot_data, order, descending, filters),
         SetStatus(id, status) => movie_behaviour::set_status(bot_data, id, status),
         Unavailable(id) => movie_behaviour::set_status(bot_data, id, "Unavailable".to_string()),
         Watched(id, date) => movie_behaviour::set_status_watched(bot_data, id, date),
         ShowMovieById(id) => movie_behaviour::show_movie_by_id(bot_data, id),
         ShowMovieByTitle(title) => movie_behaviour::show_movie_by_title(bot_data, title),
         SearchMovie(title) => movie_behaviour::search_movie(bot_data, title.as_str(), false),
-        CreateVote(title, options) => {
-            voting_behaviour::create_vote(bot_data, title, options, false)
+        CreateVote(title, options, settings) => {
+            voting_behaviour::create_vote(bot_data, title, options, false, settings)
         }
-        SendVote => voting_behaviour::determine_vote_and_send_details_message(bot_data, None),
+        SendVote => voting_behaviour::determine_vote_and_send_details_message(bot_data, None, None),
         SendVoteWithUserId(user_id) => {
-            voting_behaviour::determine_vote_and_send_details_message(bot_data, Some(user_id))
+            voting_behaviour::determine_vote_and_send_details_message(bot_data, Some(user_id), None)
+        }
+        SendVoteWithId(vote_id) => {
+            voting_behaviour::determine_vote_and_send_details_message(bot_data, None, Some(vote_id))
         }
-        CloseVote => voting_behaviour::close_vote(bot_data),
+        CloseVote(vote_id) => voting_behaviour::close_vote(bot_data, vote_id),
         SetMovieLimit(number) => movie_behaviour::set_movie_limit(bot_data, number),
         ShowMovieLimit => movie_behaviour::show_movie_limit(bot_data),
         SetMovieVoteLimit(number) => voting_behaviour::set_movie_vote_limit(bot_data, number),
         ShowMovieVoteLimit => voting_behaviour::show_movie_vote_limit(bot_data),
-        RandomMovieVote(optional_limit) => {
-            voting_behaviour::create_random_movie_vote(bot_data, optional_limit)
+        RandomMovieVote(optional_limit, settings) => {
+            voting_behaviour::create_random_movie_vote(bot_data, optional_limit, settings)
         }
+        RandomMovieVoteDryRun(optional_limit) => selection_behaviour::show_selection_dry_run(bot_data, optional_limit),
         CloseMovieVote => voting_behaviour::close_random_movie_vote(bot_data),
         Info => send_message::info(bot_data),
         Save => serde_behaviour::store_bot_data(bot_data),
         Count => movie_behaviour::count_movies(bot_data),
+        RefreshMovie(id) => movie_behaviour::refresh_movie(bot_data, id),
+        RefreshAllMovies => movie_behaviour::refresh_all_movies(bot_data),
+        SetAutoRefresh(days) => movie_behaviour::set_auto_refresh(bot_data, days),
+        ShowAutoRefresh => movie_behaviour::show_auto_refresh(bot_data),
+        Export(list, format) => export_behaviour::export_list(bot_data, list, format),
+        Import => import_behaviour::import_movies(bot_data),
+        ChooseImportOption(row, option) => import_behaviour::choose_import_option(bot_data, row, option),
+        Rate(id, score) => rating_behaviour::rate_movie(bot_data, id, score),
+        WriteReview(id, user_id, text) => review_behaviour::write_review(bot_data, id, user_id, text),
+        DeleteReview(id, user_id) => review_behaviour::delete_review(bot_data, id, user_id),
+        ShowReviews(user_id) => review_behaviour::show_user_reviews(bot_data, user_id),
+        Statistics => statistics_behaviour::show_statistics(bot_data),
+        Profile(user_id) => profile_behaviour::show_profile(bot_data, user_id),
+        CreateEvent(date, time, title) => event_behaviour::create_event(bot_data, date, time, title),
+        CancelEvent(id) => event_behaviour::cancel_event(bot_data, id),
+        ShowEvents => event_behaviour::show_events(bot_data),
+        SetEventReminders(offsets) => event_behaviour::set_event_reminders(bot_data, offsets),
+        ShowEventReminders => event_behaviour::show_event_reminders(bot_data),
+        AddAttended(id, user_ids) => attendance_behaviour::add_attended(bot_data, id, user_ids),
+        RemoveAttended(id, user_ids) => attendance_behaviour::remove_attended(bot_data, id, user_ids),
+        SetMissedNightsLimit(limit) => attendance_behaviour::set_missed_nights_limit(bot_data, limit),
+        ShowMissedNightsLimit => attendance_behaviour::show_missed_nights_limit(bot_data),
+        MarkSeen(id, seen) => seen_behaviour::mark_seen(bot_data, id, seen),
+        SetSeenLimit(share, policy) => seen_behaviour::set_seen_limit(bot_data, share, policy),
+        ShowSeenLimit => seen_behaviour::show_seen_limit(bot_data),
+        Rank(vote_id, emojis) => voting_behaviour::rank_vote_options(bot_data, vote_id, emojis),
+        Ballot(vote_id, emojis) => voting_behaviour::cast_ballot(bot_data, vote_id, emojis),
+        SetTieBreakPolicy(policy) => tie_break_behaviour::set_tie_break_policy(bot_data, policy),
+        ShowTieBreakPolicy => tie_break_behaviour::show_tie_break_policy(bot_data),
+        ShowVoteHistory => vote_history_behaviour::show_vote_history(bot_data),
+        ShowArchivedVote(id) => vote_history_behaviour::show_archived_vote(bot_data, id),
+        AddVoteOption(vote_id, option) => voting_behaviour::add_vote_option(bot_data, vote_id, option),
+        RemoveVoteOption(vote_id, emoji) => voting_behaviour::remove_vote_option(bot_data, vote_id, emoji),
+        RenameVote(vote_id, title) => voting_behaviour::rename_vote(bot_data, vote_id, title),
+        SetSelectionPolicy(setting) => selection_behaviour::set_selection_policy(bot_data, setting),
+        ShowSelectionPolicy => selection_behaviour::show_selection_policy(bot_data),
+        ShowOwnerAppearances => selection_behaviour::show_owner_appearances(bot_data),
+        Veto(vote_id, emoji) => voting_behaviour::veto(bot_data, vote_id, emoji),
+        ShowVetoes => veto_behaviour::show_vetoes(bot_data),
+        SetVetoLimit(limit, period_days) => veto_behaviour::set_veto_limit(bot_data, limit, period_days),
+        ShowVetoLimit => veto_behaviour::show_veto_limit(bot_data),
+        Snooze(id, end) => snooze_behaviour::snooze_movie(bot_data, id, end),
         Quit => todo!("What needs to happen when the Quit command is received?"),
     }
 }
@@ -519,10 +969,37 @@ fn handle_error(bot_data: &BotData, error: ParseCommandError) {
         }
         NoArgumentsForShowMovie => help_behaviour::show_help_show_movie(bot_data),
         NoArgumentsForSearchMovie => help_behaviour::show_help_search_movie(bot_data),
-        NoArgumentsForCreateVote => help_behaviour::show_help_create_vote(bot_data),
+        NoArgumentsForCreateVote | WrongArgumentsForCreateVote => help_behaviour::show_help_create_vote(bot_data),
         WrongArgumentsForMovieLimit => help_behaviour::show_help_movie_limit(bot_data),
         WrongArgumentsForMovieVoteLimit => help_behaviour::show_help_movie_vote_limit(bot_data),
         WrongArgumentsForSendVoteWithUserId => help_behaviour::show_help_send_vote(bot_data),
+        WrongArgumentsForCloseVote => help_behaviour::show_help_close_vote(bot_data),
         WrongArgumentForRandomMovieVote => help_behaviour::show_help_random_movie_vote(bot_data),
+        NoArgumentsForRefresh | WrongArgumentsForRefresh => {
+            help_behaviour::show_help_refresh(bot_data)
+        }
+        NotEnoughArgumentsForExport | WrongArgumentsForExport => {
+            help_behaviour::show_help_export(bot_data)
+        }
+        WrongArgumentsForImport => help_behaviour::show_help_import(bot_data),
+        NotEnoughArgumentsForRate | WrongArgumentsForRate => help_behaviour::show_help_rate(bot_data),
+        NotEnoughArgumentsForReview | WrongArgumentsForReview | WrongArgumentsForReviews => {
+            help_behaviour::show_help_review(bot_data)
+        }
+        WrongArgumentsForProfile => help_behaviour::show_help_profile(bot_data),
+        NotEnoughArgumentsForEvent | WrongArgumentsForEvent => help_behaviour::show_help_event(bot_data),
+        NotEnoughArgumentsForAttended | WrongArgumentsForAttended => help_behaviour::show_help_attended(bot_data),
+        WrongArgumentsForMissedNightsLimit => help_behaviour::show_help_missed_nights_limit(bot_data),
+        NotEnoughArgumentsForSeen | WrongArgumentsForSeen => help_behaviour::show_help_seen(bot_data),
+        WrongArgumentsForSeenLimit => help_behaviour::show_help_seen_limit(bot_data),
+        NotEnoughArgumentsForRank | WrongArgumentsForRank => help_behaviour::show_help_rank(bot_data),
+        NotEnoughArgumentsForBallot | WrongArgumentsForBallot => help_behaviour::show_help_ballot(bot_data),
+        WrongArgumentsForTieBreak => help_behaviour::show_help_tie_break(bot_data),
+        WrongArgumentsForVotes => help_behaviour::show_help_votes(bot_data),
+        NotEnoughArgumentsForVote | WrongArgumentsForVote => help_behaviour::show_help_vote(bot_data),
+        WrongArgumentsForSelectionPolicy => help_behaviour::show_help_selection_policy(bot_data),
+        NotEnoughArgumentsForVeto | WrongArgumentsForVeto => help_behaviour::show_help_veto(bot_data),
+        WrongArgumentsForVetoLimit => help_behaviour::show_help_veto_limit(bot_data),
+        NotEnoughArgumentsForSnooze | WrongArgumentsForSnooze => help_behaviour::show_help_snooze(bot_data),
     }
 }
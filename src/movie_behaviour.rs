@@ -1,7 +1,8 @@
 use discord::{model as Model};
 use chrono::DateTime;
 use std::collections::HashMap;
-use std::{fmt, cmp::Ordering, str::FromStr};
+use std::{fmt, cmp::Ordering, str::FromStr, sync::mpsc};
+use itertools::Itertools;
 use crate::{COLOR_ERROR, COLOR_SUCCESS, COLOR_BOT, COLOR_INFORMATION};
 use crate::general_behaviour::*;
 use crate::send_message;
@@ -89,6 +90,27 @@ pub struct WatchListEntry {
     pub status: MovieStatus,
     pub added_timestamp: DateTime<chrono::FixedOffset>,
     pub watched_or_removed_timestamp: Option<DateTime<chrono::FixedOffset>>,
+
+    #[serde(default)]
+    pub ratings: HashMap<u64, u8>, // Keys are the user ids, values the scores from 1 to 10
+
+    #[serde(default)]
+    pub reviews: HashMap<u64, crate::review_behaviour::Review>, // Keys are the user ids of the authors
+
+    #[serde(default)]
+    pub vote_wins: u32, // How often the movie won a random movie vote
+
+    #[serde(default)]
+    pub attendees: Vec<u64>, // User ids of the members who watched the movie together
+
+    #[serde(default)]
+    pub seen_by: Vec<u64>, // User ids of the members who have already seen the movie on their own
+
+    #[serde(default)]
+    pub interested: Vec<u64>, // User ids of the members who are interested in watching the movie
+
+    #[serde(default)]
+    pub snoozed_until: Option<DateTime<chrono::FixedOffset>>, // Random movie votes skip the movie until then
 }
 
 impl Ord for WatchListEntry {
@@ -109,7 +131,7 @@ impl PartialEq for WatchListEntry {
     }
 }
 
-#[derive(Eq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Movie {
     pub movie_title: String,
     pub original_title: String,
@@ -121,8 +143,29 @@ pub struct Movie {
     pub genres: String,
     pub runtime: u32,
     pub budget: String,
+
+    #[serde(default)]
+    pub genre_list: Vec<String>,
+
+    #[serde(default)]
+    pub budget_value: u64,
+
+    #[serde(default)]
+    pub revenue: u64,
+
+    #[serde(default)]
+    pub vote_average: f64,
+
+    #[serde(default)]
+    pub last_refreshed: Option<DateTime<chrono::FixedOffset>>,
+
+    #[serde(default)]
+    pub imdb_id: Option<String>,
 }
 
+// Movies are compared by their title only, so the float field does not prevent Eq
+impl Eq for Movie {}
+
 impl Ord for Movie {
     fn cmp(&self, other: &Self) -> Ordering {
         self.movie_title.cmp(&other.movie_title)
@@ -143,10 +186,209 @@ impl PartialEq for Movie {
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum SortedMovieList {
-    WatchListUserSorted(/*total_pages*/ usize, Vec<UserSortedMovieListVectorEntry>),
-    WatchListIdSorted(/*total_pages*/ usize, Vec<(u32, WatchListEntry)>),
-    HistoryUserSorted(/*total_pages*/ usize, Vec<UserSortedMovieListVectorEntry>),
-    HistoryDateSorted(/*total_pages*/ usize, Vec<(u32, WatchListEntry)>),
+    WatchListUserSorted(/*total_pages*/ usize, Vec<UserSortedMovieListVectorEntry>, Vec<crate::filter_behaviour::MovieFilter>),
+    WatchListSorted(/*total_pages*/ usize, MovieSortOrder, /*descending*/ bool, Vec<(u32, WatchListEntry)>, Vec<crate::filter_behaviour::MovieFilter>),
+    HistoryUserSorted(/*total_pages*/ usize, Vec<UserSortedMovieListVectorEntry>, Vec<crate::filter_behaviour::MovieFilter>),
+    HistorySorted(/*total_pages*/ usize, MovieSortOrder, /*descending*/ bool, Vec<(u32, WatchListEntry)>, Vec<crate::filter_behaviour::MovieFilter>),
+}
+
+impl SortedMovieList {
+    /**
+     * Returns the total number of pages of the list
+     */
+    pub fn total_pages(&self) -> usize {
+        match self {
+            SortedMovieList::WatchListUserSorted(total_pages, _, _) => *total_pages,
+            SortedMovieList::WatchListSorted(total_pages, _, _, _, _) => *total_pages,
+            SortedMovieList::HistoryUserSorted(total_pages, _, _) => *total_pages,
+            SortedMovieList::HistorySorted(total_pages, _, _, _, _) => *total_pages,
+        }
+    }
+
+    /**
+     * Returns true if the list shows the history instead of the watch list
+     */
+    pub fn is_history(&self) -> bool {
+        matches!(self, SortedMovieList::HistoryUserSorted(_, _, _) | SortedMovieList::HistorySorted(_, _, _, _, _))
+    }
+
+    /**
+     * Returns the title for the embedded message of the list
+     */
+    pub fn get_title(&self) -> &str {
+        if self.is_history() {
+            "Verlauf"
+        } else {
+            "Filmliste"
+        }
+    }
+
+    /**
+     * Generates the description text of the given page
+     */
+    pub fn generate_page_string(&self, page_to_show: usize) -> String {
+        match self {
+            SortedMovieList::WatchListUserSorted(_, user_sorted_watch_list, filters) => 
+                crate::watch_list_behaviour::generate_user_sorted_watch_list_page_string(
+                    user_sorted_watch_list,
+                    filters,
+                    page_to_show
+                ),
+            SortedMovieList::WatchListSorted(_, order, descending, sorted_watch_list, filters) => 
+                crate::watch_list_behaviour::generate_sorted_watch_list_page_string(
+                    sorted_watch_list,
+                    order,
+                    *descending,
+                    filters,
+                    page_to_show
+                ),
+            SortedMovieList::HistoryUserSorted(_, user_sorted_history, filters) => 
+                crate::history_behaviour::generate_user_sorted_history_page_string(
+                    user_sorted_history,
+                    filters,
+                    page_to_show
+                ),
+            SortedMovieList::HistorySorted(_, order, descending, sorted_history, filters) => 
+                crate::history_behaviour::generate_sorted_history_page_string(
+                    sorted_history,
+                    order,
+                    *descending,
+                    filters,
+                    page_to_show
+                ),
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum MovieSortOrder {
+    Id,
+    User,
+    Date,
+    Title,
+    Year,
+    Runtime,
+    Added,
+    Rating,
+    Language,
+}
+
+impl MovieSortOrder {
+    /**
+     * Returns the german name of the sort order for the list description
+     */
+    pub fn get_description(&self) -> &str {
+        match self {
+            MovieSortOrder::Id => "ID",
+            MovieSortOrder::User => "Nutzer",
+            MovieSortOrder::Date => "Datum",
+            MovieSortOrder::Title => "Titel",
+            MovieSortOrder::Year => "Erscheinungsjahr",
+            MovieSortOrder::Runtime => "Laufzeit",
+            MovieSortOrder::Added => "Hinzufügedatum",
+            MovieSortOrder::Rating => "TMDb-Bewertung",
+            MovieSortOrder::Language => "Originalsprache",
+        }
+    }
+
+    /**
+     * Returns true if the order should be descending if no direction was given.
+     * The newest watched movies and the best rated movies are shown first.
+     */
+    pub fn is_descending_by_default(&self) -> bool {
+        matches!(self, MovieSortOrder::Date | MovieSortOrder::Rating)
+    }
+
+    /**
+     * Compares two watch list entries by the sort order
+     */
+    pub fn compare(&self, first: &(u32, WatchListEntry), second: &(u32, WatchListEntry)) -> Ordering {
+        match self {
+            MovieSortOrder::Id => first.0.cmp(&second.0),
+            MovieSortOrder::User => first.1.user.to_lowercase().cmp(&second.1.user.to_lowercase()),
+            MovieSortOrder::Date => first.1.watched_or_removed_timestamp.cmp(&second.1.watched_or_removed_timestamp),
+            MovieSortOrder::Title => first.1.movie.movie_title.to_lowercase().cmp(&second.1.movie.movie_title.to_lowercase()),
+            MovieSortOrder::Year => first.1.movie.release_date.cmp(&second.1.movie.release_date),
+            MovieSortOrder::Runtime => first.1.movie.runtime.cmp(&second.1.movie.runtime),
+            MovieSortOrder::Added => first.1.added_timestamp.cmp(&second.1.added_timestamp),
+            MovieSortOrder::Rating => first.1.movie.vote_average
+                .partial_cmp(&second.1.movie.vote_average)
+                .unwrap_or(Ordering::Equal),
+            MovieSortOrder::Language => first.1.movie.original_language.cmp(&second.1.movie.original_language),
+        }
+    }
+
+    /**
+     * Returns the value the entry is sorted by, if it is not already part of the list entry anyway
+     */
+    pub fn get_sort_value_string(&self, entry: &WatchListEntry) -> Option<String> {
+        match self {
+            MovieSortOrder::Year => Some(format!("{}", entry.movie.release_date.format("%Y"))),
+            MovieSortOrder::Runtime => Some(format!("{} Minuten", entry.movie.runtime)),
+            MovieSortOrder::Rating => Some(format_vote_average(entry.movie.vote_average)),
+            MovieSortOrder::Language => Some(entry.movie.original_language.to_uppercase()),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for MovieSortOrder {
+    type Err = MovieSortOrderErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "id" => Self::Id,
+            "user" => Self::User,
+            "date" => Self::Date,
+            "title" => Self::Title,
+            "year" => Self::Year,
+            "runtime" => Self::Runtime,
+            "added" => Self::Added,
+            "rating" => Self::Rating,
+            "lang" | "language" => Self::Language,
+            _ => return Err(MovieSortOrderErr::UnknownOrder),
+        })
+    }
+}
+
+pub enum MovieSortOrderErr {
+    UnknownOrder,
+}
+
+/**
+ * Sorts the entries by the given order. Entries with equal values are sorted by their id.
+ */
+pub fn sort_movie_entries(entries: &mut [(u32, WatchListEntry)], order: MovieSortOrder, descending: bool) {
+    entries.sort_by(|first, second| {
+        let ordering = order.compare(first, second);
+
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+        .then(first.0.cmp(&second.0))
+    });
+}
+
+/**
+ * Watch list id, title and either the changes or the error of a refreshed movie
+ */
+pub type RefreshResult = (u32, String, Result<Vec<String>, String>);
+
+/**
+ * Watch list id and either the newly fetched data or the error of every movie of a refresh job
+ */
+type FetchedMovies = Vec<(u32, Result<Movie, String>)>;
+
+/**
+ * A refresh of several movies whose data is fetched from TMDb on a separate thread, so the bot keeps answering in the meantime
+ */
+pub struct RefreshJob {
+    channel_id: Option<Model::ChannelId>, // The channel the changes are reported to, None if they are reported nowhere
+    automatic: bool,
+    checked_count: usize,
+    receiver: mpsc::Receiver<FetchedMovies>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -187,7 +429,7 @@ pub fn get_movie_link(tmdb_id: u64, watch_link: bool) -> String {
 /**
  * Shortens the overview of a movie to around 300 characters
  */
-fn shorten_movie_description(overview: String) -> String {
+pub fn shorten_movie_description(overview: String) -> String {
     let mut i = 0;
     let mut short_overview = String::from("");
     for character in overview.chars() {
@@ -222,7 +464,63 @@ fn get_genres_formatted(genres: &Vec<tmdb::model::Genre>) -> String {
 }
 
 /**
- * Searches a movie on TMDb and displays its information. 
+ * Converts a movie fetched from TMDb into the internal movie struct. The fallback date is used
+ * as release date if TMDb does not provide a valid one.
+ */
+pub fn movie_from_tmdb(tmdb_movie: &tmdb::model::Movie, fallback_date: DateTime<chrono::FixedOffset>) -> Movie {
+    Movie {
+        movie_title: tmdb_movie.title.clone(),
+        original_title: tmdb_movie.original_title.clone(),
+        original_language: tmdb_movie.original_language.clone().to_uppercase(),
+        overview: tmdb_movie.overview.clone().unwrap_or("Keine Beschreibung verfügbar.".to_string()),
+        poster_path: tmdb_movie.poster_path.clone(),
+        tmdb_id: tmdb_movie.id,
+        genres: get_genres_formatted(&tmdb_movie.genres),
+        runtime: tmdb_movie.runtime,
+        budget: format_budget(tmdb_movie.budget),
+        release_date: parse_tmdb_release_date(tmdb_movie.release_date.clone()).unwrap_or(fallback_date),
+        genre_list: tmdb_movie.genres.iter().map(|genre| genre.name.clone()).collect(),
+        budget_value: tmdb_movie.budget,
+        revenue: tmdb_movie.revenue,
+        vote_average: tmdb_movie.vote_average,
+        last_refreshed: Some(current_timestamp()),
+        imdb_id: tmdb_movie.imdb_id.clone(),
+    }
+}
+
+/**
+ * Fetches a movie from TMDb by its TMDb id or link, or by its IMDb id or link if imdb is true.
+ * Returns a message describing the problem if the movie could not be found.
+ */
+pub fn fetch_movie_by_external_id(bot_data: &crate::BotData, external_id: &str, imdb: bool) -> Result<Movie, String> {
+    let tmdb_id = if imdb {
+        let imdb_id = match parse_imdb_link_id(external_id.to_string()) {
+            Some(imdb_id) => imdb_id,
+            None => return Err(format!("`{}` ist keine gültige IMDb ID.", external_id)),
+        };
+
+        match bot_data.tmdb.find().imdb_id(imdb_id.as_str()).execute() {
+            Ok(result) => match result.movie_results.first() {
+                Some(search_movie) => search_movie.id,
+                None => return Err(format!("Zur IMDb ID `{}` wurde kein Film gefunden.", imdb_id)),
+            },
+            Err(error) => return Err(format!("{}", error)),
+        }
+    } else {
+        match external_id.parse::<u64>().ok().or_else(|| parse_tmdb_link_id(external_id.to_string())) {
+            Some(tmdb_id) => tmdb_id,
+            None => return Err(format!("`{}` ist keine gültige TMDb ID.", external_id)),
+        }
+    };
+
+    match bot_data.tmdb.fetch().id(tmdb_id).execute() {
+        Ok(tmdb_movie) => Ok(movie_from_tmdb(&tmdb_movie, current_timestamp())),
+        Err(error) => Err(format!("{}", error)),
+    }
+}
+
+/**
+ * Searches a movie on TMDb and displays its information.
  */
 pub fn search_movie(bot_data: &mut crate::BotData, title_or_link: &str, add_movie: bool) {
     // Block the add_movie command if another movie gets added already
@@ -354,18 +652,7 @@ pub fn search_movie(bot_data: &mut crate::BotData, title_or_link: &str, add_movi
                 }
             }
 
-            let new_movie = Movie {
-                movie_title: first_movie.title.clone(),
-                original_title: first_movie.original_title.clone(),
-                original_language: first_movie.original_language.clone().to_uppercase(),
-                overview: shorten_movie_description(first_movie.overview.clone().unwrap_or("Keine Beschreibung verfügbar.".to_string())),
-                poster_path: first_movie.poster_path.clone(),
-                tmdb_id: first_movie.id,
-                genres: get_genres_formatted(&first_movie.genres),
-                runtime: first_movie.runtime,
-                budget: format_budget(first_movie.budget),
-                release_date: parse_tmdb_release_date(first_movie.release_date.clone()).unwrap_or(message.timestamp),
-            };
+            let new_movie = movie_from_tmdb(&first_movie, message.timestamp);
 
             let new_entry = WatchListEntry {
                 movie: new_movie,
@@ -374,6 +661,13 @@ pub fn search_movie(bot_data: &mut crate::BotData, title_or_link: &str, add_movi
                 watched_or_removed_timestamp: None,
                 status: MovieStatus::NotWatched,
                 user_id: message.author.id,
+                ratings: HashMap::new(),
+                reviews: HashMap::new(),
+                vote_wins: 0,
+                attendees: Vec::new(),
+                seen_by: Vec::new(),
+                interested: Vec::new(),
+                snoozed_until: None,
             };
 
             let bot_response = send_message::movie_information(bot_data, &new_entry, true, add_movie, false);
@@ -467,6 +761,11 @@ pub fn add_movie_by_reaction(bot_data: &mut crate::BotData, reaction: &discord::
             movie: new_entry.movie.clone(),
             user: new_entry.user.clone(),
             status: new_entry.status.clone(),
+            ratings: new_entry.ratings.clone(),
+            reviews: new_entry.reviews.clone(),
+            attendees: new_entry.attendees.clone(),
+            seen_by: new_entry.seen_by.clone(),
+            interested: new_entry.interested.clone(),
             ..*new_entry
         };
         bot_data.watch_list.insert(bot_data.next_movie_id, copied_entry);
@@ -565,12 +864,7 @@ pub fn handle_watch_list_message_pagination_reaction(
         let mut new_page: usize = 0;
         let watch_list_string: String;
 
-        let total_pages = match sorted_movie_list {
-            SortedMovieList::WatchListUserSorted(total_pages, _) => total_pages,
-            SortedMovieList::WatchListIdSorted(total_pages, _) => total_pages,
-            SortedMovieList::HistoryUserSorted(total_pages, _) => total_pages,
-            SortedMovieList::HistoryDateSorted(total_pages, _) => total_pages,
-        };
+        let total_pages = sorted_movie_list.total_pages();
 
         // Check the emoji from the reaction and calculate the new page to show
         if emoji == String::from("⬅️") {
@@ -589,38 +883,13 @@ pub fn handle_watch_list_message_pagination_reaction(
         // If the new page is still 0, that means we encountered a page number that would be forbidden
         // In this case, skip the generation of the actual watch list message and don't change anything
         if new_page != 0 {
-            match &sorted_movie_list {
-                SortedMovieList::WatchListUserSorted(_, user_sorted_watch_list) => {
-                    watch_list_string = crate::watch_list_behaviour::generate_user_sorted_watch_list_page_string(
-                        &user_sorted_watch_list,
-                        new_page
-                    );
-                },
-                SortedMovieList::WatchListIdSorted(_, id_sorted_watch_list) => {
-                    watch_list_string = crate::watch_list_behaviour::generate_id_sorted_watch_list_page_string(
-                        &id_sorted_watch_list, 
-                        new_page
-                    );
-                },
-                SortedMovieList::HistoryUserSorted(_, user_sorted_history) => {
-                    watch_list_string = crate::history_behaviour::generate_user_sorted_history_page_string(
-                        &user_sorted_history,
-                        new_page
-                    );
-                },
-                SortedMovieList::HistoryDateSorted(_, date_sorted_history) => {
-                    watch_list_string = crate::history_behaviour::generate_date_sorted_history_page_string(
-                        &date_sorted_history,
-                        new_page
-                    );
-                }
-            }
+            watch_list_string = sorted_movie_list.generate_page_string(new_page);
 
             let _ = bot_data.bot.edit_embed(
                 reaction.channel_id,
                 message.id,
                 |embed| embed
-                    .title("Filmliste")
+                    .title(sorted_movie_list.get_title())
                     .description(watch_list_string.as_str())
                     .color(COLOR_BOT)
                     .footer(|footer| footer.text(format!("Seite {}/{}", new_page, total_pages).as_str()))
@@ -628,7 +897,7 @@ pub fn handle_watch_list_message_pagination_reaction(
 
             // Check if the watch list gets paginated or the history instead
             match sorted_movie_list {
-                SortedMovieList::WatchListIdSorted(_, _) | SortedMovieList::WatchListUserSorted(_, _) => {
+                SortedMovieList::WatchListSorted(_, _, _, _, _) | SortedMovieList::WatchListUserSorted(_, _, _) => {
                     // Find the entry in wait_for_reaction and delete it
                     if let Some((idx, _)) = bot_data.wait_for_reaction.iter().enumerate()
                     .find(|(_, element)| if let crate::general_behaviour::WaitingForReaction::WatchListPagination(_, _, _) = element {
@@ -650,7 +919,7 @@ pub fn handle_watch_list_message_pagination_reaction(
                         )
                     );
                 },
-                SortedMovieList::HistoryDateSorted(_, _) | SortedMovieList::HistoryUserSorted(_, _) => {
+                SortedMovieList::HistorySorted(_, _, _, _, _) | SortedMovieList::HistoryUserSorted(_, _, _) => {
                     // Find the entry in wait_for_reaction and delete it
                     if let Some((idx, _)) = bot_data.wait_for_reaction.iter().enumerate()
                     .find(|(_, element)| if let crate::general_behaviour::WaitingForReaction::HistoryPagination(_, _, _) = element {
@@ -698,6 +967,11 @@ pub fn set_status(bot_data: &mut crate::BotData, id: u32, status: String) {
                         movie: watch_list_entry.movie.clone(),
                         status: new_status.clone(),
                         user: watch_list_entry.user.clone(),
+                        ratings: watch_list_entry.ratings.clone(),
+                        reviews: watch_list_entry.reviews.clone(),
+                        attendees: watch_list_entry.attendees.clone(),
+                        seen_by: watch_list_entry.seen_by.clone(),
+                        interested: watch_list_entry.interested.clone(),
                         ..*watch_list_entry
                     };
 
@@ -705,8 +979,26 @@ pub fn set_status(bot_data: &mut crate::BotData, id: u32, status: String) {
                         updated_entry.watched_or_removed_timestamp = Some(message.timestamp);
                     }
 
+                    // Movies of a movie night were watched at the date of the event by everybody who signed up
+                    if new_status == MovieStatus::Watched {
+                        if let Some(event) = crate::event_behaviour::get_latest_event_of_movie(bot_data, id) {
+                            updated_entry.watched_or_removed_timestamp = Some(event.date);
+                            crate::attendance_behaviour::add_attendees(
+                                &mut updated_entry,
+                                &event.get_user_ids(crate::event_behaviour::Rsvp::Yes)
+                            );
+                        }
+                    }
+
+                    let newly_watched = new_status == MovieStatus::Watched && watch_list_entry.status != MovieStatus::Watched;
+
                     send_message::status_changed_successfully(bot_data);
                     let _ = bot_data.watch_list.insert(id, updated_entry);
+
+                    // Ask everybody to rate the movie that was just watched
+                    if newly_watched {
+                        crate::rating_behaviour::send_rating_message(bot_data, id);
+                    }
                 // If the user is not an admin and did not add the movie himself he is not permitted to change it
                 } else {
                     send_message::insufficient_permissions_error(bot_data);
@@ -754,11 +1046,23 @@ pub fn set_status_watched(bot_data: &mut crate::BotData, id: u32, date: String)
                             status: new_status.clone(),
                             user: watch_list_entry.user.clone(),
                             watched_or_removed_timestamp: Some(datetime),
+                            ratings: watch_list_entry.ratings.clone(),
+                            reviews: watch_list_entry.reviews.clone(),
+                            attendees: watch_list_entry.attendees.clone(),
+                            seen_by: watch_list_entry.seen_by.clone(),
+                            interested: watch_list_entry.interested.clone(),
                             ..*watch_list_entry
                         };
 
+                        let newly_watched = watch_list_entry.status != MovieStatus::Watched;
+
                         send_message::status_changed_successfully(bot_data);
                         let _ = bot_data.watch_list.insert(id, updated_entry);
+
+                        // Ask everybody to rate the movie that was just watched
+                        if newly_watched {
+                            crate::rating_behaviour::send_rating_message(bot_data, id);
+                        }
                     // If the user is not an admin and did not add the movie himself he is not permitted to change it
                     } else {
                         send_message::insufficient_permissions_error(bot_data);
@@ -785,9 +1089,16 @@ pub fn set_status_watched(bot_data: &mut crate::BotData, id: u32, date: String)
 /**
  * Shows the movie information to the movie id or an error message if the id does not exist
  */
-pub fn show_movie_by_id(bot_data: &crate::BotData, id: u32) {
+pub fn show_movie_by_id(bot_data: &mut crate::BotData, id: u32) {
     if let Some(entry) = bot_data.watch_list.get(&id) {
-        let _ = send_message::movie_information(bot_data, entry, false, false, false);
+        let entry = entry.clone();
+        if let Ok(message) = send_message::movie_information(bot_data, &entry, false, false, false) {
+            // Movies on the watch list can be marked as seen with a reaction
+            if entry.status.is_watch_list_status() {
+                crate::seen_behaviour::add_seen_reaction(bot_data, message, id);
+            }
+        }
+        crate::review_behaviour::show_movie_reviews(bot_data, &entry);
     } else {
         send_message::movie_id_not_found_error(bot_data, &id);
     }
@@ -796,7 +1107,7 @@ pub fn show_movie_by_id(bot_data: &crate::BotData, id: u32) {
 /**
  * Shows the movie information to the movie title or an error message if th etitle does not exist
  */
-pub fn show_movie_by_title(bot_data: &crate::BotData, title: String) {
+pub fn show_movie_by_title(bot_data: &mut crate::BotData, title: String) {
     if let Some(id) = get_movie_id_in_watch_list(title.as_str(), &bot_data.watch_list) {
         return show_movie_by_id(bot_data, id);
     } else {
@@ -894,13 +1205,20 @@ pub fn get_three_earliest_movie_ids(bot_data: &crate::BotData) -> Vec<&u32> {
 /**
  * Handles the case, that after the closing of the random movie vote a movie should become watched or not
  */
-pub fn handle_add_movie_to_watched_after_movie_vote(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, movie: &Movie) {
+pub fn handle_add_movie_to_watched_after_movie_vote(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, movie: &Movie, participants: &[u64]) {
     if let discord::model::ReactionEmoji::Unicode(emoji) = &reaction.emoji {
         if emoji == "✅" {
             if let Some(watch_list_id) = find_id_by_tmdb_id(movie.tmdb_id, &bot_data.watch_list.clone()) {
                 // If the id was found, try to set the status to watched
                 set_status_watched(bot_data, *watch_list_id, String::new());
                 remove_set_status_watched_from_wait_for_reaction(bot_data, &reaction.message_id);
+
+                // The participants of the vote only count as attendees once the movie was actually watched
+                if let Some(entry) = bot_data.watch_list.get_mut(watch_list_id) {
+                    if entry.status == MovieStatus::Watched {
+                        crate::attendance_behaviour::add_attendees(entry, participants);
+                    }
+                }
             } else {
                 send_message::movie_not_found_in_watchlist_error(bot_data, movie.movie_title.clone());
             }
@@ -915,35 +1233,87 @@ pub fn handle_add_movie_to_watched_after_movie_vote(bot_data: &mut crate::BotDat
     send_message::emoji_not_part_of_vote_info(bot_data);
 }
 
+/**
+ * Handles the reaction to the offer to add a movie that won a vote but was not on the watch list.
+ * With ✅ the movie is added with the status watched, with ❎ the offer is declined. Only the creator of the vote
+ * or an administrator may answer. Movies of random movie votes belong to the administrator who accepted the offer
+ * and are linked to the movie night of the vote. Returns true if the offer was answered.
+ */
+pub fn add_watched_movie_by_reaction(
+    bot_data: &mut crate::BotData,
+    reaction: &discord::model::Reaction,
+    new_entry: &WatchListEntry,
+    vote_message_id: u64,
+) -> bool {
+    // Random movie votes are created by the bot, so only administrators can answer their offers
+    if reaction.user_id != new_entry.user_id && !is_user_administrator(bot_data, reaction.user_id) {
+        return false;
+    }
+
+    if reaction_emoji_equals(&reaction.emoji, "✅".to_string()) {
+        // The movie could have been added in the meantime
+        if let Some(&id) = find_id_by_tmdb_id(new_entry.movie.tmdb_id, &bot_data.watch_list) {
+            send_message::movie_already_exists(bot_data, id, new_entry.movie.tmdb_id);
+            return true;
+        }
+
+        // The bot can not own movies, so the movie belongs to the administrator who accepted it
+        let mut new_entry = new_entry.clone();
+        if new_entry.user_id == bot_data.bot_user.id {
+            match bot_data.bot.get_member(bot_data.server_id, reaction.user_id) {
+                Ok(member) => {
+                    new_entry.user_id = member.user.id;
+                    new_entry.user = member.user.name;
+                },
+                // Without the name of the new owner the offer stays open
+                Err(_) => return false,
+            }
+        }
+
+        let id = bot_data.next_movie_id;
+        bot_data.watch_list.insert(id, new_entry.clone());
+        bot_data.next_movie_id += 1;
+
+        crate::event_behaviour::link_winner_to_event(bot_data, vote_message_id, id);
+
+        send_message::watched_movie_added_successfully(bot_data, id, &new_entry);
+        crate::rating_behaviour::send_rating_message(bot_data, id);
+        true
+    } else if reaction_emoji_equals(&reaction.emoji, "❎".to_string()) {
+        send_message::movie_not_added_to_watched_information(bot_data);
+        true
+    } else {
+        false
+    }
+}
+
 /**
  * Counts the movies in the watch list that have a watch list status, and belong to the user
  * that sent the last message, and sends a message containing the information.
  */
 pub fn count_movies(bot_data: &crate::BotData) {
     let author_id = bot_data.message.as_ref().expect("Passing of message to count_movies function failed.").author.id;
-
-    let count = bot_data.watch_list.iter()
-        .filter(|(_, entry)|
-            // Only count the movie if it has a watch list status and was added by this user
-            if entry.status.is_watch_list_status() 
-                && entry.user_id == author_id {
-                true
-            } else {
-                false
-            }
-        )
-        .count();
+    let count = count_user_watch_list_movies(bot_data, author_id);
     
     send_message::current_user_movie_count(bot_data, count);
 }
 
+/**
+ * Counts the movies of the user that have a watch list status and therefore count towards the movie limit
+ */
+pub fn count_user_watch_list_movies(bot_data: &crate::BotData, user_id: Model::UserId) -> usize {
+    bot_data.watch_list.iter()
+        .filter(|(_, entry)| entry.status.is_watch_list_status() && entry.user_id == user_id)
+        .count()
+}
+
 /**
  * Finds the previous vote message in the wait_for_reaction vector of bot_data and removes the entry
  */
 fn remove_set_status_watched_from_wait_for_reaction(bot_data: &mut crate::BotData, previous_message_id: &discord::model::MessageId) {
     // Remove previous wait_for_reaction of previous vote
     for i in 0..bot_data.wait_for_reaction.len() {
-        if let crate::general_behaviour::WaitingForReaction::AddMovieToWatched(some_message, _) = &bot_data.wait_for_reaction[i] {
+        if let crate::general_behaviour::WaitingForReaction::AddMovieToWatched(some_message, _, _) = &bot_data.wait_for_reaction[i] {
             if *previous_message_id == some_message.id {
                 bot_data.wait_for_reaction.remove(i);
                 break;
@@ -951,3 +1321,290 @@ fn remove_set_status_watched_from_wait_for_reaction(bot_data: &mut crate::BotDat
         }
     }
 }
+
+/**
+ * Compares the stored data of a movie with freshly fetched data and describes every difference
+ */
+fn get_movie_changes(old_movie: &Movie, new_movie: &Movie) -> Vec<String> {
+    let mut changes: Vec<String> = Vec::new();
+
+    if old_movie.movie_title != new_movie.movie_title {
+        changes.push(format!("Titel: *{}* → *{}*", old_movie.movie_title, new_movie.movie_title));
+    }
+
+    let old_release_date = timestamp_to_string(&old_movie.release_date, false);
+    let new_release_date = timestamp_to_string(&new_movie.release_date, false);
+    if old_release_date != new_release_date {
+        changes.push(format!("Erschienen: {} → {}", old_release_date, new_release_date));
+    }
+
+    if old_movie.poster_path != new_movie.poster_path {
+        changes.push(String::from("Neues Poster"));
+    }
+
+    if old_movie.runtime != new_movie.runtime {
+        changes.push(format!("Dauer: {} min → {} min", old_movie.runtime, new_movie.runtime));
+    }
+
+    if old_movie.genres != new_movie.genres {
+        changes.push(format!("Genres: {} → {}", old_movie.genres, new_movie.genres));
+    }
+
+    if old_movie.budget != new_movie.budget {
+        changes.push(format!("Budget: {} → {}", old_movie.budget, new_movie.budget));
+    }
+
+    if old_movie.revenue != new_movie.revenue {
+        changes.push(format!("Einnahmen: {} → {}", format_budget(old_movie.revenue), format_budget(new_movie.revenue)));
+    }
+
+    if (old_movie.vote_average - new_movie.vote_average).abs() >= 0.1 {
+        changes.push(format!("TMDb-Bewertung: {} → {}", format_vote_average(old_movie.vote_average), format_vote_average(new_movie.vote_average)));
+    }
+
+    // Movies added before the full description was stored only hold its shortened version
+    if old_movie.overview != new_movie.overview && old_movie.overview != shorten_movie_description(new_movie.overview.clone()) {
+        changes.push(String::from("Neue Beschreibung"));
+    }
+
+    changes
+}
+
+/**
+ * Formats the TMDb vote average with one decimal place, or as unknown if TMDb has no votes
+ */
+pub fn format_vote_average(vote_average: f64) -> String {
+    if vote_average <= 0.0 {
+        String::from("Unbekannt")
+    } else {
+        format!("{:.1}/10", vote_average)
+    }
+}
+
+/**
+ * Fetches the current TMDb data of the movie with the given id and replaces the stored data with it.
+ * Returns the changes or the formatted error, if the movie could not be fetched.
+ */
+fn refresh_movie_data(bot_data: &mut crate::BotData, id: u32) -> Result<Vec<String>, String> {
+    let old_movie = match bot_data.watch_list.get(&id) {
+        Some(entry) => entry.movie.clone(),
+        None => return Err(format!("Ein Film mit der ID `{:0>4}` existiert nicht.", id)),
+    };
+
+    let fetch_result = bot_data.tmdb
+        .fetch()
+        .id(old_movie.tmdb_id)
+        .execute();
+
+    match fetch_result {
+        // Keep the previous release date if TMDb does not provide a valid one anymore
+        Ok(tmdb_movie) => Ok(replace_movie_data(bot_data, id, movie_from_tmdb(&tmdb_movie, old_movie.release_date))),
+        Err(error) => Err(format!("{}", error)),
+    }
+}
+
+/**
+ * Replaces the stored data of the movie with the given id and returns the changes
+ */
+fn replace_movie_data(bot_data: &mut crate::BotData, id: u32, new_movie: Movie) -> Vec<String> {
+    match bot_data.watch_list.get_mut(&id) {
+        Some(entry) => {
+            let changes = get_movie_changes(&entry.movie, &new_movie);
+            entry.movie = new_movie;
+            changes
+        },
+        None => Vec::new(),
+    }
+}
+
+/**
+ * Starts to fetch the current TMDb data of the given movies on a separate thread, since every movie needs
+ * its own request. Once all movies are fetched, their data is replaced by 'finish_refresh_jobs'.
+ */
+fn start_refresh_job(bot_data: &mut crate::BotData, ids: Vec<u32>, channel_id: Option<Model::ChannelId>, automatic: bool) {
+    let movies: Vec<(u32, u64, DateTime<chrono::FixedOffset>)> = ids.iter()
+        .filter_map(|id| bot_data.watch_list.get(id).map(|entry| (*id, entry.movie.tmdb_id, entry.movie.release_date)))
+        .collect();
+
+    let (sender, receiver) = mpsc::channel();
+    std::thread::spawn(move || {
+        let tmdb = crate::get_tmdb_struct();
+        let fetched = movies.into_iter()
+            .map(|(id, tmdb_id, release_date)| {
+                // Keep the previous release date if TMDb does not provide a valid one anymore
+                let result = tmdb.fetch().id(tmdb_id).execute()
+                    .map(|tmdb_movie| movie_from_tmdb(&tmdb_movie, release_date))
+                    .map_err(|error| format!("{}", error));
+                (id, result)
+            })
+            .collect();
+
+        let _ = sender.send(fetched);
+    });
+
+    bot_data.refresh_jobs.push(RefreshJob {
+        channel_id,
+        automatic,
+        checked_count: ids.len(),
+        receiver,
+    });
+}
+
+/**
+ * Replaces the data of the movies whose refresh finished in the meantime and reports the movies that either
+ * changed or could not be refreshed. Returns true if a refresh was finished.
+ */
+pub fn finish_refresh_jobs(bot_data: &mut crate::BotData) -> bool {
+    let mut finished: Vec<(RefreshJob, FetchedMovies)> = Vec::new();
+    let mut running: Vec<RefreshJob> = Vec::new();
+
+    for job in std::mem::take(&mut bot_data.refresh_jobs) {
+        match job.receiver.try_recv() {
+            Ok(fetched) => finished.push((job, fetched)),
+            Err(mpsc::TryRecvError::Empty) => running.push(job),
+            // Without a result there is nothing to replace
+            Err(mpsc::TryRecvError::Disconnected) => finished.push((job, Vec::new())),
+        }
+    }
+    bot_data.refresh_jobs = running;
+
+    let something_finished = !finished.is_empty();
+    let now = current_timestamp();
+
+    for (job, fetched) in finished {
+        let mut results: Vec<RefreshResult> = Vec::new();
+
+        for (id, result) in fetched {
+            let result = match result {
+                Ok(new_movie) => Ok(replace_movie_data(bot_data, id, new_movie)),
+                // Movies that failed during the automatic refresh are marked as refreshed anyway, so they are not requested again every hour
+                Err(_) if job.automatic => {
+                    if let Some(entry) = bot_data.watch_list.get_mut(&id) {
+                        entry.movie.last_refreshed = Some(now);
+                    }
+                    continue;
+                },
+                Err(error) => Err(error),
+            };
+
+            // Movies that are already up to date are not part of the result
+            if let Ok(changes) = &result {
+                if changes.is_empty() {
+                    continue;
+                }
+            }
+
+            if let Some(entry) = bot_data.watch_list.get(&id) {
+                results.push((id, entry.movie.movie_title.clone(), result));
+            }
+        }
+
+        // The automatic refresh only reports changes
+        if let Some(channel_id) = job.channel_id {
+            if !job.automatic || !results.is_empty() {
+                send_message::movies_refreshed_summary(bot_data, channel_id, job.checked_count, &results);
+            }
+        }
+    }
+
+    something_finished
+}
+
+/**
+ * Refreshes the TMDb data of a single movie and sends a message containing all changes
+ */
+pub fn refresh_movie(bot_data: &mut crate::BotData, id: u32) {
+    if !bot_data.watch_list.contains_key(&id) {
+        return send_message::movie_id_not_found_error(bot_data, &id);
+    }
+
+    match refresh_movie_data(bot_data, id) {
+        Ok(changes) => {
+            let title = bot_data.watch_list.get(&id).map(|entry| entry.movie.movie_title.clone()).unwrap_or_default();
+            send_message::movie_refreshed_information(bot_data, id, title, &changes);
+        },
+        Err(error) => send_message::refreshing_movie_failed_error(bot_data, id, error),
+    }
+}
+
+/**
+ * Starts to refresh the TMDb data of all movies on the watch list. The summary of all changes is sent once all movies are fetched.
+ * Only administrators are allowed to do this, since every movie needs its own request to TMDb.
+ */
+pub fn refresh_all_movies(bot_data: &mut crate::BotData) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to refresh_all_movies function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    let ids: Vec<u32> = bot_data.watch_list.iter()
+        .filter(|(_, entry)| entry.status.is_watch_list_status())
+        .map(|(id, _)| *id)
+        .sorted()
+        .collect();
+
+    let channel_id = bot_data.message.as_ref().expect("Passing message to refresh_all_movies function failed.").channel_id;
+    let movie_count = ids.len();
+    start_refresh_job(bot_data, ids, Some(channel_id), false);
+    send_message::refresh_started_information(bot_data, channel_id, movie_count);
+}
+
+/**
+ * Refreshes the movies on the watch list whose data is older than the configured amount of days.
+ * At most MAX_MOVIES_PER_AUTO_REFRESH movies are refreshed per call to keep the requests to TMDb low.
+ */
+pub fn refresh_stale_movies(bot_data: &mut crate::BotData) {
+    // The next movies are only requested once the previous automatic refresh finished
+    if bot_data.auto_refresh_days == 0 || bot_data.refresh_jobs.iter().any(|job| job.automatic) {
+        return;
+    }
+
+    let now = current_timestamp();
+    let max_age = chrono::Duration::days(bot_data.auto_refresh_days as i64);
+
+    let stale_ids: Vec<u32> = bot_data.watch_list.iter()
+        .filter(|(_, entry)| entry.status.is_watch_list_status())
+        .filter(|(_, entry)| match entry.movie.last_refreshed {
+            Some(last_refreshed) => now.signed_duration_since(last_refreshed) >= max_age,
+            None => true,
+        })
+        .map(|(id, _)| *id)
+        .sorted()
+        .take(crate::MAX_MOVIES_PER_AUTO_REFRESH)
+        .collect();
+
+    if stale_ids.is_empty() {
+        return;
+    }
+
+    // The job only reports to the channel the automatic refresh was set up in
+    let channel_id = match bot_data.auto_refresh_channel_id {
+        0 => None,
+        channel_id => Some(Model::ChannelId(channel_id)),
+    };
+    start_refresh_job(bot_data, stale_ids, channel_id, true);
+}
+
+/**
+ * Sets the amount of days after which the data of a movie is refreshed automatically. 0 disables the refresh.
+ * The changes found by the automatic refresh are reported to the channel of the command.
+ */
+pub fn set_auto_refresh(bot_data: &mut crate::BotData, days: u32) {
+    let message = bot_data.message.clone().expect("Passing of message to set_auto_refresh failed.");
+
+    if !is_user_administrator(bot_data, message.author.id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    bot_data.auto_refresh_days = days;
+    bot_data.auto_refresh_channel_id = if days == 0 { 0 } else { message.channel_id.0 };
+    send_message::auto_refresh_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current setting of the automatic refresh
+ */
+pub fn show_auto_refresh(bot_data: &crate::BotData) {
+    send_message::auto_refresh_information(bot_data, false);
+}
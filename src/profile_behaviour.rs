@@ -0,0 +1,100 @@
+use crate::attendance_behaviour::{get_attendance_stats, AttendanceStats};
+use crate::movie_behaviour::{count_user_watch_list_movies, MovieStatus};
+use crate::send_message;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/**
+ * The number of genres that are shown as favourite genres
+ */
+const FAVOURITE_GENRE_COUNT: usize = 3;
+
+/**
+ * All statistics about the movies of one member
+ */
+pub struct UserProfile {
+    pub user_id: u64,
+    pub user_name: String,
+    pub watch_list_count: usize,
+    pub added_count: usize,
+    pub watched_count: usize,
+    pub vote_wins: u32,
+    pub ratings_given: Vec<u8>,
+    pub ratings_received: Vec<u8>,
+    pub favourite_genres: Vec<(String, usize)>,
+    pub attendance: AttendanceStats,
+}
+
+/**
+ * Formats the average of the scores with the number of ratings, e.g. 7.5/10 (4 Bewertungen)
+ */
+pub fn format_average_score(scores: &[u8]) -> String {
+    if scores.is_empty() {
+        return String::from("Noch keine Bewertungen");
+    }
+
+    let sum: u32 = scores.iter().map(|score| *score as u32).sum();
+    format!(
+        "{:.1}/10 ({} {})",
+        sum as f64 / scores.len() as f64,
+        scores.len(),
+        if scores.len() == 1 { "Bewertung" } else { "Bewertungen" }
+    )
+}
+
+/**
+ * Collects the profile of the user from the watch list
+ */
+fn create_user_profile(bot_data: &crate::BotData, user_id: u64, user_name: String) -> UserProfile {
+    let user_entries: Vec<_> = bot_data.watch_list.values()
+        .filter(|entry| entry.user_id.0 == user_id)
+        .collect();
+
+    let mut genre_counts: HashMap<String, usize> = HashMap::new();
+    for genre in user_entries.iter().flat_map(|entry| crate::filter_behaviour::get_genre_names(entry)) {
+        if !genre.is_empty() {
+            *genre_counts.entry(genre).or_insert(0) += 1;
+        }
+    }
+
+    UserProfile {
+        user_id,
+        user_name,
+        watch_list_count: count_user_watch_list_movies(bot_data, discord::model::UserId(user_id)),
+        added_count: user_entries.len(),
+        watched_count: user_entries.iter().filter(|entry| entry.status == MovieStatus::Watched).count(),
+        vote_wins: user_entries.iter().map(|entry| entry.vote_wins).sum(),
+        ratings_given: bot_data.watch_list.values()
+            .filter_map(|entry| entry.ratings.get(&user_id).copied())
+            .collect(),
+        ratings_received: user_entries.iter()
+            .flat_map(|entry| entry.ratings.values().copied())
+            .collect(),
+        favourite_genres: genre_counts.into_iter()
+            .sorted_by(|first, second| second.1.cmp(&first.1).then_with(|| first.0.cmp(&second.0)))
+            .take(FAVOURITE_GENRE_COUNT)
+            .collect(),
+        attendance: get_attendance_stats(bot_data, user_id),
+    }
+}
+
+/**
+ * Shows the profile of the mentioned user or of the author of the message
+ */
+pub fn show_profile(bot_data: &crate::BotData, user_id: Option<u64>) {
+    let author = &bot_data.message.as_ref().expect("Passing message to show_profile function failed.").author;
+    let user_id = user_id.unwrap_or(author.id.0);
+
+    // The name of other users is taken from their movies, since the bot does not know all members
+    let user_name = if user_id == author.id.0 {
+        author.name.clone()
+    } else {
+        bot_data.watch_list.values()
+            .find(|entry| entry.user_id.0 == user_id)
+            .map(|entry| entry.user.clone())
+            .unwrap_or_default()
+    };
+
+    let profile = create_user_profile(bot_data, user_id, user_name);
+    send_message::user_profile(bot_data, &profile);
+}
@@ -0,0 +1,173 @@
+use crate::general_behaviour::{current_timestamp, remove_reactions_on_message, WaitingForReaction};
+use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+use crate::send_message;
+
+/**
+ * The reactions of the rating message. The index plus one is the score.
+ */
+pub const RATING_EMOJIS: [&str; 10] = ["1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣", "6️⃣", "7️⃣", "8️⃣", "9️⃣", "🔟"];
+
+/**
+ * How many days a rating message accepts reactions. Afterwards members can still rate with the rate command.
+ */
+const RATING_MESSAGE_DAYS: i64 = 7;
+
+/**
+ * Returns the score of a rating reaction, or None if the emoji is no rating emoji
+ */
+fn get_score_from_emoji(emoji: &discord::model::ReactionEmoji) -> Option<u8> {
+    if let discord::model::ReactionEmoji::Unicode(emoji) = emoji {
+        RATING_EMOJIS.iter()
+            .position(|rating_emoji| rating_emoji == emoji)
+            .map(|idx| idx as u8 + 1)
+    } else {
+        None
+    }
+}
+
+/**
+ * Returns the average score of all members that rated the movie, or None if nobody rated it yet
+ */
+pub fn get_average_rating(entry: &WatchListEntry) -> Option<f64> {
+    if entry.ratings.is_empty() {
+        return None;
+    }
+
+    let sum: u32 = entry.ratings.values().map(|score| *score as u32).sum();
+    Some(sum as f64 / entry.ratings.len() as f64)
+}
+
+/**
+ * Formats the average rating with the number of ratings, e.g. 7.5/10 (4 Bewertungen)
+ */
+pub fn format_average_rating(entry: &WatchListEntry) -> String {
+    match get_average_rating(entry) {
+        Some(average) => format!(
+            "{:.1}/10 ({} {})",
+            average,
+            entry.ratings.len(),
+            if entry.ratings.len() == 1 { "Bewertung" } else { "Bewertungen" }
+        ),
+        None => String::from("Noch keine Bewertungen"),
+    }
+}
+
+/**
+ * Sends a message that asks all members to rate the watched movie with the number reactions
+ */
+pub fn send_rating_message(bot_data: &mut crate::BotData, id: u32) {
+    let entry = match bot_data.watch_list.get(&id) {
+        Some(entry) => entry,
+        None => return,
+    };
+
+    if let Ok(rating_message) = send_message::rating_request(bot_data, id, entry) {
+        for emoji in RATING_EMOJIS.iter() {
+            let _ = bot_data.bot.add_reaction(
+                rating_message.channel_id,
+                rating_message.id,
+                discord::model::ReactionEmoji::Unicode(emoji.to_string())
+            );
+        }
+
+        // Only the newest rating message of a movie accepts reactions
+        bot_data.wait_for_reaction.retain(|waiting| !matches!(waiting, WaitingForReaction::Rating(_, rated_id) if *rated_id == id));
+        bot_data.wait_for_reaction.push(WaitingForReaction::Rating(rating_message, id));
+    }
+}
+
+/**
+ * Stops waiting for reactions on the rating messages that are older than RATING_MESSAGE_DAYS and removes their reactions.
+ * Returns true if a rating message was closed.
+ */
+pub fn close_expired_rating_messages(bot_data: &mut crate::BotData) -> bool {
+    let expired_before = current_timestamp() - chrono::Duration::days(RATING_MESSAGE_DAYS);
+
+    let expired_messages: Vec<discord::model::Message> = bot_data.wait_for_reaction.iter()
+        .filter_map(|waiting| match waiting {
+            WaitingForReaction::Rating(message, _) if message.timestamp < expired_before => Some(message.clone()),
+            _ => None,
+        })
+        .collect();
+
+    for message in expired_messages.iter() {
+        remove_reactions_on_message(bot_data, message, RATING_EMOJIS.to_vec());
+    }
+
+    bot_data.wait_for_reaction.retain(|waiting| !matches!(waiting, WaitingForReaction::Rating(message, _) if message.timestamp < expired_before));
+
+    !expired_messages.is_empty()
+}
+
+/**
+ * Stores the score of the reaction as rating of the user. If the user rated before, the previous
+ * reaction gets removed, so only the current score is visible.
+ */
+pub fn handle_rating_reaction(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, id: u32) {
+    let score = match get_score_from_emoji(&reaction.emoji) {
+        Some(score) => score,
+        None => {
+            let _ = bot_data.bot.delete_reaction(reaction.channel_id, reaction.message_id, Some(reaction.user_id), reaction.emoji.clone());
+            return;
+        }
+    };
+
+    if let Some(entry) = bot_data.watch_list.get_mut(&id) {
+        let previous_score = entry.ratings.insert(reaction.user_id.0, score);
+
+        if let Some(previous_score) = previous_score {
+            if previous_score != score {
+                let _ = bot_data.bot.delete_reaction(
+                    reaction.channel_id,
+                    reaction.message_id,
+                    Some(reaction.user_id),
+                    discord::model::ReactionEmoji::Unicode(RATING_EMOJIS[previous_score as usize - 1].to_string())
+                );
+            }
+        }
+    }
+}
+
+/**
+ * Withdraws the rating of the user if the removed reaction is the current score of the user.
+ * Reactions of previous scores are removed by the bot itself and leave the rating untouched.
+ * Returns true if the rating was withdrawn.
+ */
+pub fn handle_rating_reaction_removed(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, id: u32) -> bool {
+    let score = match get_score_from_emoji(&reaction.emoji) {
+        Some(score) => score,
+        None => return false,
+    };
+
+    match bot_data.watch_list.get_mut(&id) {
+        Some(entry) if entry.ratings.get(&reaction.user_id.0) == Some(&score) => {
+            entry.ratings.remove(&reaction.user_id.0);
+            true
+        }
+        _ => false,
+    }
+}
+
+/**
+ * Rates a watched movie with the given score from 1 to 10 for the author of the message
+ */
+pub fn rate_movie(bot_data: &mut crate::BotData, id: u32, score: u8) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to rate_movie function failed.").author.id;
+
+    if !(1..=10).contains(&score) {
+        return send_message::rating_out_of_range_error(bot_data);
+    }
+
+    match bot_data.watch_list.get_mut(&id) {
+        Some(entry) => {
+            if entry.status != MovieStatus::Watched {
+                return send_message::movie_not_watched_yet_error(bot_data, id);
+            }
+
+            entry.ratings.insert(author_id.0, score);
+            let entry = entry.clone();
+            send_message::movie_rated_successfully(bot_data, id, &entry, score);
+        },
+        None => send_message::movie_id_not_found_error(bot_data, &id),
+    }
+}
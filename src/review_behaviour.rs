@@ -0,0 +1,173 @@
+use crate::general_behaviour::{current_timestamp, is_user_administrator, send_paginated_message, split_into_pages, timestamp_to_string};
+use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+use crate::send_message;
+use chrono::DateTime;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Review {
+    pub user_name: String,
+    pub text: String,
+    pub created_timestamp: DateTime<chrono::FixedOffset>,
+    pub edited_timestamp: Option<DateTime<chrono::FixedOffset>>,
+}
+
+/**
+ * Formats the date of the review and whether it was edited
+ */
+fn format_review_date(review: &Review) -> String {
+    match review.edited_timestamp {
+        Some(edited_timestamp) => format!(
+            "{} (bearbeitet am {})",
+            timestamp_to_string(&review.created_timestamp, false),
+            timestamp_to_string(&edited_timestamp, false)
+        ),
+        None => timestamp_to_string(&review.created_timestamp, false),
+    }
+}
+
+/**
+ * Writes or edits a review. Without a target user the author of the message writes his own review.
+ * Reviews of other users can only be edited by administrators.
+ */
+pub fn write_review(bot_data: &mut crate::BotData, id: u32, target_user_id: Option<u64>, text: String) {
+    let message = bot_data.message.as_ref().expect("Passing message to write_review function failed.");
+    let author_id = message.author.id;
+    let author_name = message.author.name.clone();
+    let user_id = target_user_id.unwrap_or(author_id.0);
+
+    if user_id != author_id.0 && !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if text.chars().count() > crate::MAX_REVIEW_LENGTH {
+        return send_message::review_too_long_error(bot_data);
+    }
+
+    let entry = match bot_data.watch_list.get_mut(&id) {
+        Some(entry) => entry,
+        None => return send_message::movie_id_not_found_error(bot_data, &id),
+    };
+
+    if entry.status != MovieStatus::Watched {
+        return send_message::movie_not_watched_yet_error(bot_data, id);
+    }
+
+    let edited = match entry.reviews.get_mut(&user_id) {
+        Some(review) => {
+            review.text = text;
+            review.edited_timestamp = Some(current_timestamp());
+            true
+        },
+        None => {
+            // Administrators can only edit existing reviews of other users, not write new ones in their name
+            if user_id != author_id.0 {
+                return send_message::review_not_found_error(bot_data, id, user_id);
+            }
+
+            entry.reviews.insert(user_id, Review {
+                user_name: author_name,
+                text,
+                created_timestamp: current_timestamp(),
+                edited_timestamp: None,
+            });
+            false
+        },
+    };
+
+    let movie_title = entry.movie.movie_title.clone();
+    send_message::review_saved_successfully(bot_data, id, movie_title, edited);
+}
+
+/**
+ * Deletes a review. Without a target user the author of the message deletes his own review.
+ * Reviews of other users can only be deleted by administrators.
+ */
+pub fn delete_review(bot_data: &mut crate::BotData, id: u32, target_user_id: Option<u64>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to delete_review function failed.").author.id;
+    let user_id = target_user_id.unwrap_or(author_id.0);
+
+    if user_id != author_id.0 && !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    let entry = match bot_data.watch_list.get_mut(&id) {
+        Some(entry) => entry,
+        None => return send_message::movie_id_not_found_error(bot_data, &id),
+    };
+
+    if entry.reviews.remove(&user_id).is_some() {
+        let movie_title = entry.movie.movie_title.clone();
+        send_message::review_deleted_successfully(bot_data, id, movie_title);
+    } else {
+        send_message::review_not_found_error(bot_data, id, user_id);
+    }
+}
+
+/**
+ * Sends the paginated reviews of a movie, if it has any
+ */
+pub fn show_movie_reviews(bot_data: &mut crate::BotData, entry: &WatchListEntry) {
+    if entry.reviews.is_empty() {
+        return;
+    }
+
+    let channel_id = bot_data.message.as_ref().expect("Passing message to show_movie_reviews function failed.").channel_id;
+
+    let reviews: Vec<String> = entry.reviews.iter()
+        .sorted_by_key(|(_, review)| review.created_timestamp)
+        .map(|(user_id, review)| format!(
+            "**{}**{} | {}\n> {}\n",
+            review.user_name,
+            entry.ratings.get(user_id).map(|score| format!(" ⭐ {}/10", score)).unwrap_or_default(),
+            format_review_date(review),
+            review.text.replace('\n', "\n> ")
+        ))
+        .collect();
+
+    send_paginated_message(
+        bot_data,
+        channel_id,
+        format!("Reviews zu {}", entry.movie.movie_title),
+        split_into_pages(&reviews, crate::MAX_REVIEWS_PER_PAGE)
+    );
+}
+
+/**
+ * Sends all reviews the user has written, sorted by the date the movies were watched
+ */
+pub fn show_user_reviews(bot_data: &mut crate::BotData, user_id: Option<u64>) {
+    let message = bot_data.message.as_ref().expect("Passing message to show_user_reviews function failed.");
+    let channel_id = message.channel_id;
+    let user_id = user_id.unwrap_or(message.author.id.0);
+
+    let reviews: Vec<String> = bot_data.watch_list.iter()
+        .filter_map(|(id, entry)| entry.reviews.get(&user_id).map(|review| (id, entry, review)))
+        .sorted_by_key(|(_, entry, _)| entry.watched_or_removed_timestamp)
+        .map(|(id, entry, review)| format!(
+            "**{}** (`{:0>4}`){} | {}\n> {}\n",
+            entry.movie.movie_title,
+            id,
+            entry.ratings.get(&user_id).map(|score| format!(" ⭐ {}/10", score)).unwrap_or_default(),
+            format_review_date(review),
+            review.text.replace('\n', "\n> ")
+        ))
+        .collect();
+
+    if reviews.is_empty() {
+        return send_message::user_has_no_reviews_information(bot_data, user_id);
+    }
+
+    let user_name = bot_data.watch_list.values()
+        .find_map(|entry| entry.reviews.get(&user_id))
+        .map(|review| review.user_name.clone())
+        .unwrap_or_default();
+
+    send_paginated_message(
+        bot_data,
+        channel_id,
+        format!("Reviews von {}", user_name),
+        split_into_pages(&reviews, crate::MAX_REVIEWS_PER_PAGE)
+    );
+}
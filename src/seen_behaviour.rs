@@ -0,0 +1,254 @@
+use crate::general_behaviour::{is_user_administrator, remove_reactions_on_message, WaitingForReaction};
+use crate::movie_behaviour::WatchListEntry;
+use crate::send_message;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/**
+ * The reaction under the movie information that marks a movie as seen
+ */
+pub const SEEN_EMOJI: &str = "👁️";
+
+/**
+ * The reaction under the movie information that marks interest in watching a movie
+ */
+pub const INTEREST_EMOJI: &str = "🙋";
+
+/**
+ * The weight of a movie that too many active members have seen, compared to a weight of 1 for all other movies
+ */
+const SEEN_DOWN_WEIGHT: f64 = 0.25;
+
+/**
+ * How random movie votes treat movies that more than the configured share of active members have seen
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeenPolicy {
+    Exclude,
+    #[default]
+    DownWeight,
+}
+
+impl SeenPolicy {
+    pub fn get_description(&self) -> &str {
+        match self {
+            SeenPolicy::Exclude => "nicht in zufällige Filmabstimmungen aufgenommen",
+            SeenPolicy::DownWeight => "seltener in zufällige Filmabstimmungen aufgenommen",
+        }
+    }
+}
+
+impl FromStr for SeenPolicy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "exclude" => Self::Exclude,
+            "weight" | "downweight" => Self::DownWeight,
+            _ => return Err(()),
+        })
+    }
+}
+
+/**
+ * Returns true if more than the configured share of active members has seen the movie
+ */
+fn is_seen_by_too_many(entry: &WatchListEntry, bot_data: &crate::BotData, active_members: &[u64]) -> bool {
+    if bot_data.seen_share_limit == 0 || active_members.is_empty() {
+        return false;
+    }
+
+    let seen_count = entry.seen_by.iter().filter(|user_id| active_members.contains(user_id)).count();
+    seen_count as f64 / active_members.len() as f64 > bot_data.seen_share_limit as f64 / 100.0
+}
+
+/**
+ * Returns the weights of the movies for a random movie vote. Movies that too many active members
+ * have seen get a weight of 0 if they are excluded, or a lower weight otherwise.
+ */
+pub fn get_selection_weights(bot_data: &crate::BotData, entries: &[(&u32, &WatchListEntry)]) -> Vec<f64> {
+    let active_members = crate::attendance_behaviour::get_active_members(bot_data);
+
+    entries.iter()
+        .map(|(_, entry)| {
+            if !is_seen_by_too_many(entry, bot_data, &active_members) {
+                1.0
+            } else if bot_data.seen_policy == SeenPolicy::Exclude {
+                0.0
+            } else {
+                SEEN_DOWN_WEIGHT
+            }
+        })
+        .collect()
+}
+
+/**
+ * Formats the number of members who have seen the movie and who are interested in it for the watch list,
+ * or nothing if nobody has seen it or is interested
+ */
+pub fn format_seen_suffix(entry: &WatchListEntry) -> String {
+    let mut suffix = String::new();
+
+    if !entry.seen_by.is_empty() {
+        suffix.push_str(format!(" | {} {}", SEEN_EMOJI, entry.seen_by.len()).as_str());
+    }
+    if !entry.interested.is_empty() {
+        suffix.push_str(format!(" | {} {}", INTEREST_EMOJI, entry.interested.len()).as_str());
+    }
+
+    suffix
+}
+
+/**
+ * Adds the user to or removes the user from the user ids, e.g. the members who have seen the movie.
+ * Returns false if nothing changed.
+ */
+fn set_user_marked(user_ids: &mut Vec<u64>, user_id: u64, marked: bool) -> bool {
+    let already_marked = user_ids.contains(&user_id);
+
+    if marked && !already_marked {
+        user_ids.push(user_id);
+        user_ids.sort_unstable();
+        true
+    } else if !marked && already_marked {
+        user_ids.retain(|marked_user_id| *marked_user_id != user_id);
+        true
+    } else {
+        false
+    }
+}
+
+/**
+ * Marks a movie on the watch list as seen or not seen by the author of the message
+ */
+pub fn mark_seen(bot_data: &mut crate::BotData, id: u32, seen: bool) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to mark_seen function failed.").author.id;
+
+    match bot_data.watch_list.get_mut(&id) {
+        Some(entry) => {
+            if !entry.status.is_watch_list_status() {
+                return send_message::movie_not_on_watch_list_error(bot_data, id);
+            }
+
+            set_user_marked(&mut entry.seen_by, author_id.0, seen);
+            let entry = entry.clone();
+            send_message::seen_changed_successfully(bot_data, id, &entry, seen);
+        },
+        None => send_message::movie_id_not_found_error(bot_data, &id),
+    }
+}
+
+/**
+ * Adds the seen and the interest reaction to the movie information and waits for members to react
+ */
+pub fn add_seen_reaction(bot_data: &mut crate::BotData, message: discord::model::Message, id: u32) {
+    for emoji in [SEEN_EMOJI, INTEREST_EMOJI].iter() {
+        let _ = bot_data.bot.add_reaction(
+            message.channel_id,
+            message.id,
+            discord::model::ReactionEmoji::Unicode(emoji.to_string())
+        );
+    }
+
+    // Only the newest information of a movie accepts reactions, the previous one loses its reactions
+    let previous_message = bot_data.wait_for_reaction.iter().find_map(|waiting| match waiting {
+        WaitingForReaction::Seen(previous_message, seen_id) if *seen_id == id => Some(previous_message.clone()),
+        _ => None,
+    });
+
+    if let Some(previous_message) = previous_message {
+        remove_reactions_on_message(bot_data, &previous_message, vec![SEEN_EMOJI, INTEREST_EMOJI]);
+        bot_data.wait_for_reaction.retain(|waiting| !matches!(waiting, WaitingForReaction::Seen(_, seen_id) if *seen_id == id));
+    }
+
+    bot_data.wait_for_reaction.push(WaitingForReaction::Seen(message, id));
+}
+
+/**
+ * Marks the movie as seen or not seen, or the member as interested or not interested, when a member adds or removes
+ * the seen or the interest reaction. Other reactions are ignored.
+ */
+pub fn handle_seen_reaction(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, id: u32, added: bool) {
+    use crate::general_behaviour::reaction_emoji_equals;
+
+    if let Some(entry) = bot_data.watch_list.get_mut(&id) {
+        if reaction_emoji_equals(&reaction.emoji, SEEN_EMOJI.to_string()) {
+            set_user_marked(&mut entry.seen_by, reaction.user_id.0, added);
+        } else if reaction_emoji_equals(&reaction.emoji, INTEREST_EMOJI.to_string()) {
+            set_user_marked(&mut entry.interested, reaction.user_id.0, added);
+        }
+    }
+}
+
+/**
+ * Sets the share of active members in percent above which a movie counts as seen by too many,
+ * and how random movie votes treat those movies. Only administrators can change it.
+ */
+pub fn set_seen_limit(bot_data: &mut crate::BotData, share: u32, policy: Option<SeenPolicy>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_seen_limit function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if share > 100 {
+        return send_message::seen_limit_out_of_range_error(bot_data);
+    }
+
+    bot_data.seen_share_limit = share;
+    if let Some(policy) = policy {
+        bot_data.seen_policy = policy;
+    }
+
+    send_message::seen_limit_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current seen limit
+ */
+pub fn show_seen_limit(bot_data: &crate::BotData) {
+    send_message::seen_limit_information(bot_data, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn marks_users_once() {
+        let mut user_ids: Vec<u64> = Vec::new();
+
+        assert!(set_user_marked(&mut user_ids, 5, true));
+        assert!(!set_user_marked(&mut user_ids, 5, true));
+        assert!(set_user_marked(&mut user_ids, 2, true));
+        assert_eq!(user_ids, vec![2, 5]);
+        assert!(set_user_marked(&mut user_ids, 5, false));
+        assert!(!set_user_marked(&mut user_ids, 5, false));
+        assert_eq!(user_ids, vec![2]);
+    }
+
+    #[test]
+    fn weights_movies_that_too_many_active_members_have_seen() {
+        let mut watch_list = std::collections::HashMap::new();
+        for user_id in 1..=4 {
+            watch_list.insert(user_id as u32, crate::get_test_watch_list_entry("Alien", user_id));
+        }
+        // Only the owners 1 to 4 are active members, so the second movie was seen by one of four
+        watch_list.get_mut(&1).unwrap().seen_by = vec![1, 2, 3];
+        watch_list.get_mut(&2).unwrap().seen_by = vec![1, 7, 8, 9];
+
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.watch_list = watch_list.clone();
+        let entries: Vec<(&u32, &WatchListEntry)> = watch_list.iter().sorted_by_key(|(id, _)| **id).collect();
+
+        bot_data.seen_share_limit = 50;
+        assert_eq!(get_selection_weights(&bot_data, &entries), vec![SEEN_DOWN_WEIGHT, 1.0, 1.0, 1.0]);
+
+        bot_data.seen_policy = SeenPolicy::Exclude;
+        assert_eq!(get_selection_weights(&bot_data, &entries), vec![0.0, 1.0, 1.0, 1.0]);
+
+        bot_data.seen_share_limit = 0;
+        assert_eq!(get_selection_weights(&bot_data, &entries), vec![1.0, 1.0, 1.0, 1.0]);
+    }
+}
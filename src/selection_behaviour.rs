@@ -0,0 +1,689 @@
+use crate::general_behaviour::{current_timestamp, is_user_administrator, send_paginated_message, split_into_pages};
+use crate::send_message;
+use itertools::Itertools;
+use rand::{seq::SliceRandom, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/**
+ * The chance that a randomly chosen movie is swapped with one of the three earliest movies
+ */
+const EARLIEST_MOVIE_CHANCE: f64 = 0.2;
+
+/**
+ * The additional weight of a movie for every member who is interested in it
+ */
+const INTEREST_BOOST: f64 = 0.5;
+
+/**
+ * The factor the weight of a movie is multiplied with for every vote it lost recently
+ */
+const LOSS_DOWN_WEIGHT: f64 = 0.5;
+
+/**
+ * The number of days a lost vote lowers the weight of a movie
+ */
+const RECENT_LOSS_DAYS: i64 = 30;
+
+/**
+ * The number of simulated random movie votes used to estimate the probabilities of a dry run
+ */
+const DRY_RUN_SIMULATIONS: u32 = 10000;
+
+/**
+ * The base weight of the movies in a random movie vote
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SelectionWeighting {
+    #[default]
+    Uniform, // Every movie has the same weight, but one of the three earliest movies is swapped in by chance
+    TimeOnList, // The weight grows with the days the movie is on the watch list
+}
+
+impl SelectionWeighting {
+    pub fn get_description(&self) -> &str {
+        match self {
+            SelectionWeighting::Uniform => "Gleichverteilt, die drei ältesten Filme werden mit etwas Glück eingetauscht",
+            SelectionWeighting::TimeOnList => "Nach Tagen auf der Filmliste",
+        }
+    }
+}
+
+impl FromStr for SelectionWeighting {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "uniform" => Self::Uniform,
+            "time" => Self::TimeOnList,
+            _ => return Err(()),
+        })
+    }
+}
+
+/**
+ * Whether the slots of a random movie vote are first shared fairly between the owners of the movies
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FairShare {
+    #[default]
+    Off,
+    AllOwners, // Random owners get one slot each, then one of their movies is chosen
+    RsvpOwners, // Like AllOwners, but only owners who accepted the next movie night get a slot
+}
+
+impl FairShare {
+    pub fn get_description(&self) -> &str {
+        match self {
+            FairShare::Off => "aus",
+            FairShare::AllOwners => "ein Platz pro Mitglied",
+            FairShare::RsvpOwners => "ein Platz pro Mitglied mit Zusage zum nächsten Filmabend",
+        }
+    }
+}
+
+impl FromStr for FairShare {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "off" => Self::Off,
+            "on" => Self::AllOwners,
+            "rsvp" => Self::RsvpOwners,
+            _ => return Err(()),
+        })
+    }
+}
+
+/**
+ * How the movies of a random movie vote are selected
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SelectionPolicy {
+    pub weighting: SelectionWeighting,
+    pub one_per_owner: bool, // Every owner gets at most one movie in the vote
+    pub interest_boost: bool, // Movies with interest reactions are chosen more often
+    pub loss_penalty: bool, // Movies that lost votes recently are chosen less often
+    pub exclude_unreleased: bool, // Movies that are not released yet are not chosen
+
+    #[serde(default)]
+    pub fair_share: FairShare,
+}
+
+/**
+ * How often the owners had a movie in the random movie votes, to verify that the selection is fair
+ */
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SelectionStatistics {
+    pub random_movie_votes: u32,
+    pub owner_appearances: HashMap<u64, u32>, // Keys are the user ids, values the number of votes with a movie of the owner
+}
+
+/**
+ * One change of the selection policy, e.g. parsed from `owner on`
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionSetting {
+    Weighting(SelectionWeighting),
+    OnePerOwner(bool),
+    InterestBoost(bool),
+    LossPenalty(bool),
+    ExcludeUnreleased(bool),
+    FairShare(FairShare),
+}
+
+impl FromStr for SelectionSetting {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (setting, value) = match s.split_whitespace().collect_tuple() {
+            Some(setting_and_value) => setting_and_value,
+            None => return Err(()),
+        };
+
+        let enabled = match value.to_lowercase().as_str() {
+            "on" => Ok(true),
+            "off" => Ok(false),
+            _ => Err(()),
+        };
+
+        Ok(match setting.to_lowercase().as_str() {
+            "weighting" => Self::Weighting(value.parse()?),
+            "owner" => Self::OnePerOwner(enabled?),
+            "interest" => Self::InterestBoost(enabled?),
+            "losses" => Self::LossPenalty(enabled?),
+            "unreleased" => Self::ExcludeUnreleased(enabled?),
+            "fair" => Self::FairShare(value.parse()?),
+            _ => return Err(()),
+        })
+    }
+}
+
+/**
+ * A movie that can be chosen for a random movie vote
+ */
+pub struct SelectionCandidate {
+    pub id: u32,
+    pub owner_id: u64,
+    pub weight: f64,
+}
+
+/**
+ * Returns the movies on the watch list that can be chosen for a random movie vote with their weights.
+ * Movies with a weight of 0 are left out.
+ */
+pub fn get_selection_candidates(bot_data: &crate::BotData) -> Vec<SelectionCandidate> {
+    let policy = bot_data.selection_policy;
+    let now = current_timestamp();
+
+    let entries: Vec<(&u32, &crate::movie_behaviour::WatchListEntry)> = bot_data
+        .watch_list
+        .iter()
+        .filter(|(_, entry)| entry.status.is_watch_list_status())
+        .filter(|(_, entry)| !crate::snooze_behaviour::is_snoozed(entry))
+        // Skip the movies of owners who missed too many movie nights
+        .filter(|(_, entry)| !crate::attendance_behaviour::owner_missed_too_many_nights(bot_data, entry.user_id.0))
+        .filter(|(_, entry)| !policy.exclude_unreleased || entry.movie.release_date <= now)
+        .collect();
+
+    // Movies that many members have already seen are chosen less often or not at all
+    let seen_weights = crate::seen_behaviour::get_selection_weights(bot_data, &entries);
+
+    entries
+        .iter()
+        .zip(seen_weights)
+        .map(|((id, entry), seen_weight)| {
+            let mut weight = seen_weight;
+
+            if policy.weighting == SelectionWeighting::TimeOnList {
+                weight *= (now - entry.added_timestamp).num_days().max(0) as f64 + 1.0;
+            }
+            if policy.interest_boost {
+                weight *= 1.0 + INTEREST_BOOST * entry.interested.len() as f64;
+            }
+            if policy.loss_penalty {
+                let since = now - chrono::Duration::days(RECENT_LOSS_DAYS);
+                let losses = crate::vote_history_behaviour::count_lost_votes_since(bot_data, **id, since);
+                weight *= LOSS_DOWN_WEIGHT.powi(losses as i32);
+            }
+
+            SelectionCandidate {
+                id: **id,
+                owner_id: entry.user_id.0,
+                weight,
+            }
+        })
+        .filter(|candidate| candidate.weight > 0.0)
+        .collect()
+}
+
+/**
+ * Chooses up to limit movies from the candidates according to their weights and the selection policy
+ */
+pub fn select_random_movies<R: Rng>(
+    bot_data: &crate::BotData,
+    candidates: &[SelectionCandidate],
+    limit: usize,
+    rng: &mut R,
+) -> Vec<u32> {
+    let policy = bot_data.selection_policy;
+    let mut chosen: Vec<&SelectionCandidate> = match policy.fair_share {
+        FairShare::Off => Vec::new(),
+        FairShare::AllOwners | FairShare::RsvpOwners => choose_one_movie_per_owner(bot_data, candidates, limit, rng),
+    };
+
+    // The leftover slots are filled by weighted sampling
+    let mut remaining: Vec<&SelectionCandidate> = candidates
+        .iter()
+        .filter(|candidate| !chosen.iter().any(|pick| {
+            pick.id == candidate.id || (policy.one_per_owner && pick.owner_id == candidate.owner_id)
+        }))
+        .collect();
+
+    while chosen.len() < limit {
+        let pick = match remaining.choose_weighted(rng, |candidate| candidate.weight) {
+            Ok(pick) => *pick,
+            Err(_) => break,
+        };
+
+        chosen.push(pick);
+        remaining.retain(|candidate| {
+            candidate.id != pick.id && !(policy.one_per_owner && candidate.owner_id == pick.owner_id)
+        });
+    }
+
+    let mut chosen: Vec<u32> = chosen.iter().map(|candidate| candidate.id).collect();
+
+    // Swapping in the earliest movies would take the slots of the owners again
+    if policy.weighting == SelectionWeighting::Uniform && policy.fair_share == FairShare::Off {
+        swap_in_earliest_movies(bot_data, candidates, &mut chosen, rng);
+    }
+
+    chosen
+}
+
+/**
+ * Chooses up to limit distinct owners at random and one movie of every owner according to the weights.
+ * With RsvpOwners only the owners who accepted the next movie night are chosen, or all owners if nobody accepted yet.
+ */
+fn choose_one_movie_per_owner<'a, R: Rng>(
+    bot_data: &crate::BotData,
+    candidates: &'a [SelectionCandidate],
+    limit: usize,
+    rng: &mut R,
+) -> Vec<&'a SelectionCandidate> {
+    let mut owners: Vec<u64> = candidates.iter().map(|candidate| candidate.owner_id).unique().collect();
+
+    if bot_data.selection_policy.fair_share == FairShare::RsvpOwners {
+        let accepted: Vec<u64> = crate::event_behaviour::get_next_event(bot_data)
+            .map(|event| event.get_user_ids(crate::event_behaviour::Rsvp::Yes))
+            .unwrap_or_default();
+
+        if owners.iter().any(|owner_id| accepted.contains(owner_id)) {
+            owners.retain(|owner_id| accepted.contains(owner_id));
+        }
+    }
+
+    owners.shuffle(rng);
+
+    owners
+        .iter()
+        .take(limit)
+        .filter_map(|owner_id| {
+            let owner_candidates: Vec<&SelectionCandidate> = candidates
+                .iter()
+                .filter(|candidate| candidate.owner_id == *owner_id)
+                .collect();
+
+            owner_candidates.choose_weighted(rng, |candidate| candidate.weight).ok().copied()
+        })
+        .collect()
+}
+
+/**
+ * Draws one movie according to the weights that is not one of the excluded movies, e.g. to replace a vetoed movie.
+ * Returns None if there is no candidate left.
+ */
+pub fn draw_replacement_movie(bot_data: &crate::BotData, excluded_ids: &[u32]) -> Option<u32> {
+    let candidates: Vec<SelectionCandidate> = get_selection_candidates(bot_data)
+        .into_iter()
+        .filter(|candidate| !excluded_ids.contains(&candidate.id))
+        .collect();
+
+    candidates
+        .choose_weighted(&mut thread_rng(), |candidate| candidate.weight)
+        .ok()
+        .map(|candidate| candidate.id)
+}
+
+/**
+ * Remembers which owners had a movie in a new random movie vote
+ */
+pub fn record_owner_appearances(bot_data: &mut crate::BotData, movie_ids: &[u32]) {
+    let owner_ids: Vec<u64> = movie_ids
+        .iter()
+        .filter_map(|id| bot_data.watch_list.get(id))
+        .map(|entry| entry.user_id.0)
+        .unique()
+        .collect();
+
+    let statistics = &mut bot_data.selection_statistics;
+    statistics.random_movie_votes += 1;
+    for owner_id in owner_ids {
+        *statistics.owner_appearances.entry(owner_id).or_insert(0) += 1;
+    }
+}
+
+/**
+ * Remembers the owner of a movie that replaced a vetoed movie in a random movie vote,
+ * unless the owner already had a movie in this vote
+ */
+pub fn record_replacement_owner_appearance(bot_data: &mut crate::BotData, movie_id: u32, vote_movie_ids: &[u32]) {
+    let owner_id = match bot_data.watch_list.get(&movie_id) {
+        Some(entry) => entry.user_id,
+        None => return,
+    };
+
+    let owner_appeared = vote_movie_ids
+        .iter()
+        .filter_map(|id| bot_data.watch_list.get(id))
+        .any(|entry| entry.user_id == owner_id);
+
+    if !owner_appeared {
+        *bot_data.selection_statistics.owner_appearances.entry(owner_id.0).or_insert(0) += 1;
+    }
+}
+
+/**
+ * Sends how often every owner had a movie in the random movie votes compared to the number of movies on the watch list
+ */
+pub fn show_owner_appearances(bot_data: &mut crate::BotData) {
+    let channel_id = bot_data.message.as_ref().expect("Passing message to show_owner_appearances function failed.").channel_id;
+    let statistics = &bot_data.selection_statistics;
+
+    // Owners with movies on the watch list are shown even if they never had a movie in a vote
+    let owner_ids: Vec<u64> = bot_data.watch_list.values()
+        .filter(|entry| entry.status.is_watch_list_status())
+        .map(|entry| entry.user_id.0)
+        .chain(statistics.owner_appearances.keys().copied())
+        .unique()
+        .collect();
+
+    let lines: Vec<String> = owner_ids
+        .iter()
+        .map(|owner_id| (owner_id, *statistics.owner_appearances.get(owner_id).unwrap_or(&0)))
+        .sorted_by(|(first, first_count), (second, second_count)| second_count.cmp(first_count).then(first.cmp(second)))
+        .map(|(owner_id, count)| format!(
+            "<@{}> | {} von {} Abstimmungen ({:.0}%) | {} Filme auf der Filmliste",
+            owner_id,
+            count,
+            statistics.random_movie_votes,
+            if statistics.random_movie_votes > 0 { count as f64 / statistics.random_movie_votes as f64 * 100.0 } else { 0.0 },
+            crate::movie_behaviour::count_user_watch_list_movies(bot_data, discord::model::UserId(*owner_id))
+        ))
+        .collect();
+
+    if lines.is_empty() {
+        return send_message::no_selection_candidates_information(bot_data);
+    }
+
+    send_paginated_message(
+        bot_data,
+        channel_id,
+        String::from(":scales: Filme pro Mitglied in zufälligen Filmabstimmungen"),
+        split_into_pages(&lines, crate::MAX_ENTRIES_PER_PAGE)
+    );
+}
+
+/**
+ * Swaps the chosen movies with the three earliest movies by chance. Skips the earliest movies that already are chosen.
+ */
+fn swap_in_earliest_movies<R: Rng>(
+    bot_data: &crate::BotData,
+    candidates: &[SelectionCandidate],
+    chosen: &mut [u32],
+    rng: &mut R,
+) {
+    let get_owner = |id: u32| candidates.iter().find(|candidate| candidate.id == id).map(|candidate| candidate.owner_id);
+
+    // Only swap in the earliest movies that could have been chosen randomly as well, the earliest movie last
+    let mut earliest_ids: Vec<u32> = crate::movie_behaviour::get_three_earliest_movie_ids(bot_data)
+        .into_iter()
+        .copied()
+        .filter(|id| get_owner(*id).is_some())
+        .rev()
+        .collect();
+
+    let already_chosen_earliest_ids: Vec<u32> = earliest_ids.iter().copied().filter(|id| chosen.contains(id)).collect();
+    earliest_ids.retain(|id| !chosen.contains(id));
+
+    for idx in 0..chosen.len() {
+        if already_chosen_earliest_ids.contains(&chosen[idx]) || !rng.gen_bool(EARLIEST_MOVIE_CHANCE) {
+            continue;
+        }
+
+        // With one movie per owner, an earliest movie is skipped if its owner already has another movie in the vote
+        let position = earliest_ids.iter().rposition(|earliest_id| {
+            !bot_data.selection_policy.one_per_owner
+                || chosen.iter().enumerate().all(|(other_idx, other_id)| {
+                    other_idx == idx || get_owner(*other_id) != get_owner(*earliest_id)
+                })
+        });
+
+        match position {
+            Some(position) => chosen[idx] = earliest_ids.remove(position),
+            None if earliest_ids.is_empty() => break,
+            None => continue,
+        }
+    }
+}
+
+/**
+ * Simulates many random movie votes and sends the share of votes each movie would be part of
+ */
+pub fn show_selection_dry_run(bot_data: &mut crate::BotData, optional_limit: Option<u32>) {
+    let channel_id = bot_data.message.as_ref().expect("Passing message to show_selection_dry_run function failed.").channel_id;
+    let limit = optional_limit.unwrap_or(bot_data.movie_vote_limit) as usize;
+
+    let candidates = get_selection_candidates(bot_data);
+    if candidates.is_empty() {
+        return send_message::no_selection_candidates_information(bot_data);
+    }
+
+    let mut rng = thread_rng();
+    let mut counts: HashMap<u32, u32> = HashMap::new();
+    for _ in 0..DRY_RUN_SIMULATIONS {
+        for id in select_random_movies(bot_data, &candidates, limit, &mut rng) {
+            *counts.entry(id).or_insert(0) += 1;
+        }
+    }
+
+    let lines: Vec<String> = candidates
+        .iter()
+        .map(|candidate| (candidate, *counts.get(&candidate.id).unwrap_or(&0)))
+        .sorted_by(|(first, first_count), (second, second_count)| second_count.cmp(first_count).then(first.id.cmp(&second.id)))
+        .map(|(candidate, count)| format!(
+            "`{:0>4}` **{}** | <@{}> | {:.1}%",
+            candidate.id,
+            bot_data.watch_list.get(&candidate.id).map(|entry| entry.movie.movie_title.as_str()).unwrap_or_default(),
+            candidate.owner_id,
+            count as f64 / DRY_RUN_SIMULATIONS as f64 * 100.0
+        ))
+        .collect();
+
+    send_paginated_message(
+        bot_data,
+        channel_id,
+        format!(":game_die: Wahrscheinlichkeiten bei {} {}", limit, if limit == 1 { "Film" } else { "Filmen" }),
+        split_into_pages(&lines, crate::MAX_ENTRIES_PER_PAGE)
+    );
+}
+
+/**
+ * Changes one setting of the selection policy for random movie votes. Only administrators can change it.
+ */
+pub fn set_selection_policy(bot_data: &mut crate::BotData, setting: SelectionSetting) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_selection_policy function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    let policy = &mut bot_data.selection_policy;
+    match setting {
+        SelectionSetting::Weighting(weighting) => policy.weighting = weighting,
+        SelectionSetting::OnePerOwner(enabled) => policy.one_per_owner = enabled,
+        SelectionSetting::InterestBoost(enabled) => policy.interest_boost = enabled,
+        SelectionSetting::LossPenalty(enabled) => policy.loss_penalty = enabled,
+        SelectionSetting::ExcludeUnreleased(enabled) => policy.exclude_unreleased = enabled,
+        SelectionSetting::FairShare(fair_share) => policy.fair_share = fair_share,
+    }
+
+    send_message::selection_policy_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current selection policy
+ */
+pub fn show_selection_policy(bot_data: &crate::BotData) {
+    send_message::selection_policy_information(bot_data, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn candidate(id: u32, owner_id: u64, weight: f64) -> SelectionCandidate {
+        SelectionCandidate { id, owner_id, weight }
+    }
+
+    #[test]
+    fn parses_selection_settings() {
+        assert_eq!(SelectionSetting::from_str("weighting time"), Ok(SelectionSetting::Weighting(SelectionWeighting::TimeOnList)));
+        assert_eq!(SelectionSetting::from_str("owner on"), Ok(SelectionSetting::OnePerOwner(true)));
+        assert_eq!(SelectionSetting::from_str("Unreleased OFF"), Ok(SelectionSetting::ExcludeUnreleased(false)));
+        assert_eq!(SelectionSetting::from_str("owner"), Err(()));
+        assert_eq!(SelectionSetting::from_str("owner maybe"), Err(()));
+        assert_eq!(SelectionSetting::from_str("weighting on"), Err(()));
+    }
+
+    #[test]
+    fn selects_distinct_movies_up_to_the_limit() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.selection_policy.weighting = SelectionWeighting::TimeOnList;
+        let candidates: Vec<SelectionCandidate> = (1..=6).map(|id| candidate(id, 1, 1.0)).collect();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let chosen = select_random_movies(&bot_data, &candidates, 4, &mut rng);
+        assert_eq!(chosen.len(), 4);
+        assert_eq!(chosen.iter().unique().count(), 4);
+
+        let chosen = select_random_movies(&bot_data, &candidates, 10, &mut rng);
+        assert_eq!(chosen.iter().copied().sorted().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn same_seed_selects_the_same_movies() {
+        let bot_data = crate::get_test_bot_data();
+        let candidates: Vec<SelectionCandidate> = (1..=20).map(|id| candidate(id, id as u64, 1.0)).collect();
+
+        let first = select_random_movies(&bot_data, &candidates, 5, &mut StdRng::seed_from_u64(42));
+        let second = select_random_movies(&bot_data, &candidates, 5, &mut StdRng::seed_from_u64(42));
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn heavier_movies_are_selected_more_often() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.selection_policy.weighting = SelectionWeighting::TimeOnList;
+        let candidates = vec![candidate(1, 1, 9.0), candidate(2, 2, 1.0)];
+        let mut rng = StdRng::seed_from_u64(3);
+
+        let heavy_count = (0..1000)
+            .filter(|_| select_random_movies(&bot_data, &candidates, 1, &mut rng) == vec![1])
+            .count();
+
+        assert!(heavy_count > 800 && heavy_count < 980);
+    }
+
+    #[test]
+    fn one_movie_per_owner() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.selection_policy.weighting = SelectionWeighting::TimeOnList;
+        bot_data.selection_policy.one_per_owner = true;
+        let candidates = vec![candidate(1, 1, 1.0), candidate(2, 1, 1.0), candidate(3, 2, 1.0), candidate(4, 2, 1.0), candidate(5, 3, 1.0)];
+
+        for seed in 0..20 {
+            let chosen = select_random_movies(&bot_data, &candidates, 5, &mut StdRng::seed_from_u64(seed));
+            let owners: Vec<u64> = chosen.iter().map(|id| candidates[*id as usize - 1].owner_id).sorted().collect();
+
+            assert_eq!(owners, vec![1, 2, 3]);
+        }
+    }
+
+    #[test]
+    fn candidates_follow_the_selection_policy() {
+        let mut bot_data = crate::get_test_bot_data();
+        let mut unreleased_entry = crate::get_test_watch_list_entry("Dune 3", 1);
+        unreleased_entry.movie.release_date = current_timestamp() + chrono::Duration::days(30);
+        bot_data.watch_list.insert(1, unreleased_entry);
+        let mut interesting_entry = crate::get_test_watch_list_entry("Alien", 2);
+        interesting_entry.interested = vec![3, 4];
+        bot_data.watch_list.insert(2, interesting_entry);
+        let mut watched_entry = crate::get_test_watch_list_entry("Aliens", 2);
+        watched_entry.status = crate::movie_behaviour::MovieStatus::Watched;
+        bot_data.watch_list.insert(3, watched_entry);
+
+        let get_weights = |bot_data: &crate::BotData| -> Vec<(u32, f64)> {
+            get_selection_candidates(bot_data).iter()
+                .map(|candidate| (candidate.id, candidate.weight))
+                .sorted_by_key(|(id, _)| *id)
+                .collect()
+        };
+
+        assert_eq!(get_weights(&bot_data), vec![(1, 1.0), (2, 1.0)]);
+
+        bot_data.selection_policy.exclude_unreleased = true;
+        bot_data.selection_policy.interest_boost = true;
+        assert_eq!(get_weights(&bot_data), vec![(2, 1.0 + 2.0 * INTEREST_BOOST)]);
+    }
+
+    #[test]
+    fn chooses_one_movie_of_distinct_owners() {
+        let bot_data = crate::get_test_bot_data();
+        // The first owner has most of the movies, but only one slot like everybody else
+        let candidates = vec![
+            candidate(1, 1, 1.0), candidate(2, 1, 1.0), candidate(3, 1, 1.0), candidate(4, 1, 1.0),
+            candidate(5, 2, 1.0), candidate(6, 3, 0.0), candidate(7, 3, 1.0),
+        ];
+
+        for seed in 0..20 {
+            let chosen = choose_one_movie_per_owner(&bot_data, &candidates, 3, &mut StdRng::seed_from_u64(seed));
+            let owners: Vec<u64> = chosen.iter().map(|candidate| candidate.owner_id).sorted().collect();
+
+            assert_eq!(owners, vec![1, 2, 3]);
+            assert!(chosen.iter().all(|candidate| candidate.id != 6));
+        }
+
+        let chosen = choose_one_movie_per_owner(&bot_data, &candidates, 2, &mut StdRng::seed_from_u64(1));
+        assert_eq!(chosen.iter().map(|candidate| candidate.owner_id).unique().count(), 2);
+    }
+
+    #[test]
+    fn fair_share_fills_the_leftover_slots() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.selection_policy.fair_share = FairShare::AllOwners;
+        let candidates = vec![candidate(1, 1, 1.0), candidate(2, 1, 1.0), candidate(3, 1, 1.0), candidate(4, 2, 1.0)];
+
+        let chosen = select_random_movies(&bot_data, &candidates, 3, &mut StdRng::seed_from_u64(5));
+
+        assert_eq!(chosen.len(), 3);
+        assert!(chosen.contains(&4));
+        assert_eq!(chosen.iter().unique().count(), 3);
+    }
+
+    #[test]
+    fn rsvp_fair_share_prefers_owners_who_accepted() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.selection_policy.fair_share = FairShare::RsvpOwners;
+        let candidates = vec![candidate(1, 1, 1.0), candidate(2, 2, 1.0), candidate(3, 3, 1.0)];
+
+        // Without a movie night every owner gets a slot
+        let chosen = choose_one_movie_per_owner(&bot_data, &candidates, 3, &mut StdRng::seed_from_u64(1));
+        assert_eq!(chosen.len(), 3);
+
+        bot_data.events.insert(1, crate::event_behaviour::MovieEvent {
+            id: 1,
+            title: String::from("Filmabend"),
+            date: current_timestamp() + chrono::Duration::days(2),
+            creator_id: discord::model::UserId(1),
+            channel_id: discord::model::ChannelId(0),
+            message_id: discord::model::MessageId(0),
+            rsvps: vec![(2, crate::event_behaviour::Rsvp::Yes), (3, crate::event_behaviour::Rsvp::No)].into_iter().collect(),
+            sent_reminders: Vec::new(),
+            vote_message_id: None,
+            movie_id: None,
+        });
+
+        let chosen = choose_one_movie_per_owner(&bot_data, &candidates, 3, &mut StdRng::seed_from_u64(1));
+        assert_eq!(chosen.iter().map(|candidate| candidate.id).collect::<Vec<u32>>(), vec![2]);
+    }
+
+    #[test]
+    fn records_every_owner_once_per_vote() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.watch_list.insert(1, crate::get_test_watch_list_entry("Alien", 1));
+        bot_data.watch_list.insert(2, crate::get_test_watch_list_entry("Aliens", 1));
+        bot_data.watch_list.insert(3, crate::get_test_watch_list_entry("Solaris", 2));
+
+        record_owner_appearances(&mut bot_data, &[1, 2, 3]);
+        record_owner_appearances(&mut bot_data, &[2]);
+
+        assert_eq!(bot_data.selection_statistics.random_movie_votes, 2);
+        assert_eq!(bot_data.selection_statistics.owner_appearances.get(&1), Some(&2));
+        assert_eq!(bot_data.selection_statistics.owner_appearances.get(&2), Some(&1));
+    }
+}
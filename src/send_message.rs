@@ -110,7 +110,10 @@ pub fn movie_information(
             embed
                 .title(format!("{}", movie_entry.movie.movie_title).as_str())
                 .url(movie_behaviour::get_movie_link(movie_entry.movie.tmdb_id, false).as_str())
-                .description(movie_entry.movie.overview.clone().as_str())
+                .description(
+                    movie_behaviour::shorten_movie_description(movie_entry.movie.overview.clone())
+                        .as_str(),
+                )
                 .image(
                     movie_behaviour::generate_poster_link(&movie_entry.movie.poster_path).as_str(),
                 )
@@ -143,6 +146,12 @@ pub fn movie_information(
                             true,
                         )
                         .field("Budget", movie_entry.movie.budget.as_str(), true)
+                        .field(
+                            "TMDb-Bewertung",
+                            movie_behaviour::format_vote_average(movie_entry.movie.vote_average)
+                                .as_str(),
+                            true,
+                        )
                         .field(
                             "Watchlink",
                             movie_behaviour::get_movie_link(movie_entry.movie.tmdb_id, true)
@@ -171,7 +180,10 @@ pub fn movie_information(
             embed
                 .title(format!("{}", movie_entry.movie.movie_title).as_str())
                 .url(movie_behaviour::get_movie_link(movie_entry.movie.tmdb_id, false).as_str())
-                .description(movie_entry.movie.overview.clone().as_str())
+                .description(
+                    movie_behaviour::shorten_movie_description(movie_entry.movie.overview.clone())
+                        .as_str(),
+                )
                 .image(
                     movie_behaviour::generate_poster_link(&movie_entry.movie.poster_path).as_str(),
                 )
@@ -204,6 +216,12 @@ pub fn movie_information(
                             true,
                         )
                         .field("Budget", movie_entry.movie.budget.as_str(), true)
+                        .field(
+                            "TMDb-Bewertung",
+                            movie_behaviour::format_vote_average(movie_entry.movie.vote_average)
+                                .as_str(),
+                            true,
+                        )
                         .field(
                             "Hinzugefügt von",
                             format!("<@{}>", movie_entry.user_id).as_str(),
@@ -223,6 +241,24 @@ pub fn movie_information(
                             format!("{}", movie_entry.status.get_emoji()).as_str(),
                             true,
                         )
+                        .field(
+                            "Bewertung der Gruppe",
+                            crate::rating_behaviour::format_average_rating(movie_entry).as_str(),
+                            true,
+                        )
+                        .field(
+                            "Gesehen von",
+                            crate::attendance_behaviour::format_attendees(movie_entry).as_str(),
+                            false,
+                        )
+                        .field(
+                            "Abstimmungen",
+                            movie_behaviour::find_id_by_tmdb_id(movie_entry.movie.tmdb_id, &bot_data.watch_list)
+                                .map(|id| crate::vote_history_behaviour::format_vote_appearances(bot_data, *id))
+                                .unwrap_or_else(|| String::from("Noch in keiner Abstimmung"))
+                                .as_str(),
+                            true,
+                        )
                         .field(
                             "Watchlink",
                             movie_behaviour::get_movie_link(movie_entry.movie.tmdb_id, true)
@@ -813,3 +849,1390 @@ pub fn current_user_movie_count(bot_data: &crate::BotData, current_movie_count:
             .color(crate::COLOR_INFORMATION)
         );
 }
+
+/**
+ * Sends a message listing all changes of the movie data after it was refreshed
+ */
+pub fn movie_refreshed_information(
+    bot_data: &crate::BotData,
+    id: u32,
+    movie_title: String,
+    changes: &Vec<String>,
+) {
+    let description = if changes.is_empty() {
+        format!(
+            "Die Daten von **{}** (`{:0>4}`) sind bereits aktuell.",
+            movie_title, id
+        )
+    } else {
+        format!(
+            "Folgendes hat sich bei **{}** (`{:0>4}`) geändert:\n\n• {}",
+            movie_title,
+            id,
+            changes.join("\n• ")
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data
+            .message
+            .as_ref()
+            .expect("Passing message to send_message::movie_refreshed_information failed.")
+            .channel_id,
+        "",
+        |embed| {
+            embed
+                .title("Filmdaten aktualisiert")
+                .description(description.as_str())
+                .color(COLOR_SUCCESS)
+        },
+    );
+}
+
+/**
+ * Sends an error message, that the data of the movie could not be fetched from TMDb
+ */
+pub fn refreshing_movie_failed_error(bot_data: &crate::BotData, id: u32, error: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::refreshing_movie_failed_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Aktualisierung fehlgeschlagen")
+            .description(
+                format!("Die Daten des Films mit der ID `{:0>4}` konnten nicht von TMDb geladen werden. Folgende Fehlermeldung kann ich dir zur Verfügung stellen:
+                ```{}```", id, error).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a message stating that the data of the movies is fetched from TMDb in the background
+ */
+pub fn refresh_started_information(bot_data: &crate::BotData, channel_id: discord::model::ChannelId, movie_count: usize) {
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Aktualisierung gestartet")
+            .description(
+                format!(
+                    "Die Daten von `{}` {} werden von TMDb geladen. Das kann einen Moment dauern, die Zusammenfassung folgt danach.",
+                    movie_count,
+                    if movie_count == 1 { "Film" } else { "Filmen" }
+                ).as_str()
+            )
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error message if the export file could not be created or uploaded
+ */
+pub fn export_failed_error(bot_data: &crate::BotData, error: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::export_failed_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Export fehlgeschlagen")
+            .description(
+                format!("Die Exportdatei konnte nicht erstellt oder hochgeladen werden. Folgende Fehlermeldung kann ich dir zur Verfügung stellen:
+                ```{}```", error).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a summary of all movies that changed or failed during the refresh of multiple movies
+ */
+pub fn movies_refreshed_summary(
+    bot_data: &crate::BotData,
+    channel_id: discord::model::ChannelId,
+    checked_count: usize,
+    results: &Vec<movie_behaviour::RefreshResult>,
+) {
+    let changed_count = results.iter().filter(|(_, _, result)| result.is_ok()).count();
+    let mut description = format!(
+        "Es {} `{}` {} überprüft, davon {} sich `{}` geändert.\n\n",
+        if checked_count == 1 { "wurde" } else { "wurden" },
+        checked_count,
+        if checked_count == 1 { "Film" } else { "Filme" },
+        if changed_count == 1 { "hat" } else { "haben" },
+        changed_count
+    );
+
+    for (idx, (id, movie_title, result)) in results.iter().enumerate() {
+        let line = match result {
+            Ok(changes) => format!(
+                "**{}** (`{:0>4}`)\n> {}\n\n",
+                movie_title,
+                id,
+                changes.join("\n> ")
+            ),
+            Err(_) => format!(
+                "**{}** (`{:0>4}`)\n> Konnte nicht von TMDb geladen werden\n\n",
+                movie_title, id
+            ),
+        };
+
+        // Embed descriptions are limited in length, so cut the list off early
+        if description.len() + line.len() > crate::MAX_EMBED_DESCRIPTION_LENGTH {
+            description.push_str(format!("... und `{}` weitere", results.len() - idx).as_str());
+            break;
+        }
+
+        description.push_str(line.as_str());
+    }
+
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| {
+            embed
+                .title("Filmdaten aktualisiert")
+                .description(description.as_str())
+                .color(COLOR_SUCCESS)
+        },
+    );
+}
+
+/**
+ * Sends an information message about the automatic refresh of the movie data. If changed is true
+ * the message states that the setting was changed.
+ */
+pub fn auto_refresh_information(bot_data: &crate::BotData, changed: bool) {
+    let description = if bot_data.auto_refresh_days == 0 {
+        String::from("Die automatische Aktualisierung der Filmdaten ist ausgeschaltet.")
+    } else {
+        format!(
+            "Die Daten der Filme auf der Filmliste werden automatisch aktualisiert, wenn sie älter als `{}` {} sind.\nÄnderungen werden in <#{}> gemeldet.",
+            bot_data.auto_refresh_days,
+            if bot_data.auto_refresh_days == 1 { "Tag" } else { "Tage" },
+            bot_data.auto_refresh_channel_id
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data
+            .message
+            .as_ref()
+            .expect("Passing message to send_message::auto_refresh_information failed.")
+            .channel_id,
+        "",
+        |embed| {
+            embed
+                .title(if changed {
+                    "Automatische Aktualisierung geändert"
+                } else {
+                    "Automatische Aktualisierung"
+                })
+                .description(description.as_str())
+                .color(COLOR_INFORMATION)
+        },
+    );
+}
+
+/**
+ * Sends an error message if the import command was used without a csv file attached
+ */
+pub fn import_no_csv_attachment_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::import_no_csv_attachment_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Keine CSV-Datei gefunden")
+            .description("Bitte hänge an die Nachricht mit dem Kommando eine CSV-Datei an, die du bei Letterboxd oder IMDb exportiert hast.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an information that the attached file is downloaded and its movies are searched on TMDb
+ */
+pub fn import_started_information(bot_data: &crate::BotData, channel_id: discord::model::ChannelId) {
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Import gestartet")
+            .description("Die Filme der Liste werden auf TMDb gesucht. Das kann einen Moment dauern, die Übersicht folgt danach.")
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error message if the attached file could not be downloaded or read
+ */
+pub fn import_failed_error(bot_data: &crate::BotData, channel_id: discord::model::ChannelId, error: String) {
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Import fehlgeschlagen")
+            .description(
+                format!("Die angehängte Datei konnte nicht gelesen werden. Folgende Fehlermeldung kann ich dir zur Verfügung stellen:
+                ```{}```", error).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Formats one section of the import summary. Only the first entries are listed, so the message does not get too long.
+ */
+fn format_import_summary_section(title: &str, entries: &Vec<String>) -> String {
+    const MAX_LISTED_ENTRIES: usize = 15;
+
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let mut section = format!("**{}** ({})\n", title, entries.len());
+    for entry in entries.iter().take(MAX_LISTED_ENTRIES) {
+        section += format!("> {}\n", entry).as_str();
+    }
+
+    if entries.len() > MAX_LISTED_ENTRIES {
+        section += format!("> ... und `{}` weitere\n", entries.len() - MAX_LISTED_ENTRIES).as_str();
+    }
+
+    section + "\n"
+}
+
+/**
+ * Sends the summary of an import and returns the message, so the user can confirm the import with a reaction
+ */
+pub fn import_summary(
+    bot_data: &crate::BotData,
+    channel_id: discord::model::ChannelId,
+    summary: &crate::import_behaviour::ImportSummary,
+) -> Result<discord::model::Message, discord::Error> {
+    let matched: Vec<String> = summary.matched.iter().map(|movie| movie.movie_title.clone()).collect();
+
+    // Every ambiguous row lists its options, which can be chosen by their numbers
+    let ambiguous: Vec<String> = summary.ambiguous.iter()
+        .enumerate()
+        .map(|(row_idx, row)| format!(
+            "`{}` {}: {}",
+            row_idx + 1,
+            row.description,
+            row.options.iter()
+                .enumerate()
+                .map(|(option_idx, option)| format!("`{}` [{}]({})", option_idx + 1, option.title, movie_behaviour::get_movie_link(option.tmdb_id, false)))
+                .collect::<Vec<String>>()
+                .join(" • ")
+        ))
+        .collect();
+
+    let mut description = String::new();
+    description += format_import_summary_section("Gefunden", &matched).as_str();
+    description += format_import_summary_section("Mehrdeutig, wähle mit `import choose <Zeile> <Option>`", &ambiguous).as_str();
+    description += format_import_summary_section("Bereits auf der Liste", &summary.duplicates).as_str();
+    description += format_import_summary_section("Nicht gefunden", &summary.not_found).as_str();
+    description += format_import_summary_section("Über dem Filmlimit", &summary.over_limit).as_str();
+
+    if summary.skipped_rows > 0 {
+        description += format!("Es werden höchstens `{}` Zeilen pro Import verarbeitet. `{}` Zeilen wurden ignoriert.\n\n", crate::MAX_ROWS_PER_IMPORT, summary.skipped_rows).as_str();
+    }
+
+    if matched.is_empty() && ambiguous.is_empty() {
+        description += "Es gibt keine Filme, die hinzugefügt werden können.";
+    } else if matched.is_empty() {
+        description += "Wähle die mehrdeutigen Filme aus und bestätige dann mit ✅ oder brich mit ❎ ab.";
+    } else {
+        description += format!("Sollen die `{}` gefundenen Filme hinzugefügt werden? Bestätige mit ✅ oder brich mit ❎ ab.", matched.len()).as_str();
+    }
+
+    if description.len() > crate::MAX_EMBED_DESCRIPTION_LENGTH {
+        let mut cut_index = crate::MAX_EMBED_DESCRIPTION_LENGTH;
+        while !description.is_char_boundary(cut_index) {
+            cut_index -= 1;
+        }
+        description.truncate(cut_index);
+    }
+
+    bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Import")
+            .description(description.as_str())
+            .color(if matched.is_empty() && ambiguous.is_empty() { COLOR_WARNING } else { COLOR_INFORMATION })
+    )
+}
+
+/**
+ * Sends an information that the import was cancelled
+ */
+pub fn import_cancelled_information(bot_data: &crate::BotData, channel_id: discord::model::ChannelId) {
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Import abgebrochen")
+            .description("Es wurden keine Filme hinzugefügt.")
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a list of all movies that were added by the import and of those that could not be added anymore
+ */
+pub fn movies_imported_information(bot_data: &crate::BotData, channel_id: discord::model::ChannelId, added: &Vec<(u32, String)>, failed: &Vec<String>) {
+    let added_entries: Vec<String> = added.iter().map(|(id, title)| format!("`{:0>4}` {}", id, title)).collect();
+
+    let mut description = String::new();
+    description += format_import_summary_section("Hinzugefügt", &added_entries).as_str();
+    description += format_import_summary_section("Nicht hinzugefügt (bereits auf der Liste, Filmlimit erreicht oder TMDb-Fehler)", failed).as_str();
+
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Import abgeschlossen")
+            .description(description.as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an error that the user has no import that waits for a confirmation
+ */
+pub fn no_pending_import_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::no_pending_import_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Kein offener Import")
+            .description("Du hast keinen Import, der noch auf deine Bestätigung wartet.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends the reason why the chosen option of an ambiguous import row could not be added to the import
+ */
+pub fn import_choice_failed_error(bot_data: &crate::BotData, error: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::import_choice_failed_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Auswahl nicht möglich")
+            .description(error.as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an information that the chosen movie is part of the import now
+ */
+pub fn import_option_chosen_information(bot_data: &crate::BotData, movie_title: &str, candidate_count: usize) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::import_option_chosen_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Film ausgewählt")
+            .description(format!(
+                "**{}** wird mit dem Import hinzugefügt. Insgesamt werden nach der Bestätigung mit ✅ `{}` Filme hinzugefügt.",
+                movie_title,
+                candidate_count
+            ).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends the message that asks all members to rate the watched movie
+ */
+pub fn rating_request(bot_data: &crate::BotData, id: u32, entry: &movie_behaviour::WatchListEntry) -> Result<discord::model::Message, discord::Error> {
+    bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::rating_request failed.").channel_id,
+        "",
+        |embed| embed
+            .title(format!("Wie fandet ihr {}?", entry.movie.movie_title).as_str())
+            .description(
+                format!("Bewertet den Film mit einer Reaktion von 1 bis 10. Alternativ kannst du den Film auch mit `rate {} <1-10>` bewerten.", id).as_str()
+            )
+            .thumbnail(movie_behaviour::generate_poster_link(&entry.movie.poster_path).as_str())
+            .color(COLOR_INFORMATION)
+    )
+}
+
+/**
+ * Sends an error message if the given score is not between 1 and 10
+ */
+pub fn rating_out_of_range_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::rating_out_of_range_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Ungültige Bewertung")
+            .description("Filme können nur mit einer ganzen Zahl von 1 bis 10 bewertet werden.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error message if a movie should be rated that was not watched yet
+ */
+pub fn movie_not_watched_yet_error(bot_data: &crate::BotData, id: u32) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::movie_not_watched_yet_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Film noch nicht geschaut")
+            .description(format!("Der Film mit der ID `{:0>4}` hat nicht den Status `Watched` und kann deshalb nicht bewertet werden.", id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation that the rating was saved, together with the new average rating
+ */
+pub fn movie_rated_successfully(bot_data: &crate::BotData, id: u32, entry: &movie_behaviour::WatchListEntry, score: u8) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::movie_rated_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Bewertung gespeichert")
+            .description(
+                format!(
+                    "Du hast **{}** (`{:0>4}`) mit `{}/10` bewertet.\nDurchschnitt: {}",
+                    entry.movie.movie_title,
+                    id,
+                    score,
+                    crate::rating_behaviour::format_average_rating(entry)
+                ).as_str()
+            )
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an error that the review exceeds the maximum length
+ */
+pub fn review_too_long_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::review_too_long_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Review zu lang")
+            .description(format!("Eine Review darf höchstens {} Zeichen lang sein.", crate::MAX_REVIEW_LENGTH).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the user has not written a review for the movie
+ */
+pub fn review_not_found_error(bot_data: &crate::BotData, id: u32, user_id: u64) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::review_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Review nicht gefunden")
+            .description(format!("<@{}> hat noch keine Review zum Film mit der ID `{:0>4}` geschrieben.", user_id, id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation that the review was written or edited
+ */
+pub fn review_saved_successfully(bot_data: &crate::BotData, id: u32, title: String, edited: bool) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::review_saved_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if edited { "Review bearbeitet" } else { "Review gespeichert" })
+            .description(format!("Die Review zu **{}** (`{:0>4}`) wurde gespeichert.", title, id).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a confirmation that the review was deleted
+ */
+pub fn review_deleted_successfully(bot_data: &crate::BotData, id: u32, title: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::review_deleted_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Review gelöscht")
+            .description(format!("Die Review zu **{}** (`{:0>4}`) wurde gelöscht.", title, id).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an information that the user has not written any reviews yet
+ */
+pub fn user_has_no_reviews_information(bot_data: &crate::BotData, user_id: u64) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::user_has_no_reviews_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Keine Reviews")
+            .description(format!("<@{}> hat noch keine Reviews geschrieben.", user_id).as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an information that no movie has been watched yet
+ */
+pub fn no_watched_movies_information(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::no_watched_movies_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Keine Statistiken")
+            .description("Es wurde noch kein Film geschaut. Sobald ein Film den Status `Watched` hat, werden hier Statistiken angezeigt.")
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends the profile of a user with the statistics about the movies the user added and rated
+ */
+pub fn user_profile(bot_data: &crate::BotData, profile: &crate::profile_behaviour::UserProfile) {
+    use crate::profile_behaviour::format_average_score;
+
+    let favourite_genres = if profile.favourite_genres.is_empty() {
+        String::from("-")
+    } else {
+        profile.favourite_genres.iter()
+            .map(|(genre, count)| format!("{} ({})", genre, count))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::user_profile failed.").channel_id,
+        "",
+        |embed| embed
+            .title(
+                if profile.user_name.is_empty() {
+                    String::from("Profil")
+                } else {
+                    format!("Profil von {}", profile.user_name)
+                }.as_str()
+            )
+            .description(format!("<@{}>", profile.user_id).as_str())
+            .fields(|fields| fields
+                .field(
+                    "Filmliste",
+                    format!("`{}`/`{}` {}", profile.watch_list_count, bot_data.movie_limit_per_user, if bot_data.movie_limit_per_user == 1 { "Film" } else { "Filme" }).as_str(),
+                    true
+                )
+                .field(
+                    "Geschaut",
+                    format!("`{}` von `{}` hinzugefügten Filmen", profile.watched_count, profile.added_count).as_str(),
+                    true
+                )
+                .field("Abstimmungssiege", format!("`{}`", profile.vote_wins).as_str(), true)
+                .field("Vergebene Bewertung", format_average_score(&profile.ratings_given).as_str(), true)
+                .field("Erhaltene Bewertung", format_average_score(&profile.ratings_received).as_str(), true)
+                .field(
+                    "Anwesenheit",
+                    if profile.attendance.nights == 0 {
+                        String::from("Noch bei keinem Filmabend dabei")
+                    } else {
+                        format!(
+                            "`{}` von `{}` Filmabenden ({:.0}%)\nSerie: `{}` | Beste Serie: `{}`",
+                            profile.attendance.attended,
+                            profile.attendance.nights,
+                            profile.attendance.get_rate() * 100.0,
+                            profile.attendance.current_streak,
+                            profile.attendance.longest_streak
+                        )
+                    }.as_str(),
+                    false
+                )
+                .field("Lieblingsgenres", favourite_genres.as_str(), false)
+            )
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that the date or time of an event has the wrong format
+ */
+pub fn event_date_wrong_format_error(bot_data: &crate::BotData, date: String, time: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_date_wrong_format_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Falsches Datum")
+            .description(
+                format!("Das Datum '{} {}' hatte leider das falsche Format. Bitte stelle sicher, dass das Datum im Format TT.MM.JJJJ und die Uhrzeit im Format HH:MM vorliegt.", date, time).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that an event can not be created in the past
+ */
+pub fn event_date_in_past_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_date_in_past_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Datum liegt in der Vergangenheit")
+            .description("Ein Filmabend kann nur in der Zukunft geplant werden.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the event message could not be sent
+ */
+pub fn event_message_failed_to_send_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_message_failed_to_send_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Fehler beim Senden")
+            .description("Die Nachricht zum Filmabend konnte nicht gesendet werden. Der Filmabend wurde nicht erstellt.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that no event with the given id exists
+ */
+pub fn event_not_found_error(bot_data: &crate::BotData, id: u32) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Filmabend nicht gefunden")
+            .description(format!("Es gibt keinen Filmabend mit der ID `{}`.", id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation that the event was cancelled
+ */
+pub fn event_cancelled_successfully(bot_data: &crate::BotData, event: &crate::event_behaviour::MovieEvent) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_cancelled_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Filmabend abgesagt")
+            .description(
+                format!("Der Filmabend **{}** am {} wurde abgesagt.", event.title, crate::event_behaviour::format_event_date(&event.date)).as_str()
+            )
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a list of all upcoming events
+ */
+pub fn upcoming_events(bot_data: &crate::BotData, event_lines: &Vec<String>) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::upcoming_events failed.").channel_id,
+        "",
+        |embed| embed
+            .title(":calendar: Geplante Filmabende")
+            .description(
+                if event_lines.is_empty() {
+                    String::from("Es ist aktuell kein Filmabend geplant.")
+                } else {
+                    event_lines.join("\n")
+                }.as_str()
+            )
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a message showing the times before an event at which reminders are sent
+ */
+pub fn event_reminders_information(bot_data: &crate::BotData, changed: bool) {
+    let description = if bot_data.event_reminder_offsets.is_empty() {
+        String::from("Es werden keine Erinnerungen an Filmabende gesendet.")
+    } else {
+        format!(
+            "Erinnerungen an Filmabende werden {} vorher gesendet.",
+            bot_data.event_reminder_offsets.iter()
+                .map(|offset| format!("`{}`", general_behaviour::format_duration_minutes(*offset)))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::event_reminders_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Erinnerungen geändert" } else { "Erinnerungen" })
+            .description(description.as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a reminder for an upcoming event to the channel of the event and mentions everybody who did not decline
+ */
+pub fn event_reminder(bot_data: &crate::BotData, event: &crate::event_behaviour::MovieEvent, time_left: String) {
+    use crate::event_behaviour::Rsvp;
+
+    let mentions = event.get_user_ids(Rsvp::Yes).iter()
+        .chain(event.get_user_ids(Rsvp::Maybe).iter())
+        .map(|user_id| format!("<@{}>", user_id))
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    let movie = event.movie_id
+        .and_then(|id| bot_data.watch_list.get(&id))
+        .map(|entry| format!("\nGeschaut wird **{}**.", entry.movie.movie_title))
+        .unwrap_or_default();
+
+    let _ = bot_data.bot.send_embed(
+        event.channel_id,
+        mentions.as_str(),
+        |embed| embed
+            .title(format!(":alarm_clock: {} beginnt in {}", event.title, time_left).as_str())
+            .description(
+                format!(
+                    "Der Filmabend beginnt am {}.{}\nWer noch nicht geantwortet hat, kann das mit einer Reaktion auf die Nachricht zum Filmabend tun.",
+                    crate::event_behaviour::format_event_date(&event.date),
+                    movie
+                ).as_str()
+            )
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a confirmation that the attendees of the movie were changed, together with all attendees
+ */
+pub fn attendees_changed_successfully(bot_data: &crate::BotData, id: u32, entry: &movie_behaviour::WatchListEntry) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::attendees_changed_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Anwesenheit gespeichert")
+            .description(
+                format!(
+                    "**{}** (`{:0>4}`) wurde gesehen von: {}",
+                    entry.movie.movie_title,
+                    id,
+                    crate::attendance_behaviour::format_attendees(entry)
+                ).as_str()
+            )
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a message showing the current missed nights limit
+ */
+pub fn missed_nights_limit_information(bot_data: &crate::BotData, changed: bool) {
+    let description = if bot_data.missed_nights_limit == 0 {
+        String::from("Die Filme aller Nutzer werden bei zufälligen Filmabstimmungen berücksichtigt, egal wie viele Filmabende sie verpasst haben.")
+    } else {
+        format!(
+            "Die Filme von Nutzern, die die letzten `{}` {} verpasst haben, werden bei zufälligen Filmabstimmungen übersprungen.",
+            bot_data.missed_nights_limit,
+            if bot_data.missed_nights_limit == 1 { "Filmabend" } else { "Filmabende" }
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::missed_nights_limit_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Grenze für verpasste Filmabende geändert" } else { "Grenze für verpasste Filmabende" })
+            .description(description.as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that the movie is not on the watch list anymore
+ */
+pub fn movie_not_on_watch_list_error(bot_data: &crate::BotData, id: u32) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::movie_not_on_watch_list_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Film nicht auf der Filmliste")
+            .description(format!("Der Film mit der ID `{:0>4}` ist bereits im Verlauf und nicht mehr auf der Filmliste.", id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation that the movie was marked as seen or not seen
+ */
+pub fn seen_changed_successfully(bot_data: &crate::BotData, id: u32, entry: &movie_behaviour::WatchListEntry, seen: bool) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::seen_changed_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if seen { "Als gesehen markiert" } else { "Markierung entfernt" })
+            .description(
+                format!(
+                    "**{}** (`{:0>4}`) wurde bereits von `{}` {} gesehen.",
+                    entry.movie.movie_title,
+                    id,
+                    entry.seen_by.len(),
+                    if entry.seen_by.len() == 1 { "Mitglied" } else { "Mitgliedern" }
+                ).as_str()
+            )
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an error that the share of the seen limit is greater than 100 percent
+ */
+pub fn seen_limit_out_of_range_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::seen_limit_out_of_range_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Ungültiger Anteil")
+            .description("Der Anteil muss zwischen 0 und 100 Prozent liegen.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a message showing the current seen limit and policy
+ */
+pub fn seen_limit_information(bot_data: &crate::BotData, changed: bool) {
+    let description = if bot_data.seen_share_limit == 0 {
+        String::from("Bei zufälligen Filmabstimmungen wird nicht berücksichtigt, wie viele Mitglieder einen Film bereits gesehen haben.")
+    } else {
+        format!(
+            "Filme, die mehr als `{}%` der aktiven Mitglieder bereits gesehen haben, werden {}.",
+            bot_data.seen_share_limit,
+            bot_data.seen_policy.get_description()
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::seen_limit_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Grenze für gesehene Filme geändert" } else { "Grenze für gesehene Filme" })
+            .description(description.as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that no open vote, or no ranked vote if only ranked votes were searched, contains all given options
+ */
+pub fn no_vote_with_options_found_error(bot_data: &crate::BotData, ranked_only: bool) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::no_vote_with_options_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Keine passende Abstimmung")
+            .description(
+                format!(
+                    "Es gibt keine offene Abstimmung{}, die alle angegebenen Optionen enthält.",
+                    if ranked_only { " mit Rangfolge" } else { "" }
+                ).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that more than one open vote contains all given options
+ */
+pub fn vote_with_options_ambiguous_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vote_with_options_ambiguous_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Abstimmung nicht eindeutig")
+            .description("Mehrere offene Abstimmungen enthalten alle angegebenen Optionen. Bitte gib die ID der Abstimmung vor den Emojis an.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation with the saved ranking of the user
+ */
+pub fn ranking_saved_successfully(bot_data: &crate::BotData, vote_title: &str, ranking: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::ranking_saved_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Rangfolge gespeichert")
+            .description(format!("**{}**\n{}", vote_title, ranking).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an error that the deadline of a vote does not exist in the local time zone
+ */
+pub fn invalid_vote_deadline_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::invalid_vote_deadline_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Ungültiges Ende")
+            .description("Das angegebene Ende der Abstimmung existiert wegen der Zeitumstellung nicht. Bitte wähle eine andere Uhrzeit.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the vote does not allow as many options as given
+ */
+pub fn too_many_ballot_options_error(bot_data: &crate::BotData, max_choices: usize) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::too_many_ballot_options_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Zu viele Optionen")
+            .description(
+                format!(
+                    "In dieser Abstimmung kannst du höchstens `{}` {} wählen.",
+                    max_choices,
+                    if max_choices == 1 { "Option" } else { "Optionen" }
+                ).as_str()
+            )
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation with the saved choices of the user
+ */
+pub fn ballot_saved_successfully(bot_data: &crate::BotData, vote_title: &str, choices: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::ballot_saved_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Stimme gespeichert")
+            .description(format!("**{}**\n{}", vote_title, choices).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a message with the current tie break policy
+ */
+pub fn tie_break_policy_information(bot_data: &crate::BotData, changed: bool) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::tie_break_policy_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Regel bei Gleichstand geändert" } else { "Regel bei Gleichstand" })
+            .description(format!("Ein Gleichstand wird entschieden durch: {}", bot_data.tie_break_policy.get_description()).as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a list of the latest closed votes
+ */
+pub fn vote_history(bot_data: &crate::BotData, vote_lines: &[String]) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vote_history failed.").channel_id,
+        "",
+        |embed| embed
+            .title(":ballot_box: Vergangene Abstimmungen")
+            .description(
+                if vote_lines.is_empty() {
+                    String::from("Es wurde noch keine Abstimmung geschlossen.")
+                } else {
+                    vote_lines.join("\n")
+                }.as_str()
+            )
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends the details of a closed vote
+ */
+pub fn archived_vote_details(bot_data: &crate::BotData, archived_vote: &crate::vote_history_behaviour::ArchivedVote, description: &str) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::archived_vote_details failed.").channel_id,
+        "",
+        |embed| embed
+            .title(format!("Abstimmung `{}`: {}", archived_vote.id, archived_vote.title).as_str())
+            .description(description)
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that there is no closed vote with the id
+ */
+pub fn archived_vote_not_found_error(bot_data: &crate::BotData, id: u32) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::archived_vote_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Abstimmung nicht gefunden")
+            .description(format!("Es gibt keine geschlossene Abstimmung mit der ID `{}`. Mit `votes history` werden die letzten Abstimmungen angezeigt.", id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a confirmation that an open vote was changed
+ */
+pub fn vote_edited_successfully(bot_data: &crate::BotData, vote_title: &str, change: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vote_edited_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Abstimmung geändert")
+            .description(format!("**{}**\n{}", vote_title, change).as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends an error that the vote has no option with the emoji
+ */
+pub fn vote_option_not_found_error(bot_data: &crate::BotData, emoji: &str) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vote_option_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Option nicht gefunden")
+            .description(format!("Die Abstimmung hat keine Option mit dem Emoji {}.", emoji).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the last option of a vote can not be removed
+ */
+pub fn last_vote_option_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::last_vote_option_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Letzte Option")
+            .description("Die letzte Option einer Abstimmung kann nicht entfernt werden. Schließe die Abstimmung stattdessen.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that there is no open vote with the id
+ */
+pub fn vote_id_not_found_error(bot_data: &crate::BotData, vote_id: u32) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vote_id_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Abstimmung nicht gefunden")
+            .description(format!("Es gibt keine offene Abstimmung mit der ID `{}`.", vote_id).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the user owns several votes and has to choose one by its id
+ */
+pub fn multiple_votes_error(bot_data: &crate::BotData, vote_lines: &[String]) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::multiple_votes_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Mehrere Abstimmungen")
+            .description(format!("Bitte gib die ID der gewünschten Abstimmung an:\n{}", vote_lines.join("\n")).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that a movie given with tmdb: or imdb: could not be found on TMDb
+ */
+pub fn external_movie_not_found_error(bot_data: &crate::BotData, error: String) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::external_movie_not_found_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Film konnte nicht gefunden werden")
+            .description(format!("Der Film konnte nicht auf TMDb gefunden werden: {}", error).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends the offer to add the movie that won a vote, but is not on the watch list, as watched movie
+ */
+pub fn add_watched_movie_offer(
+    bot_data: &crate::BotData,
+    entry: &movie_behaviour::WatchListEntry,
+) -> Result<discord::model::Message, discord::Error> {
+    bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::add_watched_movie_offer failed.").channel_id,
+        "",
+        |embed| embed
+            .title(entry.movie.movie_title.as_str())
+            .url(movie_behaviour::get_movie_link(entry.movie.tmdb_id, false).as_str())
+            .thumbnail(movie_behaviour::generate_poster_link(&entry.movie.poster_path).as_str())
+            .description("Der Gewinner der Abstimmung ist noch nicht auf der Filmliste.")
+            .fields(|fields| fields
+                .field("Dauer", format!("{} min", entry.movie.runtime).as_str(), true)
+                .field("Genres", entry.movie.genres.as_str(), true)
+            )
+            .footer(|footer| footer.text("Soll der Film zur Filmliste hinzugefügt und als geschaut markiert werden? Das entscheidet der Ersteller der Abstimmung oder ein Administrator."))
+            .color(COLOR_INFORMATION)
+    )
+}
+
+/**
+ * Sends a message that the movie was added to the watch list as watched movie
+ */
+pub fn watched_movie_added_successfully(bot_data: &crate::BotData, id: u32, entry: &movie_behaviour::WatchListEntry) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::watched_movie_added_successfully failed.").channel_id,
+        "",
+        |embed| embed
+            .title(format!("{} wurde als geschaut hinzugefügt", entry.movie.movie_title).as_str())
+            .thumbnail(movie_behaviour::generate_poster_link(&entry.movie.poster_path).as_str())
+            .fields(|fields| fields
+                .field("ID", format!("`{:0>4}`", id).as_str(), true)
+                .field("Status", entry.status.get_emoji(), true)
+                .field("Hinzugefügt von", format!("<@{}>", entry.user_id).as_str(), true)
+            )
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a message showing the current selection policy for random movie votes
+ */
+pub fn selection_policy_information(bot_data: &crate::BotData, changed: bool) {
+    let policy = &bot_data.selection_policy;
+    let format_enabled = |enabled: bool| if enabled { "an" } else { "aus" };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::selection_policy_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Auswahlregel geändert" } else { "Auswahlregel" })
+            .description(format!(
+                "**Gewichtung:** {}\n**Ein Film pro Mitglied:** {}\n**Interesse bevorzugen:** {}\n**Verlierer benachteiligen:** {}\n**Unveröffentlichte ausschließen:** {}\n**Faire Verteilung:** {}",
+                policy.weighting.get_description(),
+                format_enabled(policy.one_per_owner),
+                format_enabled(policy.interest_boost),
+                format_enabled(policy.loss_penalty),
+                format_enabled(policy.exclude_unreleased),
+                policy.fair_share.get_description()
+            ).as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an information that no movie can be chosen for a random movie vote
+ */
+pub fn no_selection_candidates_information(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::no_selection_candidates_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Keine Filme")
+            .description("Mit der aktuellen Auswahlregel kann kein Film für eine zufällige Filmabstimmung ausgewählt werden.")
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that the period of the vetoes has to be at least one day
+ */
+pub fn veto_period_out_of_range_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::veto_period_out_of_range_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Ungültiger Zeitraum")
+            .description("Der Zeitraum für Vetos muss mindestens einen Tag lang sein.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a message showing how many vetoes every member has per period
+ */
+pub fn veto_limit_information(bot_data: &crate::BotData, changed: bool) {
+    let description = if bot_data.veto_limit == 0 {
+        String::from("Vetos sind deaktiviert.")
+    } else {
+        format!(
+            "Jedes Mitglied hat `{}` Vetos innerhalb von `{}` Tagen. Ein Veto entfernt einen Film aus einer offenen Filmabstimmung.",
+            bot_data.veto_limit,
+            bot_data.veto_period_days
+        )
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::veto_limit_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if changed { "Vetos geändert" } else { "Vetos" })
+            .description(description.as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends an error that vetoes are disabled
+ */
+pub fn vetoes_disabled_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::vetoes_disabled_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Vetos deaktiviert")
+            .description("Vetos sind aktuell deaktiviert. Ein Administrator kann sie mit `veto_limit` aktivieren.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends the reason why a veto was not possible to the channel, which is the private channel for vetoes by reaction
+ */
+pub fn veto_failed_error(bot_data: &crate::BotData, channel_id: discord::model::ChannelId, error: String) {
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title("Veto nicht möglich")
+            .description(error.as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a private message that the next reaction to an option of the vote is a veto, or that the veto was cancelled
+ */
+pub fn veto_armed_information(bot_data: &crate::BotData, channel_id: discord::model::ChannelId, vote_title: &str, armed: bool, remaining: u32) {
+    let description = if armed {
+        format!(
+            "Reagiere jetzt in der Abstimmung **{}** mit dem Emoji des Films, gegen den du ein Veto einlegen willst. Du hast noch `{}` Vetos. Reagiere erneut mit {} um abzubrechen.",
+            vote_title,
+            remaining,
+            crate::veto_behaviour::VETO_EMOJI
+        )
+    } else {
+        format!("Dein Veto in der Abstimmung **{}** wurde abgebrochen. Deine nächste Reaktion zählt wieder als Stimme.", vote_title)
+    };
+
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title(if armed { "Veto vorbereitet" } else { "Veto abgebrochen" })
+            .description(description.as_str())
+            .color(COLOR_INFORMATION)
+    );
+}
+
+/**
+ * Sends a message to the channel of the vote that a member vetoed an option, and which movie replaces it
+ */
+pub fn vote_option_vetoed_information(
+    bot_data: &crate::BotData,
+    channel_id: discord::model::ChannelId,
+    user_id: u64,
+    vote_title: &str,
+    label: String,
+    replacement_label: Option<String>,
+) {
+    let mut description = format!("<@{}> hat ein Veto gegen {} eingelegt.", user_id, label);
+    if let Some(replacement_label) = replacement_label {
+        description.push_str(format!("\nErsatz: {}", replacement_label).as_str());
+    }
+
+    let _ = bot_data.bot.send_embed(
+        channel_id,
+        "",
+        |embed| embed
+            .title(format!("Veto in {}", vote_title).as_str())
+            .description(description.as_str())
+            .color(COLOR_WARNING)
+    );
+}
+
+/**
+ * Sends an error that only movies on the watch list can be snoozed
+ */
+pub fn snooze_not_on_watch_list_error(bot_data: &crate::BotData, movie_title: &str) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::snooze_not_on_watch_list_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Film nicht auf der Filmliste")
+            .description(format!("**{}** ist nicht mehr auf der Filmliste und kann deshalb nicht pausiert werden.", movie_title).as_str())
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends an error that the date of a snooze has to be in the future
+ */
+pub fn snooze_date_in_past_error(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::snooze_date_in_past_error failed.").channel_id,
+        "",
+        |embed| embed
+            .title("Datum liegt in der Vergangenheit")
+            .description("Ein Film kann nur bis zu einem Datum in der Zukunft pausiert werden.")
+            .color(COLOR_ERROR)
+    );
+}
+
+/**
+ * Sends a message until when the movie is snoozed, or that the snooze was ended
+ */
+pub fn movie_snoozed_information(bot_data: &crate::BotData, movie_title: &str, snoozed_until: Option<chrono::DateTime<chrono::FixedOffset>>) {
+    let description = match snoozed_until {
+        Some(snoozed_until) => format!(
+            "{} **{}** wird bis {} um {} Uhr nicht in zufällige Filmabstimmungen aufgenommen und bleibt auf der Filmliste.",
+            crate::snooze_behaviour::SNOOZE_EMOJI,
+            movie_title,
+            crate::general_behaviour::timestamp_to_string(&snoozed_until, true),
+            snoozed_until.format("%H:%M")
+        ),
+        None => format!("**{}** wird wieder in zufällige Filmabstimmungen aufgenommen.", movie_title),
+    };
+
+    let _ = bot_data.bot.send_embed(
+        bot_data.message.as_ref().expect("Passing message to send_message::movie_snoozed_information failed.").channel_id,
+        "",
+        |embed| embed
+            .title(if snoozed_until.is_some() { "Film pausiert" } else { "Pause beendet" })
+            .description(description.as_str())
+            .color(COLOR_SUCCESS)
+    );
+}
+
+/**
+ * Sends a private message to the owner that the snooze of the movie ran out
+ */
+pub fn snooze_ended_information(bot_data: &crate::BotData, user_id: u64, movie_title: &str) {
+    if let Ok(private_channel) = bot_data.bot.create_private_channel(discord::model::UserId(user_id)) {
+        let _ = bot_data.bot.send_embed(
+            private_channel.id,
+            "",
+            |embed| embed
+                .title("Pause beendet")
+                .description(format!("Die Pause von **{}** ist abgelaufen. Der Film wird wieder in zufällige Filmabstimmungen aufgenommen.", movie_title).as_str())
+                .color(COLOR_INFORMATION)
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use crate::general_behaviour::{current_timestamp, is_user_administrator, timestamp_to_string};
+use crate::movie_behaviour::WatchListEntry;
+use crate::send_message;
+use std::str::FromStr;
+
+/**
+ * The marker of snoozed movies in the watch list
+ */
+pub const SNOOZE_EMOJI: &str = "💤";
+
+/**
+ * Until when a movie is snoozed, either for a duration or until the start of a date. Off ends the snooze.
+ */
+#[derive(Debug, PartialEq, Eq)]
+pub enum SnoozeEnd {
+    Minutes(u32),
+    Date(chrono::DateTime<chrono::FixedOffset>),
+    Off,
+}
+
+impl FromStr for SnoozeEnd {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.to_lowercase() == "off" {
+            return Ok(Self::Off);
+        }
+
+        if let Some(minutes) = crate::general_behaviour::parse_duration_minutes(s) {
+            return Ok(Self::Minutes(minutes));
+        }
+
+        crate::event_behaviour::parse_event_date(s, "00:00").map(Self::Date).ok_or(())
+    }
+}
+
+/**
+ * Returns true if the movie is snoozed and therefore skipped by random movie votes
+ */
+pub fn is_snoozed(entry: &WatchListEntry) -> bool {
+    matches!(entry.snoozed_until, Some(snoozed_until) if snoozed_until > current_timestamp())
+}
+
+/**
+ * Formats the end of the snooze for the watch list, or nothing if the movie is not snoozed
+ */
+pub fn format_snooze_suffix(entry: &WatchListEntry) -> String {
+    match entry.snoozed_until {
+        Some(snoozed_until) if is_snoozed(entry) => format!(
+            " | {} bis {} {} Uhr",
+            SNOOZE_EMOJI,
+            timestamp_to_string(&snoozed_until, false),
+            snoozed_until.format("%H:%M")
+        ),
+        _ => String::new(),
+    }
+}
+
+/**
+ * Snoozes the movie with the id until the end, so it stays on the watch list but is skipped by random movie votes.
+ * Owners can snooze their own movies, administrators every movie.
+ */
+pub fn snooze_movie(bot_data: &mut crate::BotData, id: u32, end: SnoozeEnd) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to snooze_movie function failed.").author.id;
+
+    let entry = match bot_data.watch_list.get(&id) {
+        Some(entry) => entry,
+        None => return send_message::movie_id_not_found_error(bot_data, &id),
+    };
+
+    if entry.user_id != author_id && !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if !entry.status.is_watch_list_status() {
+        return send_message::snooze_not_on_watch_list_error(bot_data, &entry.movie.movie_title);
+    }
+
+    let snoozed_until = match end {
+        SnoozeEnd::Minutes(minutes) => Some(current_timestamp() + chrono::Duration::minutes(minutes as i64)),
+        SnoozeEnd::Date(date) if date > current_timestamp() => Some(date),
+        SnoozeEnd::Date(_) => return send_message::snooze_date_in_past_error(bot_data),
+        SnoozeEnd::Off => None,
+    };
+
+    // This can not panic, since the movie was found above
+    let entry = bot_data.watch_list.get_mut(&id).unwrap();
+    entry.snoozed_until = snoozed_until;
+    let movie_title = entry.movie.movie_title.clone();
+
+    send_message::movie_snoozed_information(bot_data, &movie_title, snoozed_until);
+}
+
+/**
+ * Ends the snoozes that have run out and tells the owners by private message.
+ * Returns true if a snooze ended, so the bot data changed.
+ */
+pub fn end_expired_snoozes(bot_data: &mut crate::BotData) -> bool {
+    let now = current_timestamp();
+    let mut woken_up: Vec<(u64, String)> = Vec::new();
+
+    for entry in bot_data.watch_list.values_mut() {
+        if matches!(entry.snoozed_until, Some(snoozed_until) if snoozed_until <= now) {
+            entry.snoozed_until = None;
+            woken_up.push((entry.user_id.0, entry.movie.movie_title.clone()));
+        }
+    }
+
+    for (user_id, movie_title) in woken_up.iter() {
+        send_message::snooze_ended_information(bot_data, *user_id, movie_title);
+    }
+
+    !woken_up.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_snooze_ends() {
+        assert_eq!(SnoozeEnd::from_str("off"), Ok(SnoozeEnd::Off));
+        assert_eq!(SnoozeEnd::from_str("OFF"), Ok(SnoozeEnd::Off));
+        assert_eq!(SnoozeEnd::from_str("2d"), Ok(SnoozeEnd::Minutes(2 * 24 * 60)));
+        assert_eq!(SnoozeEnd::from_str("90"), Ok(SnoozeEnd::Minutes(90)));
+        assert_eq!(SnoozeEnd::from_str("bald"), Err(()));
+        assert_eq!(SnoozeEnd::from_str("31.02.2022"), Err(()));
+
+        match SnoozeEnd::from_str("24.12.2022") {
+            Ok(SnoozeEnd::Date(date)) => assert_eq!(date.format("%d.%m.%Y %H:%M").to_string(), "24.12.2022 00:00"),
+            _ => panic!("A date must be parsed as the start of the day"),
+        }
+    }
+
+    #[test]
+    fn only_future_snoozes_count() {
+        let mut entry = crate::get_test_watch_list_entry("Alien", 1);
+        assert!(!is_snoozed(&entry));
+        assert_eq!(format_snooze_suffix(&entry), "");
+
+        entry.snoozed_until = Some(current_timestamp() + chrono::Duration::hours(1));
+        assert!(is_snoozed(&entry));
+        assert!(format_snooze_suffix(&entry).starts_with(" | 💤 bis "));
+
+        entry.snoozed_until = Some(current_timestamp() - chrono::Duration::minutes(1));
+        assert!(!is_snoozed(&entry));
+        assert_eq!(format_snooze_suffix(&entry), "");
+    }
+}
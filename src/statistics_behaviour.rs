@@ -0,0 +1,175 @@
+use crate::general_behaviour::{send_paginated_message, split_into_pages};
+use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+use crate::send_message;
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/**
+ * The length of the bars in the distribution views
+ */
+const BAR_LENGTH: usize = 10;
+
+/**
+ * Formats a runtime in minutes as days, hours and minutes, e.g. 2 Tage 3 Std. 12 Min.
+ */
+fn format_total_runtime(minutes: u32) -> String {
+    let days = minutes / (24 * 60);
+    let hours = (minutes % (24 * 60)) / 60;
+    let minutes = minutes % 60;
+
+    if days > 0 {
+        format!("{} {} {} Std. {} Min.", days, if days == 1 { "Tag" } else { "Tage" }, hours, minutes)
+    } else {
+        format!("{} Std. {} Min.", hours, minutes)
+    }
+}
+
+/**
+ * Formats one line of a distribution view with the absolute count, the share and a bar
+ */
+fn format_distribution_line(name: &str, count: usize, total: usize) -> String {
+    let share = count as f64 / total as f64;
+    let filled = (share * BAR_LENGTH as f64).round() as usize;
+
+    format!(
+        "`{}{}` **{}** - {} ({:.1}%)",
+        "█".repeat(filled),
+        "░".repeat(BAR_LENGTH - filled),
+        name,
+        count,
+        share * 100.0
+    )
+}
+
+/**
+ * Counts the values and returns the formatted lines sorted by count descending and name ascending
+ */
+fn generate_distribution(values: Vec<String>, total: usize) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+
+    counts.iter()
+        .sorted_by(|first, second| second.1.cmp(first.1).then_with(|| first.0.cmp(second.0)))
+        .map(|(name, count)| format_distribution_line(name, *count, total))
+        .collect()
+}
+
+/**
+ * Adds the lines of a view as pages with the view title as heading on every page
+ */
+fn add_view_pages(pages: &mut Vec<String>, view_title: &str, lines: Vec<String>) {
+    if lines.is_empty() {
+        return;
+    }
+
+    for page in split_into_pages(&lines, crate::MAX_STATISTIC_LINES_PER_PAGE) {
+        pages.push(format!("**{}**\n\n{}", view_title, page));
+    }
+}
+
+/**
+ * Generates the overview with the total number of watched movies, the total runtime and the
+ * average time the movies stayed on the watch list
+ */
+fn generate_overview(watched: &[&WatchListEntry], watch_list_count: usize) -> Vec<String> {
+    let total_runtime: u32 = watched.iter().map(|entry| entry.movie.runtime).sum();
+
+    let waiting_days: Vec<i64> = watched.iter()
+        .filter_map(|entry| entry.watched_or_removed_timestamp
+            .map(|watched_timestamp| (watched_timestamp - entry.added_timestamp).num_days()))
+        .collect();
+
+    let average_waiting_time = if waiting_days.is_empty() {
+        String::from("-")
+    } else {
+        format!("{:.1} Tage", waiting_days.iter().sum::<i64>() as f64 / waiting_days.len() as f64)
+    };
+
+    vec![
+        format!("Geschaute Filme: **{}**", watched.len()),
+        format!("Filme auf der Filmliste: **{}**", watch_list_count),
+        format!("Gesamte Laufzeit: **{}**", format_total_runtime(total_runtime)),
+        format!(
+            "Durchschnittliche Laufzeit: **{} min**",
+            if watched.is_empty() { 0 } else { total_runtime / watched.len() as u32 }
+        ),
+        format!("Durchschnittliche Wartezeit auf der Filmliste: **{}**", average_waiting_time),
+    ]
+}
+
+/**
+ * Generates the months in which the most movies were watched
+ */
+fn generate_busiest_months(watched: &[&WatchListEntry]) -> Vec<String> {
+    let mut counts: HashMap<(i32, u32), usize> = HashMap::new();
+    for timestamp in watched.iter().filter_map(|entry| entry.watched_or_removed_timestamp) {
+        *counts.entry((chrono::Datelike::year(&timestamp), chrono::Datelike::month(&timestamp))).or_insert(0) += 1;
+    }
+
+    counts.iter()
+        .sorted_by(|first, second| second.1.cmp(first.1).then_with(|| second.0.cmp(first.0)))
+        .map(|((year, month), count)| format!(
+            "`{:0>2}.{}` - {} {}",
+            month,
+            year,
+            count,
+            if *count == 1 { "Film" } else { "Filme" }
+        ))
+        .collect()
+}
+
+/**
+ * Generates the share of the watched movies that each member added
+ */
+fn generate_member_shares(watched: &[&WatchListEntry]) -> Vec<String> {
+    let mut members: HashMap<u64, (String, usize)> = HashMap::new();
+    for entry in watched.iter() {
+        let member = members.entry(entry.user_id.0).or_insert((entry.user.clone(), 0));
+        member.1 += 1;
+    }
+
+    members.values()
+        .sorted_by(|first, second| second.1.cmp(&first.1).then_with(|| first.0.cmp(&second.0)))
+        .map(|(name, count)| format_distribution_line(name, *count, watched.len()))
+        .collect()
+}
+
+/**
+ * Sends the statistics of all watched movies. Every view is shown on its own pages.
+ */
+pub fn show_statistics(bot_data: &mut crate::BotData) {
+    let channel_id = bot_data.message.as_ref().expect("Passing message to show_statistics function failed.").channel_id;
+
+    let watched: Vec<&WatchListEntry> = bot_data.watch_list.values()
+        .filter(|entry| entry.status == MovieStatus::Watched)
+        .collect();
+
+    if watched.is_empty() {
+        return send_message::no_watched_movies_information(bot_data);
+    }
+
+    let watch_list_count = bot_data.watch_list.values()
+        .filter(|entry| entry.status.is_watch_list_status())
+        .count();
+
+    let genres: Vec<String> = watched.iter()
+        .flat_map(|entry| crate::filter_behaviour::get_genre_names(entry))
+        .filter(|genre| !genre.is_empty())
+        .collect();
+
+    let languages: Vec<String> = watched.iter()
+        .map(|entry| entry.movie.original_language.to_uppercase())
+        .collect();
+
+    let mut pages: Vec<String> = Vec::new();
+    add_view_pages(&mut pages, "Übersicht", generate_overview(&watched, watch_list_count));
+    add_view_pages(&mut pages, "Genres", generate_distribution(genres, watched.len()));
+    add_view_pages(&mut pages, "Originalsprachen", generate_distribution(languages, watched.len()));
+    add_view_pages(&mut pages, "Filmreichste Monate", generate_busiest_months(&watched));
+    add_view_pages(&mut pages, "Anteil der Mitglieder", generate_member_shares(&watched));
+    add_view_pages(&mut pages, "Anwesenheit", crate::attendance_behaviour::generate_attendance_lines(bot_data));
+
+    send_paginated_message(bot_data, channel_id, String::from(":bar_chart: Statistiken"), pages);
+}
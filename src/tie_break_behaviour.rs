@@ -0,0 +1,194 @@
+use crate::general_behaviour::is_user_administrator;
+use crate::movie_behaviour::MovieStatus;
+use crate::send_message;
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/**
+ * How a tie between the options with the most votes is broken
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TieBreakPolicy {
+    #[default]
+    Random, // A random option is chosen, the seed is published in the summary
+    OldestMovie, // The movie with the lowest id wins
+    FewestWatched, // The movie of the member with the fewest watched movies wins
+    Runoff, // A new vote with only the tied options is started
+}
+
+impl TieBreakPolicy {
+    pub fn get_description(&self) -> &str {
+        match self {
+            TieBreakPolicy::Random => "Zufall mit veröffentlichtem Seed",
+            TieBreakPolicy::OldestMovie => "Ältester Film auf der Filmliste (niedrigste ID)",
+            TieBreakPolicy::FewestWatched => "Film des Mitglieds mit den wenigsten geschauten Filmen",
+            TieBreakPolicy::Runoff => "Stichwahl zwischen den gleichauf liegenden Optionen",
+        }
+    }
+}
+
+impl FromStr for TieBreakPolicy {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "random" => Self::Random,
+            "oldest" => Self::OldestMovie,
+            "fewest_watched" => Self::FewestWatched,
+            "runoff" => Self::Runoff,
+            _ => return Err(()),
+        })
+    }
+}
+
+/**
+ * The decision of a tie break, either the winner with the rule that decided or a runoff vote
+ */
+pub enum TieBreak {
+    Winner(/*emoji*/ String, /*rule*/ String),
+    Runoff,
+}
+
+/**
+ * Chooses a random option with a new seed. The seed is part of the rule, so everybody can reproduce the result.
+ */
+fn break_tie_randomly(tied: &[(String, Option<u32>)]) -> TieBreak {
+    let seed: u64 = rand::random();
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    match tied.choose(&mut rng) {
+        Some((emoji, _)) => TieBreak::Winner(emoji.clone(), format!("Zufall mit dem Seed `{}`", seed)),
+        None => TieBreak::Runoff,
+    }
+}
+
+/**
+ * Breaks the tie between the given options with the configured policy. The options are given as emoji
+ * and the watch list id if the option is a movie. Policies that only work for movies fall back to a random choice
+ * if none of the options is a movie, a runoff falls back to a random choice if it is not possible.
+ */
+pub fn break_tie(bot_data: &crate::BotData, tied: &[(String, Option<u32>)], runoff_possible: bool) -> TieBreak {
+    let tied_movies: Vec<(&String, u32)> = tied.iter()
+        .filter_map(|(emoji, id)| id.map(|id| (emoji, id)))
+        .collect();
+
+    match bot_data.tie_break_policy {
+        TieBreakPolicy::Random => break_tie_randomly(tied),
+        TieBreakPolicy::OldestMovie => match tied_movies.iter().min_by_key(|(_, id)| *id) {
+            Some((emoji, _)) => TieBreak::Winner(emoji.to_string(), TieBreakPolicy::OldestMovie.get_description().to_string()),
+            None => break_tie_randomly(tied),
+        },
+        TieBreakPolicy::FewestWatched => {
+            let count_watched_movies_of_owner = |id: u32| {
+                bot_data.watch_list.get(&id)
+                    .map(|entry| bot_data.watch_list.values()
+                        .filter(|other| other.user_id == entry.user_id && other.status == MovieStatus::Watched)
+                        .count())
+                    .unwrap_or(0)
+            };
+
+            // If the owners watched equally many movies, the oldest movie wins
+            match tied_movies.iter().min_by_key(|(_, id)| (count_watched_movies_of_owner(*id), *id)) {
+                Some((emoji, _)) => TieBreak::Winner(emoji.to_string(), TieBreakPolicy::FewestWatched.get_description().to_string()),
+                None => break_tie_randomly(tied),
+            }
+        },
+        TieBreakPolicy::Runoff if runoff_possible => TieBreak::Runoff,
+        TieBreakPolicy::Runoff => break_tie_randomly(tied),
+    }
+}
+
+/**
+ * Sets the tie break policy for all votes. Only administrators can change it.
+ */
+pub fn set_tie_break_policy(bot_data: &mut crate::BotData, policy: TieBreakPolicy) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_tie_break_policy function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    bot_data.tie_break_policy = policy;
+    send_message::tie_break_policy_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current tie break policy
+ */
+pub fn show_tie_break_policy(bot_data: &crate::BotData) {
+    send_message::tie_break_policy_information(bot_data, false);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tied(options: &[(&str, Option<u32>)]) -> Vec<(String, Option<u32>)> {
+        options.iter().map(|(emoji, id)| (emoji.to_string(), *id)).collect()
+    }
+
+    fn get_winner(tie_break: TieBreak) -> Option<String> {
+        match tie_break {
+            TieBreak::Winner(emoji, _) => Some(emoji),
+            TieBreak::Runoff => None,
+        }
+    }
+
+    #[test]
+    fn oldest_movie_wins() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::OldestMovie;
+
+        let tie_break = break_tie(&bot_data, &tied(&[("A", Some(7)), ("B", Some(3)), ("C", Some(5))]), true);
+
+        assert_eq!(get_winner(tie_break), Some(String::from("B")));
+    }
+
+    #[test]
+    fn oldest_movie_without_movies_falls_back_to_random() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::OldestMovie;
+
+        let tie_break = break_tie(&bot_data, &tied(&[("A", None), ("B", None)]), true);
+
+        assert!(matches!(get_winner(tie_break).as_deref(), Some("A") | Some("B")));
+    }
+
+    #[test]
+    fn owner_with_fewest_watched_movies_wins() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::FewestWatched;
+        bot_data.watch_list.insert(1, crate::get_test_watch_list_entry("Alien", 1));
+        bot_data.watch_list.insert(2, crate::get_test_watch_list_entry("Solaris", 2));
+        bot_data.watch_list.insert(3, crate::get_test_watch_list_entry("Stalker", 3));
+        let mut watched_entry = crate::get_test_watch_list_entry("Aliens", 1);
+        watched_entry.status = MovieStatus::Watched;
+        bot_data.watch_list.insert(4, watched_entry);
+
+        let tie_break = break_tie(&bot_data, &tied(&[("A", Some(1)), ("C", Some(3)), ("B", Some(2))]), true);
+
+        // The owners of B and C watched no movie yet, so the older movie B wins
+        assert_eq!(get_winner(tie_break), Some(String::from("B")));
+    }
+
+    #[test]
+    fn runoff_only_if_possible() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::Runoff;
+        let tied_options = tied(&[("A", Some(1)), ("B", Some(2))]);
+
+        assert!(matches!(break_tie(&bot_data, &tied_options, true), TieBreak::Runoff));
+        assert!(matches!(get_winner(break_tie(&bot_data, &tied_options, false)).as_deref(), Some("A") | Some("B")));
+    }
+
+    #[test]
+    fn random_publishes_the_seed() {
+        let bot_data = crate::get_test_bot_data();
+
+        match break_tie(&bot_data, &tied(&[("A", None), ("B", None)]), true) {
+            TieBreak::Winner(_, rule) => assert!(rule.contains("Seed")),
+            TieBreak::Runoff => panic!("A random tie break must choose a winner"),
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use crate::general_behaviour::{current_timestamp, is_user_administrator, send_paginated_message, split_into_pages};
+use crate::send_message;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+/**
+ * The reaction under a movie vote that turns the next reaction to an option into a veto
+ */
+pub const VETO_EMOJI: &str = "🚫";
+
+/**
+ * A veto of a member against an option of a movie vote
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Veto {
+    pub user_id: u64,
+    pub vote_id: u32,
+    pub movie_id: Option<u32>, // None if the vetoed movie was not on the watch list
+    pub movie_title: String,
+    pub date: chrono::DateTime<chrono::FixedOffset>,
+}
+
+/**
+ * Returns the vetoes of the user within the current period
+ */
+fn get_vetoes_in_period(bot_data: &crate::BotData, user_id: u64) -> Vec<&Veto> {
+    let since = current_timestamp() - chrono::Duration::days(bot_data.veto_period_days as i64);
+
+    bot_data
+        .vetoes
+        .iter()
+        .filter(|veto| veto.user_id == user_id && veto.date > since)
+        .collect()
+}
+
+/**
+ * Returns how many vetoes the user has used within the current period
+ */
+pub fn count_used_vetoes(bot_data: &crate::BotData, user_id: u64) -> u32 {
+    get_vetoes_in_period(bot_data, user_id).len() as u32
+}
+
+/**
+ * Returns how many vetoes the user has left within the current period
+ */
+pub fn count_remaining_vetoes(bot_data: &crate::BotData, user_id: u64) -> u32 {
+    bot_data.veto_limit.saturating_sub(count_used_vetoes(bot_data, user_id))
+}
+
+/**
+ * Returns how many vetoes the user has left, or the reason as error if the user can not veto
+ */
+pub fn check_veto_available(bot_data: &crate::BotData, user_id: u64) -> Result<u32, String> {
+    if bot_data.veto_limit == 0 {
+        return Err(String::from("Vetos sind aktuell deaktiviert."));
+    }
+
+    match count_remaining_vetoes(bot_data, user_id) {
+        0 => Err(format!(
+            "Du hast bereits alle {} Vetos der letzten {} Tage verbraucht.",
+            bot_data.veto_limit, bot_data.veto_period_days
+        )),
+        remaining => Ok(remaining),
+    }
+}
+
+/**
+ * Returns the ids of the movies that were vetoed in the vote, so they are not drawn again as replacement
+ */
+pub fn get_vetoed_movie_ids(bot_data: &crate::BotData, vote_id: u32) -> Vec<u32> {
+    bot_data
+        .vetoes
+        .iter()
+        .filter(|veto| veto.vote_id == vote_id)
+        .filter_map(|veto| veto.movie_id)
+        .collect()
+}
+
+/**
+ * Remembers the veto of the user
+ */
+pub fn record_veto(bot_data: &mut crate::BotData, user_id: u64, vote_id: u32, movie_id: Option<u32>, movie_title: String) {
+    bot_data.vetoes.push(Veto {
+        user_id,
+        vote_id,
+        movie_id,
+        movie_title,
+        date: current_timestamp(),
+    });
+}
+
+/**
+ * Sets how many vetoes every member has per period and optionally the length of the period in days.
+ * Only administrators can change it.
+ */
+pub fn set_veto_limit(bot_data: &mut crate::BotData, limit: u32, period_days: Option<u32>) {
+    let author_id = bot_data.message.as_ref().expect("Passing message to set_veto_limit function failed.").author.id;
+
+    if !is_user_administrator(bot_data, author_id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    if period_days == Some(0) {
+        return send_message::veto_period_out_of_range_error(bot_data);
+    }
+
+    bot_data.veto_limit = limit;
+    if let Some(period_days) = period_days {
+        bot_data.veto_period_days = period_days;
+    }
+
+    send_message::veto_limit_information(bot_data, true);
+}
+
+/**
+ * Sends a message showing the current veto limit
+ */
+pub fn show_veto_limit(bot_data: &crate::BotData) {
+    send_message::veto_limit_information(bot_data, false);
+}
+
+/**
+ * Sends how many vetoes every member has used and has left within the current period, and which movies were vetoed
+ */
+pub fn show_vetoes(bot_data: &mut crate::BotData) {
+    let message = bot_data.message.clone().expect("Passing message to show_vetoes function failed.");
+
+    if bot_data.veto_limit == 0 {
+        return send_message::vetoes_disabled_error(bot_data);
+    }
+
+    // The author is shown even without vetoes, so everyone can see the own remaining vetoes
+    let user_ids: Vec<u64> = std::iter::once(message.author.id.0)
+        .chain(bot_data.vetoes.iter().map(|veto| veto.user_id))
+        .unique()
+        .filter(|user_id| *user_id == message.author.id.0 || count_used_vetoes(bot_data, *user_id) > 0)
+        .collect();
+
+    let lines: Vec<String> = user_ids
+        .iter()
+        .map(|user_id| {
+            let titles = get_vetoes_in_period(bot_data, *user_id)
+                .iter()
+                .map(|veto| veto.movie_title.as_str())
+                .join(", ");
+
+            format!(
+                "<@{}> | {} verbraucht | {} übrig{}",
+                user_id,
+                count_used_vetoes(bot_data, *user_id),
+                count_remaining_vetoes(bot_data, *user_id),
+                if titles.is_empty() { String::new() } else { format!(" | {}", titles) }
+            )
+        })
+        .collect();
+
+    send_paginated_message(
+        bot_data,
+        message.channel_id,
+        format!(":no_entry_sign: Vetos der letzten {} Tage", bot_data.veto_period_days),
+        split_into_pages(&lines, crate::MAX_ENTRIES_PER_PAGE)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_only_the_vetoes_of_the_current_period() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.veto_limit = 2;
+        bot_data.veto_period_days = 30;
+
+        record_veto(&mut bot_data, 1, 1, Some(4), String::from("Alien"));
+        record_veto(&mut bot_data, 2, 1, None, String::from("Solaris"));
+        record_veto(&mut bot_data, 1, 2, Some(5), String::from("Aliens"));
+        bot_data.vetoes[2].date = current_timestamp() - chrono::Duration::days(31);
+
+        assert_eq!(count_used_vetoes(&bot_data, 1), 1);
+        assert_eq!(count_remaining_vetoes(&bot_data, 1), 1);
+        assert_eq!(get_vetoed_movie_ids(&bot_data, 1), vec![4]);
+    }
+
+    #[test]
+    fn vetoes_are_only_available_within_the_limit() {
+        let mut bot_data = crate::get_test_bot_data();
+        assert!(check_veto_available(&bot_data, 1).is_err());
+
+        bot_data.veto_limit = 1;
+        bot_data.veto_period_days = 30;
+        assert_eq!(check_veto_available(&bot_data, 1), Ok(1));
+
+        record_veto(&mut bot_data, 1, 1, Some(4), String::from("Alien"));
+        assert!(check_veto_available(&bot_data, 1).is_err());
+        assert_eq!(check_veto_available(&bot_data, 2), Ok(1));
+    }
+}
@@ -0,0 +1,191 @@
+use crate::general_behaviour::timestamp_to_string;
+use crate::send_message;
+use crate::voting_behaviour::VoteMode;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/**
+ * The number of the latest closed votes shown in the vote history
+ */
+const VOTE_HISTORY_LENGTH: usize = 15;
+
+/**
+ * One option of a closed vote with the ids of the users who voted for it
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArchivedVoteOption {
+    pub emoji: String,
+    pub label: String, // The emoji followed by the option or the movie title
+    pub movie_id: Option<u32>, // The watch list id if the option is a movie
+    pub votes: Vec<u64>, // For ranked votes only the first preferences. Empty for secret votes.
+
+    #[serde(default)]
+    pub vote_count: usize, // Archived for secret votes as well, older archive entries only have the votes
+}
+
+impl ArchivedVoteOption {
+    fn count_votes(&self) -> usize {
+        self.vote_count.max(self.votes.len())
+    }
+}
+
+/**
+ * A closed vote with its options, the choices of every user and the result.
+ * Secret votes only keep the number of votes of every option.
+ */
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArchivedVote {
+    pub id: u32,
+    pub title: String,
+    pub creator_id: u64,
+    pub mode: VoteMode,
+
+    #[serde(default)]
+    pub secret: bool,
+
+    pub options: Vec<ArchivedVoteOption>,
+    pub rankings: HashMap<u64, Vec<String>>, // The emojis of the ranked options in order of preference, keys are the user ids
+    pub winner: Option<String>, // The emoji of the winning option
+    pub decision: Option<String>, // The rule that broke a tie, or the runoff vote that follows
+    pub creation_date: chrono::DateTime<chrono::FixedOffset>,
+    pub closing_date: chrono::DateTime<chrono::FixedOffset>,
+}
+
+impl ArchivedVote {
+    fn get_label(&self, emoji: &str) -> String {
+        self.options.iter()
+            .find(|option| option.emoji == emoji)
+            .map(|option| option.label.clone())
+            .unwrap_or_else(|| emoji.to_string())
+    }
+
+    /**
+     * Returns true if the movie was an option of the vote and another option won.
+     * Votes without a winner, e.g. with a runoff vote, are not lost.
+     */
+    fn is_lost_by(&self, movie_id: u32) -> bool {
+        match &self.winner {
+            Some(winner) => self.options.iter().any(|option| option.movie_id == Some(movie_id))
+                && self.options.iter().any(|option| option.emoji == *winner && option.movie_id != Some(movie_id)),
+            None => false,
+        }
+    }
+
+    fn format_winner(&self) -> String {
+        match &self.winner {
+            Some(winner) => self.get_label(winner),
+            None => String::from("Kein Gewinner"),
+        }
+    }
+}
+
+/**
+ * Adds the closed vote to the archive. The vote keeps the id it had while it was open.
+ */
+pub fn archive_vote(bot_data: &mut crate::BotData, archived_vote: ArchivedVote) {
+    bot_data.vote_archive.insert(archived_vote.id, archived_vote);
+}
+
+/**
+ * Sends a list of the latest closed votes with their winners
+ */
+pub fn show_vote_history(bot_data: &crate::BotData) {
+    let vote_lines: Vec<String> = bot_data.vote_archive.values()
+        .sorted_by(|first, second| second.id.cmp(&first.id))
+        .take(VOTE_HISTORY_LENGTH)
+        .map(|archived_vote| format!(
+            "`{}` **{}** | {} | {}",
+            archived_vote.id,
+            archived_vote.title,
+            timestamp_to_string(&archived_vote.closing_date, false),
+            archived_vote.format_winner()
+        ))
+        .collect();
+
+    send_message::vote_history(bot_data, &vote_lines);
+}
+
+/**
+ * Sends the options, the choices of every user and the result of a closed vote
+ */
+pub fn show_archived_vote(bot_data: &crate::BotData, id: u32) {
+    let archived_vote = match bot_data.vote_archive.get(&id) {
+        Some(archived_vote) => archived_vote,
+        None => return send_message::archived_vote_not_found_error(bot_data, id),
+    };
+
+    let mut description = format!(
+        "Erstellt von <@{}> am {}\nGeschlossen am {}\n",
+        archived_vote.creator_id,
+        timestamp_to_string(&archived_vote.creation_date, true),
+        timestamp_to_string(&archived_vote.closing_date, true)
+    );
+
+    if archived_vote.secret {
+        description.push_str("Geheime Abstimmung, es werden nur die Stimmen gezählt\n");
+    }
+
+    for option in archived_vote.options.iter() {
+        description.push_str(format!(
+            "\n`{}` {}{}",
+            option.count_votes(),
+            option.label,
+            if option.votes.is_empty() || archived_vote.secret {
+                String::new()
+            } else {
+                format!(": {}", option.votes.iter().map(|user_id| format!("<@{}>", user_id)).join(", "))
+            }
+        ).as_str());
+    }
+
+    if archived_vote.mode == VoteMode::Ranked && !archived_vote.secret && !archived_vote.rankings.is_empty() {
+        description.push_str("\n\n**Rangfolgen**");
+
+        for (user_id, ranking) in archived_vote.rankings.iter().sorted_by_key(|(user_id, _)| **user_id) {
+            description.push_str(format!(
+                "\n<@{}>: {}",
+                user_id,
+                ranking.iter().enumerate().map(|(idx, emoji)| format!("{}. {}", idx + 1, archived_vote.get_label(emoji))).join(", ")
+            ).as_str());
+        }
+    }
+
+    if let Some(decision) = &archived_vote.decision {
+        description.push_str(format!("\n\n**Entscheidung:** {}", decision).as_str());
+    }
+    description.push_str(format!("\n\n**Gewinner:** {}", archived_vote.format_winner()).as_str());
+
+    send_message::archived_vote_details(bot_data, archived_vote, &description);
+}
+
+/**
+ * Formats how often the movie appeared in closed votes and how often it lost, e.g. for the movie information
+ */
+pub fn format_vote_appearances(bot_data: &crate::BotData, movie_id: u32) -> String {
+    let appearances: Vec<&ArchivedVote> = bot_data.vote_archive.values()
+        .filter(|archived_vote| archived_vote.options.iter().any(|option| option.movie_id == Some(movie_id)))
+        .collect();
+
+    if appearances.is_empty() {
+        return String::from("Noch in keiner Abstimmung");
+    }
+
+    let lost = appearances.iter().filter(|archived_vote| archived_vote.is_lost_by(movie_id)).count();
+
+    format!(
+        "{} {}, davon {} verloren",
+        appearances.len(),
+        if appearances.len() == 1 { "Abstimmung" } else { "Abstimmungen" },
+        lost
+    )
+}
+
+/**
+ * Counts the closed votes the movie lost since the given date
+ */
+pub fn count_lost_votes_since(bot_data: &crate::BotData, movie_id: u32, since: chrono::DateTime<chrono::FixedOffset>) -> usize {
+    bot_data.vote_archive.values()
+        .filter(|archived_vote| archived_vote.closing_date >= since && archived_vote.is_lost_by(movie_id))
+        .count()
+}
@@ -1,8 +1,11 @@
+use crate::general_behaviour::{current_timestamp, format_duration_minutes};
 use crate::movie_behaviour::get_movie_id_in_watch_list;
 use crate::send_message;
+use chrono::TimeZone;
 use rand::distributions::{Distribution, Uniform};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 #[derive(Clone, Serialize, Deserialize)]
 pub enum VoteOptionEnum {
@@ -10,21 +13,221 @@ pub enum VoteOptionEnum {
     MovieVoteOption(VoteOption<crate::movie_behaviour::Movie>),
 }
 
+impl VoteOptionEnum {
+    pub fn get_emoji(&self) -> &String {
+        match self {
+            VoteOptionEnum::GeneralVoteOption(general_option) => &general_option.emoji,
+            VoteOptionEnum::MovieVoteOption(movie_option) => &movie_option.emoji,
+        }
+    }
+
+    pub fn get_votes(&self) -> &Vec<discord::model::UserId> {
+        match self {
+            VoteOptionEnum::GeneralVoteOption(general_option) => &general_option.votes,
+            VoteOptionEnum::MovieVoteOption(movie_option) => &movie_option.votes,
+        }
+    }
+
+    pub fn get_votes_mut(&mut self) -> &mut Vec<discord::model::UserId> {
+        match self {
+            VoteOptionEnum::GeneralVoteOption(general_option) => &mut general_option.votes,
+            VoteOptionEnum::MovieVoteOption(movie_option) => &mut movie_option.votes,
+        }
+    }
+
+    /**
+     * Returns the emoji and the name of the option, e.g. for the results of a vote
+     */
+    pub fn get_label(&self) -> String {
+        match self {
+            VoteOptionEnum::GeneralVoteOption(general_option) => {
+                format!("{} {}", general_option.emoji, general_option.cargo)
+            }
+            VoteOptionEnum::MovieVoteOption(movie_option) => {
+                format!("{} {}", movie_option.emoji, movie_option.cargo.movie_title)
+            }
+        }
+    }
+
+    /**
+     * Returns the watch list id if the option is a movie from the watch list
+     */
+    pub fn get_movie_id(&self) -> Option<u32> {
+        match self {
+            VoteOptionEnum::MovieVoteOption(movie_option) if !movie_option.external => Some(movie_option.id),
+            _ => None,
+        }
+    }
+}
+
+/**
+ * How members vote and how the winner of a vote is determined
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoteMode {
+    #[default]
+    Single, // Every member chooses one option, the option with the most votes wins
+    Ranked, // Every member ranks the options, the winner is determined by instant runoff
+    Approval, // Every member approves any number of options, the option with the most approvals wins
+    PickUpTo(u32), // Every member chooses up to the given number of options, the option with the most votes wins
+}
+
+/**
+ * The latest number of minutes before the deadline of a vote at which members who have not voted yet are reminded
+ */
+const VOTE_REMINDER_MINUTES: i64 = 30;
+
+/**
+ * When a vote closes automatically, either after a duration or at the next occurrence of a time of day
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoteDeadline {
+    Duration(u32), // Minutes after the creation of the vote
+    Until(chrono::NaiveTime),
+}
+
+impl VoteDeadline {
+    /**
+     * Returns the date at which a vote that is created now closes
+     */
+    fn get_date(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        let now = current_timestamp();
+
+        match self {
+            VoteDeadline::Duration(minutes) => Some(now + chrono::Duration::minutes(*minutes as i64)),
+            VoteDeadline::Until(time) => {
+                let mut naive_date = now.naive_local().date().and_time(*time);
+
+                // If the time already passed today, the vote closes tomorrow
+                if naive_date <= now.naive_local() {
+                    naive_date += chrono::Duration::days(1);
+                }
+
+                let local_date = chrono::Local.from_local_datetime(&naive_date).single()?;
+                Some(local_date.with_timezone(local_date.offset()))
+            }
+        }
+    }
+}
+
+/**
+ * The settings of a new vote, which can be given when creating the vote
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VoteSettings {
+    pub mode: VoteMode,
+    pub deadline: Option<VoteDeadline>,
+    pub secret: bool,
+}
+
+impl VoteSettings {
+    /**
+     * Parses a setting like mode:ranked, duration:2h, until:20:00 or ballot:secret into the settings
+     * Returns Ok(false) if the argument is no setting and Err if the value of the setting is invalid
+     */
+    pub fn parse_setting(&mut self, argument: &str) -> Result<bool, ()> {
+        if let Some(mode) = argument.strip_prefix("mode:") {
+            self.mode = VoteMode::from_str(mode)?;
+        } else if let Some(duration) = argument.strip_prefix("duration:") {
+            match crate::general_behaviour::parse_duration_minutes(duration) {
+                Some(minutes) if minutes > 0 => self.deadline = Some(VoteDeadline::Duration(minutes)),
+                _ => return Err(()),
+            }
+        } else if let Some(time) = argument.strip_prefix("until:") {
+            let time = chrono::NaiveTime::parse_from_str(time, "%H:%M").map_err(|_| ())?;
+            self.deadline = Some(VoteDeadline::Until(time));
+        } else if let Some(ballot) = argument.strip_prefix("ballot:") {
+            self.secret = match ballot.to_lowercase().as_str() {
+                "secret" => true,
+                "open" => false,
+                _ => return Err(()),
+            };
+        } else {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+}
+
+impl FromStr for VoteMode {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "single" => Self::Single,
+            "ranked" => Self::Ranked,
+            "approval" => Self::Approval,
+            pick => match pick.strip_prefix("pick:").map(|number| number.parse::<u32>()) {
+                Some(Ok(number)) if number > 0 => Self::PickUpTo(number),
+                _ => return Err(()),
+            },
+        })
+    }
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct VoteOption<T> {
     id: u32,
     emoji: String,
     cargo: T,
     votes: Vec<discord::model::UserId>,
+
+    #[serde(default)]
+    external: bool, // True if the movie was found on TMDb and is not on the watch list
 }
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct Vote {
+    #[serde(default)]
+    id: u32, // Short id to address the vote in commands, 0 if the vote was not sent yet
+
     creator: discord::model::User,
     creation_date: chrono::DateTime<chrono::FixedOffset>,
     title: String,
     options: Vec<VoteOptionEnum>,
     message_id: discord::model::MessageId,
+
+    #[serde(default)]
+    channel_id: u64,
+
+    #[serde(default)]
+    mode: VoteMode,
+
+    #[serde(default)]
+    rankings: HashMap<u64, Vec<String>>, // The emojis of the ranked options in order of preference, keys are the user ids
+
+    #[serde(default)]
+    deadline: Option<chrono::DateTime<chrono::FixedOffset>>, // The vote closes automatically at the deadline
+
+    #[serde(default)]
+    reminder_sent: bool,
+
+    #[serde(default)]
+    secret: bool, // The results of secret votes are only shown when the vote is closed
+
+    #[serde(default)]
+    armed_vetoes: Vec<u64>, // User ids of the members whose next reaction to an option is a veto
+}
+
+/**
+ * One round of an instant runoff with the first preferences of every remaining option
+ */
+struct RunoffRound {
+    counts: Vec<(String, usize)>, // Emojis of the remaining options and their first preferences
+    eliminated: Vec<String>,
+}
+
+/**
+ * The result of a vote with the emoji of the winning option. Ranked votes also contain the rounds of the instant runoff.
+ * If the winner was decided by a tie break, the rule that decided is stored as well. If the tie is broken by a runoff vote,
+ * there is no winner yet and the tied options are stored instead.
+ */
+struct VoteResult {
+    winner: Option<String>,
+    rounds: Vec<RunoffRound>,
+    tie_break: Option<String>,
+    runoff_options: Vec<String>,
 }
 
 /**
@@ -77,6 +280,7 @@ pub fn create_vote(
     title: String,
     options: Vec<String>,
     is_movie_vote: bool,
+    settings: VoteSettings,
 ) {
     let message = bot_data
         .message
@@ -89,7 +293,8 @@ pub fn create_vote(
         message.author.clone()
     };
 
-    if user_already_owns_a_vote(bot_data, creator.id) {
+    // Users can run several votes at once, but there is only one random movie vote
+    if is_movie_vote && user_already_owns_a_vote(bot_data, creator.id) {
         // The case, that a random_movie_vote already exists, is covered by the create_random_movie_vote function
 
         send_message::user_already_owns_a_vote_error(bot_data);
@@ -97,96 +302,42 @@ pub fn create_vote(
     } else {
         let mut vote_options: Vec<VoteOptionEnum> = Vec::with_capacity(options.len());
         if let Some(emojis) = get_random_unique_emojis(vote_options.capacity()) {
-            let emojis: Vec<String> = emojis.iter().cloned().collect();
-            let mut emoji_idx = 0;
             // Check all options for ids or movie titles
             // Construct the options simultaneously and add them to the temporary vector
-            for option in options {
-                use crate::movie_behaviour::Movie;
-
-                // If the option starts with prefix id:
-                if let Some(movie_id_string) = option.strip_prefix("id:") {
-                    // Try to parse the id_string into u32
-                    if let Ok(movie_id) = movie_id_string.parse::<u32>() {
-                        // Try to get the movie from the watch_list
-                        if let Some(watch_list_entry) = bot_data.watch_list.get(&movie_id) {
-                            // Finally push the vote option with the movie as cargo
-                            vote_options.push(VoteOptionEnum::MovieVoteOption(
-                                VoteOption::<Movie> {
-                                    id: movie_id,
-                                    emoji: emojis[emoji_idx].clone(),
-                                    cargo: watch_list_entry.movie.clone(),
-                                    votes: Vec::new(),
-                                },
-                            ));
-                        } else {
-                            send_message::movie_not_found_in_watchlist_error(
-                                bot_data,
-                                movie_id_string.to_string(),
-                            );
-                            return;
-                        }
-                    } else {
-                        send_message::wrong_vote_parameter_error(bot_data, option);
-                        return;
-                    }
-                }
-                // If the option starts with prefix t:
-                else if let Some(movie_title) = option.strip_prefix("t:") {
-                    // Try to get the movie id based on the movie title from the watch_list
-                    if let Some(movie_id) =
-                        get_movie_id_in_watch_list(movie_title, &bot_data.watch_list)
-                    {
-                        // Try to get the movie from the watch_list
-                        if let Some(watch_list_entry) = bot_data.watch_list.get(&movie_id) {
-                            // Finally push the vote option with the movie as cargo
-                            vote_options.push(VoteOptionEnum::MovieVoteOption(
-                                VoteOption::<Movie> {
-                                    id: movie_id,
-                                    emoji: emojis[emoji_idx].clone(),
-                                    cargo: watch_list_entry.movie.clone(),
-                                    votes: Vec::new(),
-                                },
-                            ));
-                        } else {
-                            send_message::movie_not_found_in_watchlist_error(
-                                bot_data,
-                                movie_title.to_string(),
-                            );
-                            return;
-                        }
-                    } else {
-                        send_message::movie_not_found_in_watchlist_error(
-                            bot_data,
-                            movie_title.to_string(),
-                        );
-                        return;
-                    }
-                }
-                // If the option is a normal vote option
-                else {
-                    vote_options.push(VoteOptionEnum::GeneralVoteOption(VoteOption::<String> {
-                        id: 0, // ID only used as of now for votes of the movie kind - in future this could be a UUID
-                        emoji: emojis[emoji_idx].clone(),
-                        cargo: option,
-                        votes: Vec::new(),
-                    }));
+            for (option, emoji) in options.into_iter().zip(emojis) {
+                match parse_vote_option(bot_data, option, emoji) {
+                    Some(vote_option) => vote_options.push(vote_option),
+                    None => return,
                 }
-
-                emoji_idx += 1;
             }
         } else {
             send_message::not_enough_emojis_error(bot_data);
             return;
         }
 
+        let deadline = match settings.deadline {
+            Some(deadline) => match deadline.get_date() {
+                Some(date) => Some(date),
+                None => return send_message::invalid_vote_deadline_error(bot_data),
+            },
+            None => None,
+        };
+
         // Create the new instance of the vote struct
         let mut new_vote = Vote {
+            id: 0, // The id is assigned when the vote is sent
             creator: creator.clone(),
             creation_date: message.timestamp,
             title: title,
             options: vote_options,
             message_id: discord::model::MessageId(0),
+            channel_id: 0,
+            mode: settings.mode,
+            rankings: HashMap::new(),
+            deadline,
+            reminder_sent: false,
+            secret: settings.secret,
+            armed_vetoes: Vec::new(),
         };
 
         // Send the vote details message and assign it to the bot_data
@@ -203,6 +354,85 @@ pub fn create_vote(
     }
 }
 
+/**
+ * Creates the vote option with the given emoji. If the option starts with id: or t: it is the movie
+ * with this id or title in the watch list, if it starts with tmdb: or imdb: it is the movie with this id on TMDb
+ * or IMDb, otherwise it is a general option.
+ * Sends an error message and returns None if the movie could not be found.
+ */
+fn parse_vote_option(bot_data: &crate::BotData, option: String, emoji: String) -> Option<VoteOptionEnum> {
+    use crate::movie_behaviour::{fetch_movie_by_external_id, find_id_by_tmdb_id, Movie};
+
+    let external_id = option.strip_prefix("tmdb:").map(|id| (id, false))
+        .or_else(|| option.strip_prefix("imdb:").map(|id| (id, true)));
+
+    let movie_id = if let Some((external_id, imdb)) = external_id {
+        let movie = match fetch_movie_by_external_id(bot_data, external_id.trim(), imdb) {
+            Ok(movie) => movie,
+            Err(error) => {
+                send_message::external_movie_not_found_error(bot_data, error);
+                return None;
+            }
+        };
+
+        // Movies that are already on the watch list become normal movie options
+        match find_id_by_tmdb_id(movie.tmdb_id, &bot_data.watch_list) {
+            Some(&movie_id) => movie_id,
+            None => {
+                return Some(VoteOptionEnum::MovieVoteOption(VoteOption::<Movie> {
+                    id: 0,
+                    emoji,
+                    cargo: movie,
+                    votes: Vec::new(),
+                    external: true,
+                }));
+            }
+        }
+    } else if let Some(movie_id_string) = option.strip_prefix("id:") {
+        // Try to parse the id_string into u32
+        match movie_id_string.parse::<u32>() {
+            Ok(movie_id) => movie_id,
+            Err(_) => {
+                send_message::wrong_vote_parameter_error(bot_data, option);
+                return None;
+            }
+        }
+    } else if let Some(movie_title) = option.strip_prefix("t:") {
+        // Try to get the movie id based on the movie title from the watch_list
+        match get_movie_id_in_watch_list(movie_title, &bot_data.watch_list) {
+            Some(movie_id) => movie_id,
+            None => {
+                send_message::movie_not_found_in_watchlist_error(bot_data, movie_title.to_string());
+                return None;
+            }
+        }
+    } else {
+        // If the option is a normal vote option
+        return Some(VoteOptionEnum::GeneralVoteOption(VoteOption::<String> {
+            id: 0, // ID only used as of now for votes of the movie kind - in future this could be a UUID
+            emoji,
+            cargo: option,
+            votes: Vec::new(),
+            external: false,
+        }));
+    };
+
+    // Try to get the movie from the watch_list
+    match bot_data.watch_list.get(&movie_id) {
+        Some(watch_list_entry) => Some(VoteOptionEnum::MovieVoteOption(VoteOption::<Movie> {
+            id: movie_id,
+            emoji,
+            cargo: watch_list_entry.movie.clone(),
+            votes: Vec::new(),
+            external: false,
+        })),
+        None => {
+            send_message::movie_not_found_in_watchlist_error(bot_data, movie_id.to_string());
+            None
+        }
+    }
+}
+
 /**
  * Returns true if the user owns a vote. False otherwise
  */
@@ -229,7 +459,15 @@ pub fn send_vote_details_message(
     bot_data: &mut crate::BotData,
     vote: &mut Vote,
 ) -> Option<discord::model::Message> {
-    let embed_description: String = build_vote_embed_description(vote);
+    if vote.id == 0 {
+        vote.id = get_next_vote_id(bot_data);
+    }
+
+    let embed_description: String = format!(
+        "{}{}",
+        format_vote_countdown(vote),
+        build_vote_embed_description(vote, !vote.secret)
+    );
 
     let vote_message = bot_data.bot.send_embed(
         bot_data
@@ -254,7 +492,9 @@ pub fn send_vote_details_message(
                 .footer(|footer| {
                     footer.text(
                         format!(
-                            "Um abzustimmen reagiere bitte auf diese Nachricht • {}",
+                            "Abstimmungs-ID: {} • {} • {}",
+                            vote.id,
+                            get_vote_instructions(vote),
                             crate::general_behaviour::timestamp_to_string(
                                 &vote.creation_date,
                                 false
@@ -295,6 +535,15 @@ pub fn send_vote_details_message(
             }
         }
 
+        // Movies can be vetoed with a dedicated reaction
+        if allows_vetoes(bot_data, vote) {
+            let _ = bot_data.bot.add_reaction(
+                vote_message.channel_id,
+                vote_message.id,
+                discord::model::ReactionEmoji::Unicode(crate::veto_behaviour::VETO_EMOJI.to_string()),
+            );
+        }
+
         // Vote already exists in the bot_data, so remove the previous entry from the bot_data
         if vote.message_id != discord::model::MessageId(0) {
             bot_data.votes.remove(&vote.message_id.0);
@@ -302,6 +551,7 @@ pub fn send_vote_details_message(
 
         // Independent of the previous state, set the message_id and insert the (new) vote into bot_data
         vote.message_id = vote_message.id;
+        vote.channel_id = vote_message.channel_id.0;
         bot_data.votes.insert(vote_message.id.0, vote.clone());
 
         return Some(vote_message);
@@ -310,49 +560,136 @@ pub fn send_vote_details_message(
     }
 }
 
+/**
+ * Returns the instructions on how to vote, which are shown in the footer of the vote message
+ */
+fn get_vote_instructions(vote: &Vote) -> String {
+    let instructions = match vote.mode {
+        VoteMode::Single => String::from("Um abzustimmen reagiere bitte auf diese Nachricht"),
+        VoteMode::Ranked => String::from(
+            "Um abzustimmen reagiere bitte in der Reihenfolge deiner Präferenz oder schicke mir den Befehl rank per Direktnachricht",
+        ),
+        VoteMode::Approval => String::from(
+            "Um abzustimmen reagiere bitte auf alle Optionen, die du gut findest. Erneutes Reagieren entfernt deine Stimme",
+        ),
+        VoteMode::PickUpTo(limit) => format!(
+            "Um abzustimmen reagiere bitte auf bis zu {} {}. Erneutes Reagieren entfernt deine Stimme",
+            limit,
+            if limit == 1 { "Option" } else { "Optionen" }
+        ),
+    };
+
+    if vote.secret {
+        format!(
+            "{} • Geheime Abstimmung: Die Ergebnisse werden erst beim Schließen gezeigt, du kannst auch mit dem Befehl ballot per Direktnachricht abstimmen",
+            instructions
+        )
+    } else {
+        instructions
+    }
+}
+
+/**
+ * Formats the time until the vote closes for the vote message, or nothing if the vote has no deadline
+ */
+fn format_vote_countdown(vote: &Vote) -> String {
+    match vote.deadline {
+        Some(deadline) => {
+            let minutes_left = (deadline - current_timestamp()).num_minutes().max(0) as u32;
+
+            format!(
+                "⏳ Endet in **{}** ({})",
+                format_duration_minutes(minutes_left),
+                crate::event_behaviour::format_event_date(&deadline)
+            )
+        }
+        None => String::new(),
+    }
+}
+
 /**
  * Constructs the vote description for the embedded message, consisting of general options and/or movie options
+ * For ranked votes the shown number is the number of first preferences. Votes where members can choose more than one
+ * option also show the share of the participants who chose the option.
+ * Without the results, only the number of members who voted is shown.
  */
-fn build_vote_embed_description(vote: &Vote) -> String {
+fn build_vote_embed_description(vote: &Vote, show_results: bool) -> String {
     let mut description = String::new();
 
+    let participant_count = get_vote_participants(vote).len();
+    let format_count = |vote_count: usize| {
+        if show_results {
+            format!("`{}` ", vote_count)
+        } else {
+            String::new()
+        }
+    };
+    let format_share = |vote_count: usize| match vote.mode {
+        _ if !show_results => String::new(),
+        VoteMode::Approval | VoteMode::PickUpTo(_) if participant_count > 0 => {
+            format!(" ({:.0}%)", vote_count as f64 / participant_count as f64 * 100.0)
+        }
+        VoteMode::Approval | VoteMode::PickUpTo(_) => String::from(" (0%)"),
+        VoteMode::Single | VoteMode::Ranked => String::new(),
+    };
+
     for option in vote.options.iter() {
         match option {
             VoteOptionEnum::GeneralVoteOption(string_option) => {
                 description.push_str(
                     format!(
-                        "\n\n`{}` {} - {}",
-                        string_option.votes.len(),
+                        "\n\n{}{} - {}{}",
+                        format_count(string_option.votes.len()),
                         string_option.emoji,
-                        string_option.cargo
+                        string_option.cargo,
+                        format_share(string_option.votes.len())
                     )
                     .as_str(),
                 );
             }
+            VoteOptionEnum::MovieVoteOption(movie_option) if movie_option.external => description.push_str(
+                format!(
+                    "\n\n{}{} - `(TMDb)` [{}]({}) • {} min • [Poster]({}){}",
+                    format_count(movie_option.votes.len()),
+                    movie_option.emoji,
+                    movie_option.cargo.movie_title,
+                    crate::movie_behaviour::get_movie_link(movie_option.cargo.tmdb_id, false),
+                    movie_option.cargo.runtime,
+                    crate::movie_behaviour::generate_poster_link(&movie_option.cargo.poster_path),
+                    format_share(movie_option.votes.len())
+                )
+                .as_str(),
+            ),
             VoteOptionEnum::MovieVoteOption(movie_option) => description.push_str(
                 format!(
-                    "\n\n`{}` {} - `(ID {})` [{}]({})",
-                    movie_option.votes.len(),
+                    "\n\n{}{} - `(ID {})` [{}]({}){}",
+                    format_count(movie_option.votes.len()),
                     movie_option.emoji,
                     movie_option.id,
                     movie_option.cargo.movie_title,
                     crate::movie_behaviour::get_movie_link(movie_option.cargo.tmdb_id, false),
+                    format_share(movie_option.votes.len())
                 )
                 .as_str(),
             ),
         }
     }
 
+    if !show_results {
+        description.push_str(format!("\n\n🗳️ Abgegebene Stimmen: `{}`", participant_count).as_str());
+    }
+
     description
 }
 
 /**
- * Searches the vote of a user in the votes vector. If a vote was found sends the vote message again.
+ * Searches the vote with the id, or the vote of a user if no id is given. If a vote was found sends the vote message again.
  * If the user has no vote, sends a message.
  */
 pub fn determine_vote_and_send_details_message(
     bot_data: &mut crate::BotData,
     other_user_id: Option<u64>,
+    vote_id: Option<u32>,
 ) {
     let message = bot_data
         .message
@@ -366,38 +703,131 @@ pub fn determine_vote_and_send_details_message(
         message.author.id
     };
 
-    for (_, vote) in bot_data.votes.clone().iter_mut() {
-        // If a vote was created by the same user, who sent the message, we found the vote
-        if vote.creator.id == comparing_user_id {
-            let previous_message_id = vote.message_id;
+    let vote_message_id = match determine_vote(bot_data, vote_id, comparing_user_id, other_user_id.is_some()) {
+        Some(vote_message_id) => vote_message_id,
+        None => return,
+    };
+    // This can not panic, since the vote was found by 'determine_vote'
+    let mut vote = bot_data.votes.get(&vote_message_id).unwrap().clone();
+    let previous_message_id = vote.message_id;
+
+    // First remove all reactions on previous vote
+    remove_all_reactions_on_previous_vote(
+        bot_data,
+        &vote,
+        (&get_vote_channel_id(&vote, message.channel_id), &previous_message_id),
+    );
 
-            // First remove all reactions on previous vote
-            remove_all_reactions_on_previous_vote(
-                bot_data,
-                vote,
-                (&message.channel_id, &previous_message_id),
-            );
+    // Send the vote details message and assign it to the bot_data
+    // If the sending was successful, add the vote to the waiting_for_reaction list
+    if let Some(message_id) = send_vote_details_message(bot_data, &mut vote) {
+        bot_data.wait_for_reaction.push(
+            crate::general_behaviour::WaitingForReaction::Vote(message_id),
+        );
 
-            // Send the vote details message and assign it to the bot_data
-            // If the sending was successful, add the vote to the waiting_for_reaction list
-            if let Some(message_id) = send_vote_details_message(bot_data, vote) {
-                bot_data.wait_for_reaction.push(
-                    crate::general_behaviour::WaitingForReaction::Vote(message_id),
-                );
+        remove_previous_vote_from_wait_for_reaction(bot_data, &previous_message_id);
+    } else {
+        send_message::vote_message_failed_to_send_error(bot_data);
+    }
+}
+
+/**
+ * Returns the message id of the vote with the given id. Without an id returns the vote of the user,
+ * if the user owns exactly one vote. Otherwise sends an error message and returns None.
+ */
+fn determine_vote(
+    bot_data: &crate::BotData,
+    vote_id: Option<u32>,
+    user_id: discord::model::UserId,
+    other_user: bool,
+) -> Option<u64> {
+    if let Some(vote_id) = vote_id {
+        let vote_message_id = bot_data
+            .votes
+            .values()
+            .find(|vote| vote.id == vote_id)
+            .map(|vote| vote.message_id.0);
+
+        if vote_message_id.is_none() {
+            send_message::vote_id_not_found_error(bot_data, vote_id);
+        }
+        return vote_message_id;
+    }
 
-                remove_previous_vote_from_wait_for_reaction(bot_data, &previous_message_id);
+    let mut user_votes: Vec<&Vote> = bot_data
+        .votes
+        .values()
+        .filter(|vote| vote.creator.id == user_id)
+        .collect();
+    user_votes.sort_by_key(|vote| vote.id);
+
+    match user_votes.as_slice() {
+        [] => {
+            if other_user {
+                send_message::other_user_has_no_vote_error(bot_data);
             } else {
-                send_message::vote_message_failed_to_send_error(bot_data);
+                send_message::user_has_no_vote_error(bot_data);
             }
-            return;
+            None
+        }
+        [vote] => Some(vote.message_id.0),
+        _ => {
+            let vote_lines: Vec<String> = user_votes
+                .iter()
+                .map(|vote| format!("`{}` **{}**", vote.id, vote.title))
+                .collect();
+            send_message::multiple_votes_error(bot_data, &vote_lines);
+            None
         }
     }
+}
 
-    // If the user has not vote, send a message
-    if other_user_id.is_none() {
-        send_message::user_has_no_vote_error(bot_data);
+/**
+ * Returns the channel the vote message was sent to. Votes stored before the channel was remembered fall back to the given channel.
+ */
+fn get_vote_channel_id(vote: &Vote, fallback: discord::model::ChannelId) -> discord::model::ChannelId {
+    if vote.channel_id == 0 {
+        fallback
     } else {
-        send_message::other_user_has_no_vote_error(bot_data);
+        discord::model::ChannelId(vote.channel_id)
+    }
+}
+
+/**
+ * Returns the next free vote id. Ids start at 1, so 0 marks votes without an id.
+ */
+fn get_next_vote_id(bot_data: &mut crate::BotData) -> u32 {
+    bot_data.next_vote_id += 1;
+    bot_data.next_vote_id
+}
+
+/**
+ * Assigns an id to all open votes that were created before votes had ids.
+ * The next id is raised above all ids in use first, so new votes never overwrite archived votes.
+ */
+pub fn assign_missing_vote_ids(bot_data: &mut crate::BotData) {
+    let highest_used_id = bot_data
+        .vote_archive
+        .keys()
+        .copied()
+        .chain(bot_data.votes.values().map(|vote| vote.id))
+        .max()
+        .unwrap_or(0);
+    bot_data.next_vote_id = bot_data.next_vote_id.max(highest_used_id);
+
+    let mut message_ids: Vec<u64> = bot_data
+        .votes
+        .iter()
+        .filter(|(_, vote)| vote.id == 0)
+        .map(|(message_id, _)| *message_id)
+        .collect();
+    message_ids.sort_unstable();
+
+    for message_id in message_ids {
+        let vote_id = get_next_vote_id(bot_data);
+        if let Some(vote) = bot_data.votes.get_mut(&message_id) {
+            vote.id = vote_id;
+        }
     }
 }
 
@@ -423,6 +853,13 @@ pub fn remove_all_reactions_on_previous_vote(
             discord::model::ReactionEmoji::Unicode(emoji_string),
         );
     }
+
+    let _ = bot_data.bot.delete_reaction(
+        *channel_and_message_id.0,
+        *channel_and_message_id.1,
+        None,
+        discord::model::ReactionEmoji::Unicode(crate::veto_behaviour::VETO_EMOJI.to_string()),
+    );
 }
 
 /**
@@ -453,23 +890,77 @@ pub fn update_vote(
     reaction: &discord::model::Reaction,
     message_id: &u64,
 ) {
+    use crate::general_behaviour::reaction_emoji_equals;
+
+    if reaction_emoji_equals(&reaction.emoji, crate::veto_behaviour::VETO_EMOJI.to_string()) {
+        return arm_veto(bot_data, reaction, *message_id);
+    }
+
+    // The next reaction to an option after the veto reaction vetoes the option
+    let armed = match bot_data.votes.get(message_id) {
+        Some(vote) => vote.armed_vetoes.contains(&reaction.user_id.0) && is_emoji_part_of_vote(vote, reaction),
+        None => false,
+    };
+    if armed {
+        let _ = bot_data.bot.delete_reaction(
+            reaction.channel_id,
+            reaction.message_id,
+            Some(reaction.user_id),
+            reaction.emoji.clone(),
+        );
+
+        if let Some(vote) = bot_data.votes.get_mut(message_id) {
+            vote.armed_vetoes.retain(|user_id| *user_id != reaction.user_id.0);
+        }
+        if let discord::model::ReactionEmoji::Unicode(emoji) = &reaction.emoji {
+            if let Err(error) = veto_vote_option(bot_data, *message_id, reaction.user_id.0, emoji.clone()) {
+                if let Ok(private_channel) = bot_data.bot.create_private_channel(reaction.user_id) {
+                    send_message::veto_failed_error(bot_data, private_channel.id, error);
+                }
+            }
+        }
+        return;
+    }
+
     // Find the vote in the votes from bot_data
     if let Some(vote) = bot_data.votes.get_mut(message_id) {
         if is_emoji_part_of_vote(vote, reaction) {
-            update_user_choice(&bot_data.bot, vote, reaction);
-            update_vote_embed(
-                &bot_data.bot,
-                &reaction.channel_id,
-                vote,
-                &reaction.message_id,
-            );
-
+            // Remove the reaction right away, so it does not reveal the choice longer than necessary
             let _ = bot_data.bot.delete_reaction(
                 reaction.channel_id,
                 reaction.message_id,
                 Some(reaction.user_id),
                 reaction.emoji.clone(),
             );
+
+            match vote.mode {
+                VoteMode::Single => update_user_choice(&bot_data.bot, vote, reaction),
+                VoteMode::Ranked => update_user_ranking(&bot_data.bot, vote, reaction),
+                VoteMode::Approval => update_user_selection(&bot_data.bot, vote, reaction, None),
+                VoteMode::PickUpTo(limit) => {
+                    update_user_selection(&bot_data.bot, vote, reaction, Some(limit))
+                }
+            }
+
+            // Without visible results the user gets the choices as private message, ranked votes already send the ranking
+            if vote.secret && vote.mode != VoteMode::Ranked {
+                if let Ok(private_channel) = bot_data.bot.create_private_channel(reaction.user_id) {
+                    let choices = format_user_choices(vote, reaction.user_id);
+                    let _ = bot_data.bot.send_embed(private_channel.id, "", |embed| {
+                        embed
+                            .title("Deine Stimme")
+                            .description(format!("**{}**\n{}", vote.title, choices).as_str())
+                            .color(crate::COLOR_INFORMATION)
+                    });
+                }
+            }
+
+            update_vote_embed(
+                &bot_data.bot,
+                &reaction.channel_id,
+                vote,
+                &reaction.message_id,
+            );
         } else {
             send_message::emoji_not_part_of_vote_info(bot_data);
         }
@@ -558,486 +1049,1885 @@ fn update_user_choice(
 }
 
 /**
- * Returns the index of the user if the user is in the list of votes, None if not.
+ * Adds the option of the reaction to the choices of the user, or removes it if the user already chose it
+ * If a limit is given and the user already chose as many options, the choice is rejected with a private message
  */
-fn user_id_in_votes(
-    user_id: &discord::model::UserId,
-    vote_user_list: &Vec<discord::model::UserId>,
-) -> Option<usize> {
-    for i in 0..vote_user_list.len() {
-        if *user_id == vote_user_list[i] {
-            return Some(i);
+fn update_user_selection(
+    bot: &discord::Discord,
+    vote: &mut Vote,
+    reaction: &discord::model::Reaction,
+    limit: Option<u32>,
+) {
+    let reaction_emoji = match &reaction.emoji {
+        discord::model::ReactionEmoji::Unicode(reaction_emoji) => reaction_emoji,
+        _ => return,
+    };
+
+    let choice_count = vote
+        .options
+        .iter()
+        .filter(|option| option.get_votes().contains(&reaction.user_id))
+        .count();
+
+    if let Some(option) = vote
+        .options
+        .iter_mut()
+        .find(|option| option.get_emoji() == reaction_emoji)
+    {
+        let option_user_list = option.get_votes_mut();
+
+        if let Some(idx) = user_id_in_votes(&reaction.user_id, option_user_list) {
+            option_user_list.remove(idx);
+        } else if limit.map(|limit| choice_count < limit as usize).unwrap_or(true) {
+            option_user_list.push(reaction.user_id);
+        } else if let Ok(private_channel) = bot.create_private_channel(reaction.user_id) {
+            // The user already chose as many options as allowed, so send him a private message
+            let _ = bot.send_embed(private_channel.id, "", |embed| {
+                embed
+                    .title("Zu viele Optionen gewählt.")
+                    .description(
+                        format!(
+                            "Du kannst höchstens `{}` Optionen wählen. Reagiere erneut auf eine gewählte Option, um deine Stimme zu entfernen.",
+                            limit.unwrap_or_default()
+                        )
+                        .as_str(),
+                    )
+                    .color(crate::COLOR_INFORMATION)
+            });
         }
     }
-
-    None
 }
 
 /**
- * Edits the embed message of a previously send vote message
+ * Appends the option of the reaction to the ranking of the user and sends the current ranking to the user
+ * Reacting to an option that is already ranked starts the ranking over with this option
  */
-fn update_vote_embed(
+fn update_user_ranking(
     bot: &discord::Discord,
-    channel_id: &discord::model::ChannelId,
-    vote: &Vote,
-    message_id: &discord::model::MessageId,
+    vote: &mut Vote,
+    reaction: &discord::model::Reaction,
 ) {
-    let embed_description: String = build_vote_embed_description(vote);
+    let reaction_emoji = match &reaction.emoji {
+        discord::model::ReactionEmoji::Unicode(reaction_emoji) => reaction_emoji.clone(),
+        _ => return,
+    };
 
-    let _ = bot.edit_embed(*channel_id, *message_id, |embed| {
-        embed
-            .title(format!("{}", vote.title).as_str())
-            .description(embed_description.as_str())
-            .author(|author_builder| {
-                if let Some(avatar_url) = vote.creator.avatar_url() {
-                    author_builder
-                        .name(vote.creator.name.as_str())
-                        .icon_url(avatar_url.as_str())
-                } else {
-                    author_builder.name(vote.creator.name.as_str())
-                }
-            })
-            .footer(|footer| {
-                footer.text(
+    let mut ranking = vote
+        .rankings
+        .get(&reaction.user_id.0)
+        .cloned()
+        .unwrap_or_default();
+
+    if ranking.contains(&reaction_emoji) {
+        ranking.clear();
+    }
+    ranking.push(reaction_emoji);
+
+    set_user_ranking(vote, reaction.user_id, ranking);
+
+    if let Ok(private_channel) = bot.create_private_channel(reaction.user_id) {
+        let ranking_string = format_user_ranking(vote, reaction.user_id);
+        let _ = bot.send_embed(private_channel.id, "", |embed| {
+            embed
+                .title("Deine Rangfolge")
+                .description(
                     format!(
-                        "Um abzustimmen reagiere bitte auf diese Nachricht • {}",
-                        crate::general_behaviour::timestamp_to_string(&vote.creation_date, false)
+                        "**{}**\n{}\n\nReagiere auf eine bereits gewählte Option, um deine Rangfolge neu zu beginnen.",
+                        vote.title, ranking_string
                     )
                     .as_str(),
                 )
-            })
-            .color(crate::COLOR_BOT)
-    });
+                .color(crate::COLOR_INFORMATION)
+        });
+    }
 }
 
 /**
- * Removes the vote from the bot_data and manages all other dependencies
- * Sends a message summarizing the result of the vote
+ * Stores the ranking of the user. The user is counted as vote for the first option of the ranking,
+ * so the vote message shows the first preferences.
  */
-pub fn close_vote(bot_data: &mut crate::BotData) {
-    let message = bot_data
-        .message
-        .clone()
-        .expect("Passing message to determine_vote_and_send_details_message failed.");
-
-    for (_, vote) in bot_data.votes.clone().iter_mut() {
-        // If a vote was created by the same user, who sent the message, we found the vote
-        if vote.creator.id == message.author.id {
-            let previous_message_id = vote.message_id;
-
-            // First remove all reactions on previous vote
-            remove_all_reactions_on_previous_vote(
-                bot_data,
-                vote,
-                (&message.channel_id, &previous_message_id),
-            );
-
-            // Send the vote summary message
-            if let Some(_) = send_vote_summary_message(bot_data, vote) {
-                remove_previous_vote_from_wait_for_reaction(bot_data, &previous_message_id);
-                let _ = bot_data.votes.remove(&previous_message_id.0);
-            } else {
-                send_message::vote_message_failed_to_send_error(bot_data);
-            }
-            return;
+fn set_user_ranking(vote: &mut Vote, user_id: discord::model::UserId, ranking: Vec<String>) {
+    for option in vote.options.iter_mut() {
+        let is_first_preference = ranking.first() == Some(option.get_emoji());
+        let votes = option.get_votes_mut();
+
+        votes.retain(|voter_id| *voter_id != user_id);
+        if is_first_preference {
+            votes.push(user_id);
         }
     }
 
-    // If the user has not vote, send a message
-    send_message::user_has_no_vote_error(bot_data);
+    vote.rankings.insert(user_id.0, ranking);
 }
 
 /**
- * Sends the vote summary
+ * Formats the ranking of the user as numbered list of the options
  */
-fn send_vote_summary_message(
-    bot_data: &crate::BotData,
-    vote: &Vote,
-) -> Option<discord::model::MessageId> {
-    let mut embed_description = String::from(format!("**{}**", vote.title));
-    embed_description.push_str(build_vote_embed_description(vote).as_str());
+fn format_user_ranking(vote: &Vote, user_id: discord::model::UserId) -> String {
+    let ranking = match vote.rankings.get(&user_id.0) {
+        Some(ranking) if !ranking.is_empty() => ranking,
+        _ => return String::from("Du hast noch keine Option gewählt."),
+    };
 
-    if let Ok(message) = bot_data.bot.send_embed(
-        bot_data
-            .message
-            .clone()
-            .expect("Passing message to send_vote_summary_message failed.")
-            .channel_id,
-        "",
-        |embed| {
-            embed
-                .title("Abstimmungsergebnisse")
-                .description(embed_description.as_str())
-                .author(|author_builder| {
-                    if let Some(avatar_url) = vote.creator.avatar_url() {
-                        author_builder
-                            .name(vote.creator.name.as_str())
-                            .icon_url(avatar_url.as_str())
-                    } else {
-                        author_builder.name(vote.creator.name.as_str())
-                    }
-                })
-                .color(crate::COLOR_SUCCESS)
-        },
-    ) {
-        Some(message.id)
-    } else {
-        None
-    }
+    ranking
+        .iter()
+        .filter_map(|emoji| vote.options.iter().find(|option| option.get_emoji() == emoji))
+        .enumerate()
+        .map(|(idx, option)| format!("{}. {}", idx + 1, option.get_label()))
+        .collect::<Vec<String>>()
+        .join("\n")
 }
 
 /**
- * Updates the movie limit per user and sends an info message
+ * Formats the options the user chose as list, e.g. for the confirmation of a secret vote
  */
-pub fn set_movie_vote_limit(bot_data: &mut crate::BotData, new_limit: u32) {
-    use crate::general_behaviour;
+fn format_user_choices(vote: &Vote, user_id: discord::model::UserId) -> String {
+    let choices: Vec<String> = vote
+        .options
+        .iter()
+        .filter(|option| option.get_votes().contains(&user_id))
+        .map(|option| format!("- {}", option.get_label()))
+        .collect();
 
-    let message = bot_data
-        .message
-        .clone()
-        .expect("Passing of message to set_movie_limit failed.");
+    if choices.is_empty() {
+        String::from("Du hast keine Option gewählt.")
+    } else {
+        choices.join("\n")
+    }
+}
 
-    if !general_behaviour::is_user_administrator(bot_data, message.author.id) {
-        return send_message::insufficient_permissions_error(bot_data);
+/**
+ * Finds the open vote that contains all given emojis as options, only among the vote with the id if one is given.
+ * If no or more than one vote is found, sends an error and returns None.
+ */
+fn find_vote_by_option_emojis(bot_data: &crate::BotData, vote_id: Option<u32>, emojis: &[String], ranked_only: bool) -> Option<u64> {
+    if let Some(vote_id) = vote_id {
+        if !bot_data.votes.values().any(|vote| vote.id == vote_id) {
+            send_message::vote_id_not_found_error(bot_data, vote_id);
+            return None;
+        }
     }
 
-    let old_limit = bot_data.movie_vote_limit;
-    bot_data.movie_vote_limit = new_limit;
+    let matching_votes: Vec<u64> = bot_data
+        .votes
+        .iter()
+        .filter(|(_, vote)| vote_id.is_none() || Some(vote.id) == vote_id)
+        .filter(|(_, vote)| !ranked_only || vote.mode == VoteMode::Ranked)
+        .filter(|(_, vote)| {
+            emojis
+                .iter()
+                .all(|emoji| vote.options.iter().any(|option| option.get_emoji() == emoji))
+        })
+        .map(|(message_id, _)| *message_id)
+        .collect();
 
-    let _ = bot_data.bot.send_embed(message.channel_id, "", |embed| {
-        embed
-            .title("Filmlimit für Abstimmungen aktualisiert")
-            .description(
-                format!(
-                    "Das Filmlimit für Filmabstimmungen wurde von `{}` auf `{}` geändert.",
-                    old_limit, new_limit
-                )
-                .as_str(),
-            )
-            .color(crate::COLOR_INFORMATION)
-    });
+    match matching_votes.as_slice() {
+        [] => {
+            send_message::no_vote_with_options_found_error(bot_data, ranked_only);
+            None
+        }
+        [message_id] => Some(*message_id),
+        _ => {
+            send_message::vote_with_options_ambiguous_error(bot_data);
+            None
+        }
+    }
 }
 
 /**
- * Sends a message showing the current movie vote limit
+ * Updates the vote message after a vote was cast with a command instead of a reaction
  */
-pub fn show_movie_vote_limit(bot_data: &crate::BotData) {
-    let _ = bot_data.bot.send_embed(
-        bot_data
-            .message
-            .clone()
-            .expect("Passing of message to show_movie_vote_limit function failed.")
-            .channel_id,
-        "",
-        |embed| {
-            embed
-                .title("Filmlimit für Abstimmungen")
-                .description(
-                    format!(
-                        "Das aktuelle Filmlimit für Abstimmungen beträgt `{}` {}.",
-                        bot_data.movie_vote_limit,
-                        if bot_data.movie_vote_limit == 1 {
-                            "Film"
-                        } else {
-                            "Filme"
-                        }
-                    )
-                    .as_str(),
-                )
-                .color(crate::COLOR_INFORMATION)
-        },
-    );
+fn update_vote_embed_of_vote(bot_data: &crate::BotData, message_id: u64) {
+    if let Some(vote) = bot_data.votes.get(&message_id) {
+        if vote.channel_id != 0 {
+            update_vote_embed(
+                &bot_data.bot,
+                &discord::model::ChannelId(vote.channel_id),
+                vote,
+                &vote.message_id,
+            );
+        }
+    }
 }
 
 /**
- * Creates a new random movie vote with the given optional limit
- * The movies which are longest on the list have a greater chance of getting selected
+ * Sets the ranking of the author for the ranked vote that contains all given emojis
+ * This way members can rank the options in a direct message to the bot
  */
-pub fn create_random_movie_vote(bot_data: &mut crate::BotData, optional_limit: Option<u32>) {
-    if user_already_owns_a_vote(bot_data, bot_data.bot_user.id) {
-        if optional_limit.is_some() {
-            send_message::there_is_already_a_random_movie_vote_information(bot_data);
-        }
-
-        send_random_movie_vote_again(bot_data);
-        return;
-    }
+pub fn rank_vote_options(bot_data: &mut crate::BotData, vote_id: Option<u32>, emojis: Vec<String>) {
+    let author_id = bot_data
+        .message
+        .as_ref()
+        .expect("Passing message to rank_vote_options failed.")
+        .author
+        .id;
 
-    use crate::movie_behaviour;
-    use rand::{seq::IteratorRandom, thread_rng, Rng};
+    let message_id = match find_vote_by_option_emojis(bot_data, vote_id, &emojis, true) {
+        Some(message_id) => message_id,
+        None => return,
+    };
 
-    // Get the three (or less) movies that have earliest creation date (lowest id)
-    let mut earliest_movie_ids_vec: Vec<&u32> =
-        movie_behaviour::get_three_earliest_movie_ids(bot_data);
-    earliest_movie_ids_vec.reverse();
+    if let Some(vote) = bot_data.votes.get_mut(&message_id) {
+        set_user_ranking(vote, author_id, emojis);
 
-    let limit = get_movie_limit_or_optional_limit_as_usize(bot_data, optional_limit);
-    if limit.is_none() {
-        return;
+        let vote = vote.clone();
+        update_vote_embed_of_vote(bot_data, message_id);
+        send_message::ranking_saved_successfully(bot_data, &vote.title, format_user_ranking(&vote, author_id));
     }
+}
 
-    let limit = limit.unwrap();
-
-    let mut rng = thread_rng();
+/**
+ * Casts the ballot of the author for the vote that contains all given emojis, replacing the previous choices
+ * This way members can vote secretly in a direct message to the bot. For ranked votes the order of the emojis is the ranking.
+ */
+pub fn cast_ballot(bot_data: &mut crate::BotData, vote_id: Option<u32>, emojis: Vec<String>) {
+    let author_id = bot_data
+        .message
+        .as_ref()
+        .expect("Passing message to cast_ballot failed.")
+        .author
+        .id;
 
-    let mut random_movies: Vec<&u32> = bot_data
-        .watch_list
-        .iter()
-        .filter(|(_, entry)| entry.status.is_watch_list_status())
-        .choose_multiple(&mut rng, limit)
-        .iter()
-        .map(|(id, _)| *id)
-        .collect();
+    let message_id = match find_vote_by_option_emojis(bot_data, vote_id, &emojis, false) {
+        Some(message_id) => message_id,
+        None => return,
+    };
 
-    let mut removed_earliest_ids: Vec<u32> = Vec::new();
+    if let Some(vote) = bot_data.votes.get_mut(&message_id) {
+        let max_choices = match vote.mode {
+            VoteMode::Single => Some(1),
+            VoteMode::PickUpTo(limit) => Some(limit as usize),
+            VoteMode::Ranked | VoteMode::Approval => None,
+        };
 
-    // Remove all duplicates
-    for i in 0..earliest_movie_ids_vec.len() {
-        let idx = i - removed_earliest_ids.len();
-        if random_movies.contains(&earliest_movie_ids_vec[idx]) {
-            removed_earliest_ids.push(*earliest_movie_ids_vec[idx]);
-            earliest_movie_ids_vec.remove(idx);
+        if let Some(max_choices) = max_choices {
+            if emojis.len() > max_choices {
+                return send_message::too_many_ballot_options_error(bot_data, max_choices);
+            }
         }
-    }
 
-    // Swap the random movies with earliest movies by chance. Skips the earliest movies that already are in the vote
-    for id in random_movies.iter_mut() {
-        if !removed_earliest_ids.contains(id) && rng.gen_bool(0.2) {
-            let earliest_movie = earliest_movie_ids_vec.pop();
+        if vote.mode == VoteMode::Ranked {
+            set_user_ranking(vote, author_id, emojis);
+        } else {
+            for option in vote.options.iter_mut() {
+                let is_chosen = emojis.contains(option.get_emoji());
+                let votes = option.get_votes_mut();
 
-            match earliest_movie {
-                None => break,
-                Some(movie_id) => *id = movie_id,
-            };
+                votes.retain(|voter_id| *voter_id != author_id);
+                if is_chosen {
+                    votes.push(author_id);
+                }
+            }
         }
-    }
 
-    let options_vec: Vec<String> = random_movies.iter().map(|x| format!("id:{}", x)).collect();
+        let vote = vote.clone();
+        let choices = if vote.mode == VoteMode::Ranked {
+            format_user_ranking(&vote, author_id)
+        } else {
+            format_user_choices(&vote, author_id)
+        };
 
-    create_vote(bot_data, String::from("Nächster Film"), options_vec, true);
+        update_vote_embed_of_vote(bot_data, message_id);
+        send_message::ballot_saved_successfully(bot_data, &vote.title, choices);
+    }
 }
 
 /**
- * Returns the optional limit if it is_some, or the set limit from bot data, each converted to usize
+ * Returns the message id of the vote the author can edit. Without an id this is the vote of the author,
+ * administrators can edit every vote by its id. Sends an error message and returns None if there is no such vote.
  */
-fn get_movie_limit_or_optional_limit_as_usize(
-    bot_data: &crate::BotData,
-    optional_limit: Option<u32>,
-) -> Option<usize> {
-    use std::convert::TryInto;
+fn find_editable_vote(bot_data: &crate::BotData, vote_id: Option<u32>) -> Option<u64> {
+    let author_id = bot_data
+        .message
+        .as_ref()
+        .expect("Passing message to find_editable_vote failed.")
+        .author
+        .id;
 
-    let limit = match optional_limit {
-        Some(limit) => limit,
-        None => bot_data.movie_vote_limit,
-    };
-    let limit = limit.try_into();
+    let vote_message_id = determine_vote(bot_data, vote_id, author_id, false)?;
 
-    if limit.is_err() {
-        send_message::unknown_error_occured(bot_data, 100);
+    // This can not panic, since the vote was found by 'determine_vote'
+    if bot_data.votes.get(&vote_message_id).unwrap().creator.id != author_id
+        && !crate::general_behaviour::is_user_administrator(bot_data, author_id)
+    {
+        send_message::insufficient_permissions_error(bot_data);
         return None;
-    } else {
-        return Some(limit.unwrap());
     }
+
+    Some(vote_message_id)
 }
 
 /**
- * Finds the random movie vote in the votes and sends the vote message again
- * If there is no random movie vote, sends an error message that there is no
- * random_movie_vote
+ * Adds an option to an open vote. The option gets an emoji that no other option of the vote uses,
+ * and the cast votes are kept.
  */
-fn send_random_movie_vote_again(bot_data: &mut crate::BotData) {
-    // Find the random movie vote in the votes
-    if let Some(message_id) = find_random_movie_vote(bot_data) {
-        // Extract the vote as mutable from the bot_data
-        // This can not panic, since the vote was found by 'find_random_movie_vote'
-        let mut the_vote = bot_data.votes.get_mut(&message_id.0).unwrap().clone();
+pub fn add_vote_option(bot_data: &mut crate::BotData, vote_id: Option<u32>, option: String) {
+    let message_id = match find_editable_vote(bot_data, vote_id) {
+        Some(message_id) => message_id,
+        None => return,
+    };
+    if let Some(label) = push_vote_option(bot_data, message_id, option) {
+        // This can not panic, since the vote was found by 'find_editable_vote'
+        let title = bot_data.votes.get(&message_id).unwrap().title.clone();
+        send_message::vote_edited_successfully(bot_data, &title, format!("Neue Option: {}", label));
+    }
+}
 
-        // Send the vote details message and assign it to the bot_data
-        // If the sending was successful, add the vote to the waiting_for_reaction list
-        if let Some(message_id) = send_vote_details_message(bot_data, &mut the_vote) {
-            bot_data
-                .wait_for_reaction
-                .push(crate::general_behaviour::WaitingForReaction::Vote(
-                    message_id,
-                ))
-        } else {
-            send_message::vote_message_failed_to_send_error(bot_data);
+/**
+ * Parses the option and adds it with an unused emoji to the vote. Returns the label of the new option,
+ * or None if an error message was sent instead.
+ */
+fn push_vote_option(bot_data: &mut crate::BotData, message_id: u64, option: String) -> Option<String> {
+    let vote = bot_data.votes.get(&message_id)?;
+
+    let emoji = match get_unused_vote_emoji(vote) {
+        Some(emoji) => emoji,
+        None => {
+            send_message::not_enough_emojis_error(bot_data);
+            return None;
         }
-    } else {
-        send_message::no_random_movie_vote_exists_error(bot_data);
-    }
+    };
+
+    let vote_option = parse_vote_option(bot_data, option, emoji)?;
+    Some(insert_vote_option(bot_data, message_id, vote_option))
 }
 
 /**
- * Finds the random movie vote in the bot data and returns its MessageId, or None if the vote couldn't be found
+ * Returns a random emoji that no option of the vote uses yet, or None if all emojis are used
  */
-fn find_random_movie_vote(bot_data: &mut crate::BotData) -> Option<discord::model::MessageId> {
-    let bot_user_id = bot_data.bot_user.id;
+fn get_unused_vote_emoji(vote: &Vote) -> Option<String> {
+    // One more emoji than options guarantees an emoji that is not used yet
+    get_random_unique_emojis(vote.options.len() + 1).and_then(|emojis| {
+        emojis
+            .into_iter()
+            .find(|emoji| !vote.options.iter().any(|option| option.get_emoji() == emoji))
+    })
+}
 
-    // Find the random movie vote in the votes
-    if let Some(random_movie_vote) = bot_data
-        .votes
-        .iter_mut()
-        .find(|(_, vote)| vote.creator.id == bot_user_id)
-    {
-        return Some(discord::model::MessageId(*random_movie_vote.0));
-    } else {
-        return None;
+/**
+ * Adds the option to the vote, reacts with its emoji on the vote message and returns the label of the option
+ */
+fn insert_vote_option(bot_data: &mut crate::BotData, message_id: u64, vote_option: VoteOptionEnum) -> String {
+    let label = vote_option.get_label();
+    let emoji = vote_option.get_emoji().clone();
+
+    if let Some(vote) = bot_data.votes.get_mut(&message_id) {
+        vote.options.push(vote_option);
+
+        let channel_id = discord::model::ChannelId(vote.channel_id);
+        let vote_message_id = vote.message_id;
+        let _ = bot_data.bot.add_reaction(
+            channel_id,
+            vote_message_id,
+            discord::model::ReactionEmoji::Unicode(emoji),
+        );
     }
+    update_vote_embed_of_vote(bot_data, message_id);
+
+    label
 }
 
-pub fn close_random_movie_vote(bot_data: &mut crate::BotData) {
-    // Find the random movie vote in the votes
+/**
+ * Removes the option with the emoji from an open vote. The votes for all other options are kept,
+ * in ranked votes the next preference of the users who ranked the option first counts instead.
+ */
+pub fn remove_vote_option(bot_data: &mut crate::BotData, vote_id: Option<u32>, emoji: String) {
+    let message_id = match find_editable_vote(bot_data, vote_id) {
+        Some(message_id) => message_id,
+        None => return,
+    };
+    // This can not panic, since the vote was found by 'find_editable_vote'
+    let vote = bot_data.votes.get_mut(&message_id).unwrap();
+
+    let label = match vote.options.iter().find(|option| *option.get_emoji() == emoji) {
+        Some(option) => option.get_label(),
+        None => return send_message::vote_option_not_found_error(bot_data, &emoji),
+    };
+
+    if vote.options.len() == 1 {
+        return send_message::last_vote_option_error(bot_data);
+    }
+
+    let title = vote.title.clone();
+    delete_vote_option(bot_data, message_id, emoji);
+    send_message::vote_edited_successfully(bot_data, &title, format!("Entfernte Option: {}", label));
+}
+
+/**
+ * Removes the option with the emoji from the vote, its reaction and its place in the rankings
+ */
+fn delete_vote_option(bot_data: &mut crate::BotData, message_id: u64, emoji: String) {
+    let vote = match bot_data.votes.get_mut(&message_id) {
+        Some(vote) => vote,
+        None => return,
+    };
+
+    vote.options.retain(|option| *option.get_emoji() != emoji);
+    for (user_id, mut ranking) in vote.rankings.clone() {
+        ranking.retain(|ranked_emoji| *ranked_emoji != emoji);
+        set_user_ranking(vote, discord::model::UserId(user_id), ranking);
+    }
+
+    let vote = vote.clone();
+    let _ = bot_data.bot.delete_reaction(
+        discord::model::ChannelId(vote.channel_id),
+        vote.message_id,
+        None,
+        discord::model::ReactionEmoji::Unicode(emoji),
+    );
+    update_vote_embed_of_vote(bot_data, message_id);
+}
+
+/**
+ * Returns true if members can veto options of the vote, which requires vetoes to be enabled and a movie option
+ */
+fn allows_vetoes(bot_data: &crate::BotData, vote: &Vote) -> bool {
+    bot_data.veto_limit > 0
+        && vote
+            .options
+            .iter()
+            .any(|option| matches!(option, VoteOptionEnum::MovieVoteOption(_)))
+}
+
+/**
+ * Toggles whether the next reaction of the user to an option of the vote is a veto
+ * and tells the user by private message
+ */
+fn arm_veto(bot_data: &mut crate::BotData, reaction: &discord::model::Reaction, message_id: u64) {
+    let _ = bot_data.bot.delete_reaction(
+        reaction.channel_id,
+        reaction.message_id,
+        Some(reaction.user_id),
+        reaction.emoji.clone(),
+    );
+
+    let vote = match bot_data.votes.get(&message_id) {
+        Some(vote) if allows_vetoes(bot_data, vote) => vote,
+        _ => return,
+    };
+    let user_id = reaction.user_id.0;
+    let title = vote.title.clone();
+    let was_armed = vote.armed_vetoes.contains(&user_id);
+
+    let private_channel = match bot_data.bot.create_private_channel(reaction.user_id) {
+        Ok(private_channel) => private_channel,
+        Err(_) => return,
+    };
+
+    let remaining = match crate::veto_behaviour::check_veto_available(bot_data, user_id) {
+        Ok(remaining) => remaining,
+        Err(error) if !was_armed => return send_message::veto_failed_error(bot_data, private_channel.id, error),
+        Err(_) => 0,
+    };
+
+    // This can not panic, since the vote was found above
+    let vote = bot_data.votes.get_mut(&message_id).unwrap();
+    if was_armed {
+        vote.armed_vetoes.retain(|armed_user_id| *armed_user_id != user_id);
+    } else {
+        vote.armed_vetoes.push(user_id);
+    }
+
+    send_message::veto_armed_information(bot_data, private_channel.id, &title, !was_armed, remaining);
+}
+
+/**
+ * Vetoes the option with the emoji in the vote with the id, or in the random movie vote without an id
+ */
+pub fn veto(bot_data: &mut crate::BotData, vote_id: Option<u32>, emoji: String) {
+    let message = bot_data.message.clone().expect("Passing message to veto function failed.");
+
+    let message_id = if vote_id.is_some() {
+        match determine_vote(bot_data, vote_id, message.author.id, false) {
+            Some(message_id) => message_id,
+            None => return,
+        }
+    } else {
+        match find_random_movie_vote(bot_data) {
+            Some(message_id) => message_id.0,
+            None => return send_message::no_random_movie_vote_exists_error(bot_data),
+        }
+    };
+
+    if let Err(error) = veto_vote_option(bot_data, message_id, message.author.id.0, emoji) {
+        send_message::veto_failed_error(bot_data, message.channel_id, error);
+    }
+}
+
+/**
+ * Removes the movie option with the emoji from the vote as veto of the user. Random movie votes get a movie
+ * from the watch list as replacement. Returns the reason as error if the user can not veto the option.
+ */
+fn veto_vote_option(bot_data: &mut crate::BotData, message_id: u64, user_id: u64, emoji: String) -> Result<(), String> {
+    crate::veto_behaviour::check_veto_available(bot_data, user_id)?;
+
+    let vote = bot_data
+        .votes
+        .get(&message_id)
+        .cloned()
+        .ok_or_else(|| String::from("Die Abstimmung existiert nicht mehr."))?;
+
+    let option = vote
+        .options
+        .iter()
+        .find(|option| *option.get_emoji() == emoji)
+        .cloned()
+        .ok_or_else(|| format!("Die Abstimmung **{}** hat keine Option {}.", vote.title, emoji))?;
+
+    let movie_title = match &option {
+        VoteOptionEnum::MovieVoteOption(movie_option) => movie_option.cargo.movie_title.clone(),
+        VoteOptionEnum::GeneralVoteOption(_) => {
+            return Err(String::from("Ein Veto ist nur gegen Filme möglich, nicht gegen allgemeine Optionen."))
+        }
+    };
+
+    // Random movie votes keep their number of options, the vetoed movies of the vote are not drawn again.
+    // The replacement is built without sending messages, since a veto by reaction has no command channel to answer in.
+    let vote_movie_ids: Vec<u32> = vote.options.iter().filter_map(|option| option.get_movie_id()).collect();
+    let replacement = if vote.creator.id == bot_data.bot_user.id {
+        let mut excluded_ids = vote_movie_ids.clone();
+        excluded_ids.extend(crate::veto_behaviour::get_vetoed_movie_ids(bot_data, vote.id));
+
+        crate::selection_behaviour::draw_replacement_movie(bot_data, &excluded_ids)
+            .and_then(|movie_id| Some((movie_id, bot_data.watch_list.get(&movie_id)?.movie.clone())))
+            .and_then(|(movie_id, movie)| Some(VoteOptionEnum::MovieVoteOption(VoteOption::<crate::movie_behaviour::Movie> {
+                id: movie_id,
+                emoji: get_unused_vote_emoji(&vote)?,
+                cargo: movie,
+                votes: Vec::new(),
+                external: false,
+            })))
+    } else {
+        None
+    };
+
+    if replacement.is_none() && vote.options.len() == 1 {
+        return Err(String::from("Die letzte Option einer Abstimmung kann nicht durch ein Veto entfernt werden."));
+    }
+
+    let replacement_label = replacement.map(|replacement| {
+        if let Some(movie_id) = replacement.get_movie_id() {
+            crate::selection_behaviour::record_replacement_owner_appearance(bot_data, movie_id, &vote_movie_ids);
+        }
+        insert_vote_option(bot_data, message_id, replacement)
+    });
+    delete_vote_option(bot_data, message_id, emoji);
+    crate::veto_behaviour::record_veto(bot_data, user_id, vote.id, option.get_movie_id(), movie_title);
+
+    send_message::vote_option_vetoed_information(
+        bot_data,
+        discord::model::ChannelId(vote.channel_id),
+        user_id,
+        &vote.title,
+        option.get_label(),
+        replacement_label,
+    );
+
+    Ok(())
+}
+
+/**
+ * Changes the title of an open vote
+ */
+pub fn rename_vote(bot_data: &mut crate::BotData, vote_id: Option<u32>, title: String) {
+    let message_id = match find_editable_vote(bot_data, vote_id) {
+        Some(message_id) => message_id,
+        None => return,
+    };
+
+    // This can not panic, since the vote was found by 'find_editable_vote'
+    let vote = bot_data.votes.get_mut(&message_id).unwrap();
+    let previous_title = std::mem::replace(&mut vote.title, title.clone());
+
+    update_vote_embed_of_vote(bot_data, message_id);
+    send_message::vote_edited_successfully(bot_data, &title, format!("Vorheriger Titel: {}", previous_title));
+}
+
+/**
+ * Returns the index of the user if the user is in the list of votes, None if not.
+ */
+fn user_id_in_votes(
+    user_id: &discord::model::UserId,
+    vote_user_list: &Vec<discord::model::UserId>,
+) -> Option<usize> {
+    for i in 0..vote_user_list.len() {
+        if *user_id == vote_user_list[i] {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/**
+ * Edits the embed message of a previously send vote message
+ */
+fn update_vote_embed(
+    bot: &discord::Discord,
+    channel_id: &discord::model::ChannelId,
+    vote: &Vote,
+    message_id: &discord::model::MessageId,
+) {
+    let embed_description: String = format!(
+        "{}{}",
+        format_vote_countdown(vote),
+        build_vote_embed_description(vote, !vote.secret)
+    );
+
+    let _ = bot.edit_embed(*channel_id, *message_id, |embed| {
+        embed
+            .title(format!("{}", vote.title).as_str())
+            .description(embed_description.as_str())
+            .author(|author_builder| {
+                if let Some(avatar_url) = vote.creator.avatar_url() {
+                    author_builder
+                        .name(vote.creator.name.as_str())
+                        .icon_url(avatar_url.as_str())
+                } else {
+                    author_builder.name(vote.creator.name.as_str())
+                }
+            })
+            .footer(|footer| {
+                footer.text(
+                    format!(
+                        "Abstimmungs-ID: {} • {} • {}",
+                        vote.id,
+                        get_vote_instructions(vote),
+                        crate::general_behaviour::timestamp_to_string(&vote.creation_date, false)
+                    )
+                    .as_str(),
+                )
+            })
+            .color(crate::COLOR_BOT)
+    });
+}
+
+/**
+ * Removes the vote from the bot_data and manages all other dependencies
+ * Sends a message summarizing the result of the vote
+ * Without an id the vote of the author is closed. Administrators can close every vote by its id.
+ */
+pub fn close_vote(bot_data: &mut crate::BotData, vote_id: Option<u32>) {
+    let message = bot_data
+        .message
+        .clone()
+        .expect("Passing message to close_vote failed.");
+
+    let vote_message_id = match determine_vote(bot_data, vote_id, message.author.id, false) {
+        Some(vote_message_id) => vote_message_id,
+        None => return,
+    };
+    // This can not panic, since the vote was found by 'determine_vote'
+    let vote = &bot_data.votes.get(&vote_message_id).unwrap().clone();
+
+    if vote.creator.id != message.author.id
+        && !crate::general_behaviour::is_user_administrator(bot_data, message.author.id)
+    {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    let previous_message_id = vote.message_id;
+
+    // First remove all reactions on previous vote
+    remove_all_reactions_on_previous_vote(
+        bot_data,
+        vote,
+        (&get_vote_channel_id(vote, message.channel_id), &previous_message_id),
+    );
+
+    // Send the vote summary message, the random movie vote is closed like with close_movie_vote
+    let (vote_result, summary) = if vote.creator.id == bot_data.bot_user.id {
+        let vote_result = determine_random_movie_vote_result(bot_data, vote);
+        let summary = send_random_movie_vote_summary_message(bot_data, vote, &vote_result);
+        (vote_result, summary)
+    } else {
+        let option_emojis: Vec<String> = vote.options.iter().map(|option| option.get_emoji().clone()).collect();
+        let vote_result = determine_vote_result(bot_data, vote, &option_emojis);
+        let summary = send_vote_summary_message(bot_data, vote, &vote_result);
+        (vote_result, summary)
+    };
+
+    if summary.is_some() {
+        finish_closed_vote(bot_data, vote, &vote_result);
+    } else {
+        send_message::vote_message_failed_to_send_error(bot_data);
+    }
+}
+
+/**
+ * Sends the vote summary
+ */
+fn send_vote_summary_message(
+    bot_data: &crate::BotData,
+    vote: &Vote,
+    vote_result: &VoteResult,
+) -> Option<discord::model::MessageId> {
+    let mut embed_description = String::from(format!("**{}**", vote.title));
+    embed_description.push_str(build_vote_embed_description(vote, true).as_str());
+    embed_description.push_str(format_vote_result(vote, vote_result).as_str());
+
+    if let Ok(message) = bot_data.bot.send_embed(
+        bot_data
+            .message
+            .clone()
+            .expect("Passing message to send_vote_summary_message failed.")
+            .channel_id,
+        "",
+        |embed| {
+            embed
+                .title("Abstimmungsergebnisse")
+                .description(embed_description.as_str())
+                .author(|author_builder| {
+                    if let Some(avatar_url) = vote.creator.avatar_url() {
+                        author_builder
+                            .name(vote.creator.name.as_str())
+                            .icon_url(avatar_url.as_str())
+                    } else {
+                        author_builder.name(vote.creator.name.as_str())
+                    }
+                })
+                .color(crate::COLOR_SUCCESS)
+        },
+    ) {
+        Some(message.id)
+    } else {
+        None
+    }
+}
+
+/**
+ * Updates the movie limit per user and sends an info message
+ */
+pub fn set_movie_vote_limit(bot_data: &mut crate::BotData, new_limit: u32) {
+    use crate::general_behaviour;
+
+    let message = bot_data
+        .message
+        .clone()
+        .expect("Passing of message to set_movie_limit failed.");
+
+    if !general_behaviour::is_user_administrator(bot_data, message.author.id) {
+        return send_message::insufficient_permissions_error(bot_data);
+    }
+
+    let old_limit = bot_data.movie_vote_limit;
+    bot_data.movie_vote_limit = new_limit;
+
+    let _ = bot_data.bot.send_embed(message.channel_id, "", |embed| {
+        embed
+            .title("Filmlimit für Abstimmungen aktualisiert")
+            .description(
+                format!(
+                    "Das Filmlimit für Filmabstimmungen wurde von `{}` auf `{}` geändert.",
+                    old_limit, new_limit
+                )
+                .as_str(),
+            )
+            .color(crate::COLOR_INFORMATION)
+    });
+}
+
+/**
+ * Sends a message showing the current movie vote limit
+ */
+pub fn show_movie_vote_limit(bot_data: &crate::BotData) {
+    let _ = bot_data.bot.send_embed(
+        bot_data
+            .message
+            .clone()
+            .expect("Passing of message to show_movie_vote_limit function failed.")
+            .channel_id,
+        "",
+        |embed| {
+            embed
+                .title("Filmlimit für Abstimmungen")
+                .description(
+                    format!(
+                        "Das aktuelle Filmlimit für Abstimmungen beträgt `{}` {}.",
+                        bot_data.movie_vote_limit,
+                        if bot_data.movie_vote_limit == 1 {
+                            "Film"
+                        } else {
+                            "Filme"
+                        }
+                    )
+                    .as_str(),
+                )
+                .color(crate::COLOR_INFORMATION)
+        },
+    );
+}
+
+/**
+ * Creates a new random movie vote with the given optional limit
+ * The movies are selected according to the selection policy
+ */
+pub fn create_random_movie_vote(
+    bot_data: &mut crate::BotData,
+    optional_limit: Option<u32>,
+    settings: VoteSettings,
+) {
+    if user_already_owns_a_vote(bot_data, bot_data.bot_user.id) {
+        if optional_limit.is_some() {
+            send_message::there_is_already_a_random_movie_vote_information(bot_data);
+        }
+
+        send_random_movie_vote_again(bot_data);
+        return;
+    }
+
+    let limit = get_movie_limit_or_optional_limit_as_usize(bot_data, optional_limit);
+    if limit.is_none() {
+        return;
+    }
+
+    let limit = limit.unwrap();
+
+    // The movies are chosen according to the selection policy
+    let candidates = crate::selection_behaviour::get_selection_candidates(bot_data);
+    let random_movies = crate::selection_behaviour::select_random_movies(bot_data, &candidates, limit, &mut rand::thread_rng());
+
+    let options_vec: Vec<String> = random_movies.iter().map(|x| format!("id:{}", x)).collect();
+
+    create_vote(bot_data, String::from("Nächster Film"), options_vec, true, settings);
+
+    // The vote decides the movie of the next movie night
+    if let Some(message_id) = find_random_movie_vote(bot_data) {
+        crate::event_behaviour::link_vote_to_next_event(bot_data, message_id.0);
+        crate::selection_behaviour::record_owner_appearances(bot_data, &random_movies);
+    }
+}
+
+/**
+ * Returns the optional limit if it is_some, or the set limit from bot data, each converted to usize
+ */
+fn get_movie_limit_or_optional_limit_as_usize(
+    bot_data: &crate::BotData,
+    optional_limit: Option<u32>,
+) -> Option<usize> {
+    use std::convert::TryInto;
+
+    let limit = match optional_limit {
+        Some(limit) => limit,
+        None => bot_data.movie_vote_limit,
+    };
+    let limit = limit.try_into();
+
+    if limit.is_err() {
+        send_message::unknown_error_occured(bot_data, 100);
+        return None;
+    } else {
+        return Some(limit.unwrap());
+    }
+}
+
+/**
+ * Finds the random movie vote in the votes and sends the vote message again
+ * If there is no random movie vote, sends an error message that there is no
+ * random_movie_vote
+ */
+fn send_random_movie_vote_again(bot_data: &mut crate::BotData) {
+    // Find the random movie vote in the votes
+    if let Some(message_id) = find_random_movie_vote(bot_data) {
+        // Extract the vote as mutable from the bot_data
+        // This can not panic, since the vote was found by 'find_random_movie_vote'
+        let mut the_vote = bot_data.votes.get_mut(&message_id.0).unwrap().clone();
+
+        // Send the vote details message and assign it to the bot_data
+        // If the sending was successful, add the vote to the waiting_for_reaction list
+        if let Some(message_id) = send_vote_details_message(bot_data, &mut the_vote) {
+            bot_data
+                .wait_for_reaction
+                .push(crate::general_behaviour::WaitingForReaction::Vote(
+                    message_id,
+                ))
+        } else {
+            send_message::vote_message_failed_to_send_error(bot_data);
+        }
+    } else {
+        send_message::no_random_movie_vote_exists_error(bot_data);
+    }
+}
+
+/**
+ * Finds the random movie vote in the bot data and returns its MessageId, or None if the vote couldn't be found
+ */
+fn find_random_movie_vote(bot_data: &mut crate::BotData) -> Option<discord::model::MessageId> {
+    let bot_user_id = bot_data.bot_user.id;
+
+    // Find the random movie vote in the votes
+    if let Some(random_movie_vote) = bot_data
+        .votes
+        .iter_mut()
+        .find(|(_, vote)| vote.creator.id == bot_user_id)
+    {
+        return Some(discord::model::MessageId(*random_movie_vote.0));
+    } else {
+        return None;
+    }
+}
+
+pub fn close_random_movie_vote(bot_data: &mut crate::BotData) {
+    // Find the random movie vote in the votes
     if let Some(message_id) = find_random_movie_vote(bot_data) {
         // Extract the vote from the bot_data
         // This can not panic, since the vote was found by 'find_random_movie_vote'
         let vote = &bot_data.votes.get(&message_id.0).unwrap().clone();
 
-        let previous_message_id = vote.message_id;
-        let message = bot_data
-            .message
-            .clone()
-            .expect("Passing of message to close_random_movie_vote failed.");
+        let previous_message_id = vote.message_id;
+        let message = bot_data
+            .message
+            .clone()
+            .expect("Passing of message to close_random_movie_vote failed.");
+
+        // First remove all reactions on previous vote
+        remove_all_reactions_on_previous_vote(
+            bot_data,
+            vote,
+            (&get_vote_channel_id(vote, message.channel_id), &previous_message_id),
+        );
+
+        // Send the vote summary message
+        let vote_result = determine_random_movie_vote_result(bot_data, vote);
+
+        if let Some(_) = send_random_movie_vote_summary_message(bot_data, vote, &vote_result) {
+            finish_closed_vote(bot_data, vote, &vote_result);
+        } else {
+            send_message::vote_message_failed_to_send_error(bot_data);
+        }
+        return;
+    }
+
+    // If the user has not vote, send a message
+    send_message::user_has_no_vote_error(bot_data);
+}
+
+/**
+ * Determines the result of the random movie vote among its movie options
+ */
+fn determine_random_movie_vote_result(bot_data: &crate::BotData, vote: &Vote) -> VoteResult {
+    let movie_option_emojis: Vec<String> = vote
+        .options
+        .iter()
+        .filter(|option| matches!(option, VoteOptionEnum::MovieVoteOption(_)))
+        .map(|option| option.get_emoji().clone())
+        .collect();
+
+    determine_vote_result(bot_data, vote, &movie_option_emojis)
+}
+
+fn send_random_movie_vote_summary_message(
+    bot_data: &mut crate::BotData,
+    vote: &Vote,
+    vote_result: &VoteResult,
+) -> Option<discord::model::MessageId> {
+    // If the tie is broken by a runoff vote, there is no winner yet
+    if !vote_result.runoff_options.is_empty() {
+        let embed_description = format!(
+            "Mehrere Filme liegen gleichauf, deshalb folgt eine Stichwahl.{}",
+            format_vote_result(vote, vote_result)
+        );
+
+        return bot_data.bot.send_embed(
+            bot_data
+                .message
+                .as_ref()
+                .expect("Passing message to send_random_movie_vote_summary_message failed.")
+                .channel_id,
+            "",
+            |embed| {
+                embed
+                    .title("Gleichstand")
+                    .description(embed_description.as_str())
+                    .color(crate::COLOR_INFORMATION)
+            },
+        )
+        .ok()
+        .map(|message| message.id);
+    }
+
+    if let Some(movie_vote_winner) = determine_movie_vote_winner(vote, vote_result) {
+        let mut embed_description = String::from("Der folgende Film hat die Abstimmung gewonnen:");
+        embed_description.push_str(format_vote_result(vote, vote_result).as_str());
+
+        let winner_message = bot_data.bot.send_embed(
+            bot_data
+                .message
+                .clone()
+                .expect("Passing message to send_random_movie_vote_summary_message failed.")
+                .channel_id,
+            "",
+            |embed| {
+                embed
+                    .title("Gewinner")
+                    .description(embed_description.as_str())
+                    .author(|author_builder| {
+                        if let Some(avatar_url) = vote.creator.avatar_url() {
+                            author_builder
+                                .name(vote.creator.name.as_str())
+                                .icon_url(avatar_url.as_str())
+                        } else {
+                            author_builder.name(vote.creator.name.as_str())
+                        }
+                    })
+                    .color(crate::COLOR_SUCCESS)
+            },
+        );
+
+        // A winner that is not on the watch list is offered to be added when the vote is finished,
+        // the movie night of the vote is linked once the offer is accepted
+        if movie_vote_winner.external {
+            return winner_message.ok().map(|message| message.id);
+        }
+
+        use crate::movie_behaviour::find_id_by_tmdb_id;
+
+        // Try to get the id of the winner inside the watch list
+        if let Some(&watch_list_id_of_winner) =
+            find_id_by_tmdb_id(movie_vote_winner.cargo.tmdb_id, &bot_data.watch_list)
+        {
+            // Remember the win for the profile of the user who added the movie
+            if let Some(movie_entry) = bot_data.watch_list.get_mut(&watch_list_id_of_winner) {
+                movie_entry.vote_wins += 1;
+            }
+
+            // Without a movie night, everybody who took part in the vote is assumed to watch the movie
+            let participants = if crate::event_behaviour::link_winner_to_event(bot_data, vote.message_id.0, watch_list_id_of_winner) {
+                Vec::new()
+            } else {
+                get_vote_participants(vote)
+            };
+
+            // If the id was found, try to retreive the entry
+            if let Some(movie_entry) = bot_data.watch_list.get(&watch_list_id_of_winner) {
+                // If this also worked, send the message
+                if let Ok(message) =
+                    send_message::movie_information(bot_data, movie_entry, false, false, true)
+                {
+                    // If the message could be sent, add the reactions to the bot_data
+                    let _ = bot_data.bot.add_reaction(
+                        message.channel_id,
+                        message.id,
+                        discord::model::ReactionEmoji::Unicode(String::from("✅")),
+                    );
+
+                    let _ = bot_data.bot.add_reaction(
+                        message.channel_id,
+                        message.id,
+                        discord::model::ReactionEmoji::Unicode(String::from("❎")),
+                    );
+
+                    bot_data.wait_for_reaction.push(
+                        crate::general_behaviour::WaitingForReaction::AddMovieToWatched(
+                            message.clone(),
+                            movie_entry.movie.clone(),
+                            participants,
+                        ),
+                    );
+
+                    // Now return the message id
+                    return Some(message.id);
+                } else {
+                    // If the message couldn't be sent, send an error message
+                    send_message::sending_of_movie_information_message_failed_error(bot_data);
+                }
+            }
+            // If the entry couldn't be found, send an error message
+            else {
+                send_message::movie_not_found_in_watchlist_error(
+                    bot_data,
+                    movie_vote_winner.cargo.movie_title,
+                );
+            }
+        }
+        // If the id couldn't be found, send an error message
+        else {
+            send_message::movie_not_found_in_watchlist_error(
+                bot_data,
+                movie_vote_winner.cargo.movie_title,
+            );
+        }
+    }
+    // If there were no movie votes to find the winner, send an error message
+    else {
+        send_message::no_movie_vote_options_in_movie_vote_error(bot_data);
+    }
+
+    return None;
+}
+
+/**
+ * Closes the votes whose deadline passed. Returns true if a vote was closed.
+ */
+pub fn close_expired_votes(bot_data: &mut crate::BotData) -> bool {
+    let now = current_timestamp();
+
+    let expired_votes: Vec<Vote> = bot_data
+        .votes
+        .values()
+        .filter(|vote| matches!(vote.deadline, Some(deadline) if deadline <= now))
+        .cloned()
+        .collect();
+
+    for vote in expired_votes.iter() {
+        close_expired_vote(bot_data, vote);
+    }
+
+    !expired_votes.is_empty()
+}
+
+/**
+ * Updates the countdown of all open votes with a deadline and reminds members who have not voted yet shortly before
+ * the deadline. Returns true if a vote was changed.
+ */
+pub fn update_vote_deadlines(bot_data: &mut crate::BotData) -> bool {
+    let now = current_timestamp();
+    let mut something_changed = false;
+
+    let votes_with_deadline: Vec<Vote> = bot_data
+        .votes
+        .values()
+        .filter(|vote| matches!(vote.deadline, Some(deadline) if deadline > now))
+        .cloned()
+        .collect();
+
+    for vote in votes_with_deadline.iter() {
+        let deadline = vote.deadline.unwrap_or(now);
+        let channel_id = discord::model::ChannelId(vote.channel_id);
+
+        // Short votes remind halfway through, so the reminder is not sent right after the creation
+        let reminder_minutes = VOTE_REMINDER_MINUTES.min((deadline - vote.creation_date).num_minutes() / 2);
+        if !vote.reminder_sent && (deadline - now).num_minutes() <= reminder_minutes {
+            send_vote_reminders(bot_data, vote);
+
+            if let Some(vote) = bot_data.votes.get_mut(&vote.message_id.0) {
+                vote.reminder_sent = true;
+            }
+            something_changed = true;
+        }
+
+        update_vote_embed(&bot_data.bot, &channel_id, vote, &vote.message_id);
+    }
+
+    something_changed
+}
+
+/**
+ * Sends a private message to every active member who has not voted yet, with a link to the vote
+ */
+fn send_vote_reminders(bot_data: &crate::BotData, vote: &Vote) {
+    let participants = get_vote_participants(vote);
+    let minutes_left = vote
+        .deadline
+        .map(|deadline| (deadline - current_timestamp()).num_minutes().max(0) as u32)
+        .unwrap_or(0);
+
+    let vote_link = format!(
+        "https://discord.com/channels/{}/{}/{}",
+        bot_data.server_id.0, vote.channel_id, vote.message_id.0
+    );
+
+    for user_id in crate::attendance_behaviour::get_active_members(bot_data)
+        .iter()
+        .filter(|user_id| !participants.contains(user_id))
+    {
+        if let Ok(private_channel) = bot_data
+            .bot
+            .create_private_channel(discord::model::UserId(*user_id))
+        {
+            let _ = bot_data.bot.send_embed(private_channel.id, "", |embed| {
+                embed
+                    .title("Erinnerung an eine Abstimmung")
+                    .description(
+                        format!(
+                            "Die Abstimmung **{}** endet in {} und du hast noch nicht abgestimmt.\n[Zur Abstimmung]({})",
+                            vote.title,
+                            format_duration_minutes(minutes_left),
+                            vote_link
+                        )
+                        .as_str(),
+                    )
+                    .color(crate::COLOR_INFORMATION)
+            });
+        }
+    }
+}
+
+/**
+ * Closes a vote whose deadline passed and sends the normal summary into the channel of the vote
+ * If the vote can not be closed, its deadline is removed so the creator can close it manually
+ */
+fn close_expired_vote(bot_data: &mut crate::BotData, vote: &Vote) {
+    let channel_id = discord::model::ChannelId(vote.channel_id);
+
+    // No command closes the vote, so the summary is sent as if the vote message itself was the command
+    let previous_message = bot_data.message.clone();
+    let summary = match bot_data.bot.get_message(channel_id, vote.message_id) {
+        Ok(vote_message) => {
+            bot_data.message = Some(vote_message);
+            remove_all_reactions_on_previous_vote(bot_data, vote, (&channel_id, &vote.message_id));
+
+            if vote.creator.id == bot_data.bot_user.id {
+                let vote_result = determine_random_movie_vote_result(bot_data, vote);
+                send_random_movie_vote_summary_message(bot_data, vote, &vote_result)
+                    .map(|_| vote_result)
+            } else {
+                let option_emojis: Vec<String> = vote.options.iter().map(|option| option.get_emoji().clone()).collect();
+                let vote_result = determine_vote_result(bot_data, vote, &option_emojis);
+                send_vote_summary_message(bot_data, vote, &vote_result)
+                    .map(|_| vote_result)
+            }
+        }
+        Err(_) => None,
+    };
+
+    // A runoff vote is sent into the channel of the vote as well
+    if let Some(vote_result) = &summary {
+        finish_closed_vote(bot_data, vote, vote_result);
+    }
+    bot_data.message = previous_message;
+
+    if summary.is_none() {
+        if let Some(vote) = bot_data.votes.get_mut(&vote.message_id.0) {
+            vote.deadline = None;
+        }
+    }
+}
+
+/**
+ * Removes the closed vote from the bot_data, adds it to the vote archive and starts a runoff vote if the tie break demands one
+ */
+fn finish_closed_vote(bot_data: &mut crate::BotData, vote: &Vote, vote_result: &VoteResult) {
+    remove_previous_vote_from_wait_for_reaction(bot_data, &vote.message_id);
+    let _ = bot_data.votes.remove(&vote.message_id.0);
+
+    archive_closed_vote(bot_data, vote, vote_result);
+    start_runoff_vote(bot_data, vote, &vote_result.runoff_options);
+    offer_to_add_watched_movie(bot_data, vote, vote_result);
+}
+
+/**
+ * If the winner of the vote is a movie from TMDb that is not on the watch list, offers to add it to the watch list
+ * as watched movie. The movie belongs to the creator of the vote, or to the administrator who accepts the offer for random movie votes.
+ * If the vote belongs to a movie night, the movie was watched at its date by everybody who signed up,
+ * otherwise everybody who took part in the vote is assumed to have watched it.
+ */
+fn offer_to_add_watched_movie(bot_data: &mut crate::BotData, vote: &Vote, vote_result: &VoteResult) {
+    use crate::movie_behaviour::{MovieStatus, WatchListEntry};
+
+    let winner = match determine_movie_vote_winner(vote, vote_result) {
+        Some(winner) if winner.external => winner,
+        _ => return,
+    };
+
+    let linked_event = bot_data
+        .events
+        .values()
+        .find(|event| event.vote_message_id == Some(vote.message_id.0));
+
+    let (watched_timestamp, attendees) = match linked_event {
+        Some(event) => (event.date, event.get_user_ids(crate::event_behaviour::Rsvp::Yes)),
+        None => (current_timestamp(), get_vote_participants(vote)),
+    };
+
+    let is_random_movie_vote = vote.creator.id == bot_data.bot_user.id;
+    let new_entry = WatchListEntry {
+        movie: winner.cargo,
+        user: vote.creator.name.clone(),
+        user_id: vote.creator.id,
+        status: MovieStatus::Watched,
+        added_timestamp: current_timestamp(),
+        watched_or_removed_timestamp: Some(watched_timestamp),
+        ratings: HashMap::new(),
+        reviews: HashMap::new(),
+        vote_wins: if is_random_movie_vote { 1 } else { 0 },
+        attendees,
+        seen_by: Vec::new(),
+        interested: Vec::new(),
+        snoozed_until: None,
+    };
+
+    match send_message::add_watched_movie_offer(bot_data, &new_entry) {
+        Ok(message) => {
+            for emoji in ["✅", "❎"].iter() {
+                let _ = bot_data.bot.add_reaction(
+                    message.channel_id,
+                    message.id,
+                    discord::model::ReactionEmoji::Unicode(emoji.to_string()),
+                );
+            }
+
+            bot_data.wait_for_reaction.push(
+                crate::general_behaviour::WaitingForReaction::AddWatchedMovie(message, new_entry, vote.message_id.0),
+            );
+        }
+        Err(_) => send_message::vote_message_failed_to_send_error(bot_data),
+    }
+}
+
+/**
+ * Adds the closed vote with the choices of every user and its result to the vote archive
+ */
+fn archive_closed_vote(bot_data: &mut crate::BotData, vote: &Vote, vote_result: &VoteResult) {
+    use crate::vote_history_behaviour::{archive_vote, ArchivedVote, ArchivedVoteOption};
+
+    // Secret votes stay secret in the archive, so only the number of votes is kept
+    let options: Vec<ArchivedVoteOption> = vote
+        .options
+        .iter()
+        .map(|option| ArchivedVoteOption {
+            emoji: option.get_emoji().clone(),
+            label: option.get_label(),
+            movie_id: option.get_movie_id(),
+            votes: if vote.secret {
+                vec![]
+            } else {
+                option.get_votes().iter().map(|user_id| user_id.0).collect()
+            },
+            vote_count: option.get_votes().len(),
+        })
+        .collect();
 
-        // First remove all reactions on previous vote
-        remove_all_reactions_on_previous_vote(
-            bot_data,
-            vote,
-            (&message.channel_id, &previous_message_id),
-        );
+    let decision = if vote_result.runoff_options.is_empty() {
+        vote_result.tie_break.clone()
+    } else {
+        Some(format!("Stichwahl zwischen {}", vote_result.runoff_options.join(" ")))
+    };
 
-        // Send the vote summary message
-        if let Some(_) = send_random_movie_vote_summary_message(bot_data, vote) {
-            remove_previous_vote_from_wait_for_reaction(bot_data, &previous_message_id);
-            let _ = bot_data.votes.remove(&previous_message_id.0);
-        } else {
-            send_message::vote_message_failed_to_send_error(bot_data);
+    archive_vote(
+        bot_data,
+        ArchivedVote {
+            id: vote.id,
+            title: vote.title.clone(),
+            creator_id: vote.creator.id.0,
+            mode: vote.mode,
+            secret: vote.secret,
+            options,
+            rankings: if vote.secret { HashMap::new() } else { vote.rankings.clone() },
+            winner: vote_result.winner.clone(),
+            decision,
+            creation_date: vote.creation_date,
+            closing_date: current_timestamp(),
+        },
+    );
+}
+
+/**
+ * Returns the sorted ids of all users who voted for at least one option
+ */
+fn get_vote_participants(vote: &Vote) -> Vec<u64> {
+    let mut participants: Vec<u64> = vote
+        .options
+        .iter()
+        .flat_map(|option| match option {
+            VoteOptionEnum::GeneralVoteOption(general_option) => general_option.votes.clone(),
+            VoteOptionEnum::MovieVoteOption(movie_option) => movie_option.votes.clone(),
+        })
+        .map(|user_id| user_id.0)
+        .collect();
+
+    participants.sort_unstable();
+    participants.dedup();
+    participants
+}
+
+/**
+ * Returns the movie option that won the vote
+ */
+fn determine_movie_vote_winner(
+    vote: &Vote,
+    vote_result: &VoteResult,
+) -> Option<VoteOption<crate::movie_behaviour::Movie>> {
+    let winner_emoji = vote_result.winner.as_ref()?;
+
+    vote.options.iter().find_map(|option| match option {
+        VoteOptionEnum::MovieVoteOption(movie_option) if movie_option.emoji == *winner_emoji => {
+            Some(movie_option.clone())
         }
-        return;
-    }
+        _ => None,
+    })
+}
 
-    // If the user has not vote, send a message
-    send_message::user_has_no_vote_error(bot_data);
+/**
+ * Determines the winner among the options with the given emojis. Ranked votes are won by instant runoff,
+ * all other votes by the option with the most votes. Ties are broken by the tie break policy.
+ */
+fn determine_vote_result(bot_data: &crate::BotData, vote: &Vote, option_emojis: &[String]) -> VoteResult {
+    match vote.mode {
+        VoteMode::Single | VoteMode::Approval | VoteMode::PickUpTo(_) => {
+            let counts: Vec<(String, usize)> = vote
+                .options
+                .iter()
+                .filter(|option| option_emojis.contains(option.get_emoji()))
+                .map(|option| match option {
+                    VoteOptionEnum::GeneralVoteOption(general_option) => (general_option.emoji.clone(), general_option.votes.len()),
+                    VoteOptionEnum::MovieVoteOption(movie_option) => (movie_option.emoji.clone(), movie_option.votes.len()),
+                })
+                .collect();
+
+            determine_option_with_max_count(bot_data, vote, &counts, option_emojis.len(), Vec::new())
+        }
+        VoteMode::Ranked => tally_instant_runoff(bot_data, vote, option_emojis),
+    }
 }
 
-fn send_random_movie_vote_summary_message(
-    bot_data: &mut crate::BotData,
+/**
+ * Returns the result with the option with the highest count as winner. If several options are tied,
+ * the tie break policy decides. A runoff vote is only possible if not all of the candidates are tied,
+ * otherwise it would repeat the same vote.
+ */
+fn determine_option_with_max_count(
+    bot_data: &crate::BotData,
     vote: &Vote,
-) -> Option<discord::model::MessageId> {
-    if let Some(movie_vote_winner) = determine_movie_vote_winner(vote) {
-        let _ = bot_data.bot.send_embed(
-            bot_data
-                .message
-                .clone()
-                .expect("Passing message to send_random_movie_vote_summary_message failed.")
-                .channel_id,
-            "",
-            |embed| {
-                embed
-                    .title("Gewinner")
-                    .description("Der folgende Film hat die Abstimmung gewonnen:")
-                    .author(|author_builder| {
-                        if let Some(avatar_url) = vote.creator.avatar_url() {
-                            author_builder
-                                .name(vote.creator.name.as_str())
-                                .icon_url(avatar_url.as_str())
-                        } else {
-                            author_builder.name(vote.creator.name.as_str())
-                        }
-                    })
-                    .color(crate::COLOR_SUCCESS)
-            },
-        );
+    counts: &[(String, usize)],
+    candidate_count: usize,
+    rounds: Vec<RunoffRound>,
+) -> VoteResult {
+    use crate::tie_break_behaviour::{break_tie, TieBreak};
+
+    let max_count = counts.iter().map(|(_, count)| *count).max();
+    let tied: Vec<(String, Option<u32>)> = counts
+        .iter()
+        .filter(|(_, count)| Some(*count) == max_count)
+        .map(|(emoji, _)| {
+            let movie_id = vote.options.iter()
+                .find(|option| option.get_emoji() == emoji)
+                .and_then(|option| option.get_movie_id());
+            (emoji.clone(), movie_id)
+        })
+        .collect();
 
-        use crate::movie_behaviour::find_id_by_tmdb_id;
+    let mut vote_result = VoteResult {
+        winner: None,
+        rounds,
+        tie_break: None,
+        runoff_options: Vec::new(),
+    };
 
-        // Try to get the id of the winner inside the watch list
-        if let Some(watch_list_id_of_winner) =
-            find_id_by_tmdb_id(movie_vote_winner.cargo.tmdb_id, &bot_data.watch_list)
-        {
-            // If the id was found, try to retreive the entry
-            if let Some(movie_entry) = bot_data.watch_list.get(&watch_list_id_of_winner) {
-                // If this also worked, send the message
-                if let Ok(message) =
-                    send_message::movie_information(bot_data, movie_entry, false, false, true)
-                {
-                    // If the message could be sent, add the reactions to the bot_data
-                    let _ = bot_data.bot.add_reaction(
-                        message.channel_id,
-                        message.id,
-                        discord::model::ReactionEmoji::Unicode(String::from("✅")),
-                    );
+    if tied.len() == 1 {
+        vote_result.winner = Some(tied[0].0.clone());
+    } else if tied.len() > 1 {
+        match break_tie(bot_data, &tied, tied.len() < candidate_count) {
+            TieBreak::Winner(emoji, rule) => {
+                vote_result.winner = Some(emoji);
+                vote_result.tie_break = Some(rule);
+            }
+            TieBreak::Runoff => {
+                vote_result.runoff_options = tied.into_iter().map(|(emoji, _)| emoji).collect();
+            }
+        }
+    }
 
-                    let _ = bot_data.bot.add_reaction(
-                        message.channel_id,
-                        message.id,
-                        discord::model::ReactionEmoji::Unicode(String::from("❎")),
-                    );
+    vote_result
+}
 
-                    bot_data.wait_for_reaction.push(
-                        crate::general_behaviour::WaitingForReaction::AddMovieToWatched(
-                            message.clone(),
-                            movie_entry.movie.clone(),
-                        ),
-                    );
+/**
+ * Tallies the rankings with instant runoff. In every round each ranking counts for its highest ranked remaining option.
+ * An option with more than half of these votes wins. Otherwise the options with the fewest votes are eliminated.
+ * If all remaining options are tied, the tie break policy decides among them.
+ */
+fn tally_instant_runoff(bot_data: &crate::BotData, vote: &Vote, option_emojis: &[String]) -> VoteResult {
+    let mut remaining: Vec<String> = option_emojis.to_vec();
+    let mut rounds: Vec<RunoffRound> = Vec::new();
 
-                    // Now return the message id
-                    return Some(message.id);
-                } else {
-                    // If the message couldn't be sent, send an error message
-                    send_message::sending_of_movie_information_message_failed_error(bot_data);
+    while !remaining.is_empty() {
+        let mut counts: Vec<(String, usize)> = remaining.iter().map(|emoji| (emoji.clone(), 0)).collect();
+
+        for ranking in vote.rankings.values() {
+            if let Some(choice) = ranking.iter().find(|emoji| remaining.contains(emoji)) {
+                if let Some(count) = counts.iter_mut().find(|(emoji, _)| emoji == choice) {
+                    count.1 += 1;
                 }
             }
-            // If the entry couldn't be found, send an error message
-            else {
-                send_message::movie_not_found_in_watchlist_error(
-                    bot_data,
-                    movie_vote_winner.cargo.movie_title,
-                );
-            }
         }
-        // If the id couldn't be found, send an error message
-        else {
-            send_message::movie_not_found_in_watchlist_error(
-                bot_data,
-                movie_vote_winner.cargo.movie_title,
-            );
+
+        let total: usize = counts.iter().map(|(_, count)| *count).sum();
+        let max_count = counts.iter().map(|(_, count)| *count).max().unwrap_or(0);
+        let min_count = counts.iter().map(|(_, count)| *count).min().unwrap_or(0);
+
+        // A majority or the last remaining option wins, if all options are tied the tie break policy decides
+        if max_count * 2 > total || remaining.len() == 1 || min_count == max_count {
+            let final_counts = counts.clone();
+            rounds.push(RunoffRound { counts, eliminated: Vec::new() });
+            return determine_option_with_max_count(bot_data, vote, &final_counts, option_emojis.len(), rounds);
         }
+
+        let eliminated: Vec<String> = counts
+            .iter()
+            .filter(|(_, count)| *count == min_count)
+            .map(|(emoji, _)| emoji.clone())
+            .collect();
+
+        remaining.retain(|emoji| !eliminated.contains(emoji));
+        rounds.push(RunoffRound { counts, eliminated });
     }
-    // If there were no movie votes to find the winner, send an error message
-    else {
-        send_message::no_movie_vote_options_in_movie_vote_error(bot_data);
+
+    VoteResult {
+        winner: None,
+        rounds,
+        tie_break: None,
+        runoff_options: Vec::new(),
+    }
+}
+
+/**
+ * Formats the rounds of the instant runoff, the rule that broke a tie and the winner for the vote summary
+ */
+fn format_vote_result(vote: &Vote, vote_result: &VoteResult) -> String {
+    let get_label = |emoji: &String| {
+        vote.options
+            .iter()
+            .find(|option| option.get_emoji() == emoji)
+            .map(|option| option.get_label())
+            .unwrap_or_else(|| emoji.clone())
+    };
+
+    let mut result = String::new();
+
+    for (idx, round) in vote_result.rounds.iter().enumerate() {
+        result.push_str(format!("\n\n**Runde {}**", idx + 1).as_str());
+
+        for (emoji, count) in round.counts.iter() {
+            result.push_str(format!("\n`{}` {}", count, get_label(emoji)).as_str());
+        }
+
+        if !round.eliminated.is_empty() {
+            result.push_str(format!("\nAusgeschieden: {}", round.eliminated.join(" ")).as_str());
+        }
     }
 
-    return None;
+    if let Some(tie_break) = &vote_result.tie_break {
+        result.push_str(format!("\n\n**Gleichstand entschieden durch:** {}", tie_break).as_str());
+    }
+
+    if !vote_result.runoff_options.is_empty() {
+        let runoff_labels: Vec<String> = vote_result.runoff_options.iter().map(get_label).collect();
+        result.push_str(format!("\n\n**Stichwahl zwischen:** {}", runoff_labels.join(", ")).as_str());
+    }
+
+    if let Some(winner) = &vote_result.winner {
+        result.push_str(format!("\n\n**Gewinner:** {}", get_label(winner)).as_str());
+    }
+
+    result
 }
 
-fn determine_movie_vote_winner(vote: &Vote) -> Option<VoteOption<crate::movie_behaviour::Movie>> {
-    let only_movie_options: Vec<&VoteOption<crate::movie_behaviour::Movie>> = vote
+/**
+ * Starts a runoff vote between the tied options of the closed vote. The runoff keeps the creator and the settings
+ * of the vote, so the runoff of a random movie vote is the new random movie vote.
+ */
+fn start_runoff_vote(bot_data: &mut crate::BotData, vote: &Vote, runoff_options: &[String]) {
+    if runoff_options.is_empty() {
+        return;
+    }
+
+    let mut options: Vec<VoteOptionEnum> = vote
         .options
         .iter()
-        .filter_map(|option| match option {
-            VoteOptionEnum::GeneralVoteOption(_) => None,
-            VoteOptionEnum::MovieVoteOption(movie_option) => Some(movie_option),
-        })
+        .filter(|option| runoff_options.contains(option.get_emoji()))
+        .cloned()
         .collect();
+    for option in options.iter_mut() {
+        option.get_votes_mut().clear();
+    }
 
-    if let Some(max_votes) = only_movie_options
-        .iter()
-        .map(|option| option.votes.len())
-        .max()
-    {
-        use rand::seq::IteratorRandom;
-        let mut rng = rand::thread_rng();
+    let creation_date = current_timestamp();
+    let mut runoff_vote = Vote {
+        id: 0, // The id is assigned when the vote is sent
+        creator: vote.creator.clone(),
+        creation_date,
+        title: format!("Stichwahl: {}", vote.title),
+        options,
+        message_id: discord::model::MessageId(0),
+        channel_id: 0,
+        mode: VoteMode::Single,
+        rankings: HashMap::new(),
+        // The runoff runs as long as the closed vote did
+        deadline: vote.deadline.map(|deadline| creation_date + (deadline - vote.creation_date)),
+        reminder_sent: false,
+        secret: vote.secret,
+        armed_vetoes: Vec::new(),
+    };
+
+    if let Some(message) = send_vote_details_message(bot_data, &mut runoff_vote) {
+        let message_id = message.id;
+        bot_data
+            .wait_for_reaction
+            .push(crate::general_behaviour::WaitingForReaction::Vote(message));
 
-        if let Some(&random_movie_option_with_max_vote) = only_movie_options
+        // A runoff of the random movie vote decides the movie of the next movie night as well
+        if vote.creator.id == bot_data.bot_user.id {
+            crate::event_behaviour::link_vote_to_next_event(bot_data, message_id.0);
+        }
+    } else {
+        send_message::vote_message_failed_to_send_error(bot_data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tie_break_behaviour::TieBreakPolicy;
+
+    fn test_vote(mode: VoteMode, emojis: &[&str]) -> Vote {
+        let options = emojis
             .iter()
-            .filter(|option| option.votes.len() == max_votes)
-            .choose(&mut rng)
-        {
-            return Some(random_movie_option_with_max_vote.clone());
+            .map(|emoji| {
+                VoteOptionEnum::GeneralVoteOption(VoteOption::<String> {
+                    id: 0,
+                    emoji: emoji.to_string(),
+                    cargo: format!("Option {}", emoji),
+                    votes: Vec::new(),
+                    external: false,
+                })
+            })
+            .collect();
+
+        Vote {
+            id: 1,
+            creator: discord::model::User {
+                id: discord::model::UserId(1),
+                name: String::from("Tester"),
+                discriminator: 1,
+                avatar: None,
+                bot: false,
+            },
+            creation_date: crate::general_behaviour::current_timestamp(),
+            title: String::from("Test"),
+            options,
+            message_id: discord::model::MessageId(1),
+            channel_id: 0,
+            mode,
+            rankings: HashMap::new(),
+            deadline: None,
+            reminder_sent: false,
+            secret: false,
+            armed_vetoes: Vec::new(),
         }
     }
 
-    return None;
+    fn create_ranked_vote(emojis: &[&str], rankings: &[&[&str]]) -> Vote {
+        let mut vote = test_vote(VoteMode::Ranked, emojis);
+        for (user_idx, ranking) in rankings.iter().enumerate() {
+            vote.rankings.insert(user_idx as u64 + 10, ranking.iter().map(|emoji| emoji.to_string()).collect());
+        }
+        vote
+    }
+
+    fn get_emojis(vote: &Vote) -> Vec<String> {
+        vote.options.iter().map(|option| option.get_emoji().clone()).collect()
+    }
+
+    fn to_counts(counts: &[(&str, usize)]) -> Vec<(String, usize)> {
+        counts.iter().map(|(emoji, count)| (emoji.to_string(), *count)).collect()
+    }
+
+    #[test]
+    fn instant_runoff_majority_wins_in_first_round() {
+        let bot_data = crate::get_test_bot_data();
+        let vote = create_ranked_vote(&["A", "B", "C"], &[&["A", "B"], &["A", "C"], &["B", "A"]]);
+
+        let vote_result = tally_instant_runoff(&bot_data, &vote, &get_emojis(&vote));
+
+        assert_eq!(vote_result.winner, Some(String::from("A")));
+        assert_eq!(vote_result.rounds.len(), 1);
+        assert_eq!(vote_result.tie_break, None);
+    }
+
+    #[test]
+    fn instant_runoff_eliminates_all_lowest_options_at_once() {
+        let bot_data = crate::get_test_bot_data();
+        let vote = create_ranked_vote(
+            &["A", "B", "C", "D"],
+            &[&["A"], &["A"], &["B"], &["B"], &["C", "B"], &["D", "B"]],
+        );
+
+        let vote_result = tally_instant_runoff(&bot_data, &vote, &get_emojis(&vote));
+
+        assert_eq!(vote_result.rounds.len(), 2);
+        assert_eq!(vote_result.rounds[0].eliminated, vec![String::from("C"), String::from("D")]);
+        assert_eq!(vote_result.rounds[1].counts, to_counts(&[("A", 2), ("B", 4)]));
+        assert_eq!(vote_result.winner, Some(String::from("B")));
+    }
+
+    #[test]
+    fn instant_runoff_ends_with_the_last_remaining_option() {
+        let bot_data = crate::get_test_bot_data();
+        // B and C are eliminated together, then only A remains although the ballots for B and C are exhausted
+        let vote = create_ranked_vote(&["A", "B", "C"], &[&["A"], &["A"], &["B"], &["C"]]);
+
+        let vote_result = tally_instant_runoff(&bot_data, &vote, &get_emojis(&vote));
+
+        assert_eq!(vote_result.rounds.len(), 2);
+        assert_eq!(vote_result.rounds[1].counts, to_counts(&[("A", 2)]));
+        assert_eq!(vote_result.winner, Some(String::from("A")));
+    }
+
+    #[test]
+    fn instant_runoff_without_rankings_terminates() {
+        let bot_data = crate::get_test_bot_data();
+        let vote = create_ranked_vote(&["A", "B"], &[]);
+
+        let vote_result = tally_instant_runoff(&bot_data, &vote, &get_emojis(&vote));
+
+        // All options are tied with zero votes, so the tie break decides in the first round
+        assert_eq!(vote_result.rounds.len(), 1);
+        assert!(vote_result.winner.is_some());
+        assert!(vote_result.tie_break.is_some());
+    }
+
+    #[test]
+    fn instant_runoff_all_tied_falls_back_to_random_instead_of_repeating_the_vote() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::Runoff;
+        let vote = create_ranked_vote(&["A", "B", "C"], &[&["A"], &["B"], &["C"]]);
+
+        let vote_result = tally_instant_runoff(&bot_data, &vote, &get_emojis(&vote));
+
+        assert!(vote_result.runoff_options.is_empty());
+        assert!(matches!(vote_result.winner.as_deref(), Some("A") | Some("B") | Some("C")));
+        assert!(vote_result.tie_break.is_some());
+    }
+
+    #[test]
+    fn parses_vote_modes() {
+        assert_eq!(VoteMode::from_str("Ranked"), Ok(VoteMode::Ranked));
+        assert_eq!(VoteMode::from_str("approval"), Ok(VoteMode::Approval));
+        assert_eq!(VoteMode::from_str("pick:3"), Ok(VoteMode::PickUpTo(3)));
+        assert_eq!(VoteMode::from_str("pick:0"), Err(()));
+        assert_eq!(VoteMode::from_str("pick"), Err(()));
+        assert_eq!(VoteMode::from_str("best"), Err(()));
+    }
+
+    #[test]
+    fn approval_votes_show_the_share_of_participants() {
+        let mut vote = test_vote(VoteMode::Approval, &["A", "B"]);
+        vote.options[0].get_votes_mut().extend(vec![discord::model::UserId(1), discord::model::UserId(2)]);
+        vote.options[1].get_votes_mut().push(discord::model::UserId(1));
+
+        assert_eq!(build_vote_embed_description(&vote, true), "\n\n`2` A - Option A (100%)\n\n`1` B - Option B (50%)");
+
+        vote.mode = VoteMode::Single;
+        assert_eq!(build_vote_embed_description(&vote, true), "\n\n`2` A - Option A\n\n`1` B - Option B");
+    }
+
+    #[test]
+    fn secret_ballots_only_show_the_number_of_voters() {
+        let mut vote = test_vote(VoteMode::Approval, &["A", "B"]);
+        vote.options[0].get_votes_mut().extend(vec![discord::model::UserId(1), discord::model::UserId(2)]);
+        vote.options[1].get_votes_mut().push(discord::model::UserId(1));
+
+        assert_eq!(build_vote_embed_description(&vote, false), "\n\nA - Option A\n\nB - Option B\n\n🗳️ Abgegebene Stimmen: `2`");
+    }
+
+    #[test]
+    fn parse_setting_reads_modes() {
+        let mut settings = VoteSettings::default();
+
+        assert_eq!(settings.parse_setting("mode:ranked"), Ok(true));
+        assert_eq!(settings.mode, VoteMode::Ranked);
+        assert_eq!(settings.parse_setting("mode:pick:3"), Ok(true));
+        assert_eq!(settings.mode, VoteMode::PickUpTo(3));
+        assert_eq!(settings.parse_setting("mode:unknown"), Err(()));
+    }
+
+    #[test]
+    fn parse_setting_reads_deadlines() {
+        let mut settings = VoteSettings::default();
+
+        assert_eq!(settings.parse_setting("duration:2h"), Ok(true));
+        assert_eq!(settings.deadline, Some(VoteDeadline::Duration(120)));
+        assert_eq!(settings.parse_setting("until:20:30"), Ok(true));
+        assert_eq!(settings.deadline, Some(VoteDeadline::Until(chrono::NaiveTime::from_hms_opt(20, 30, 0).unwrap())));
+        assert_eq!(settings.parse_setting("duration:0"), Err(()));
+        assert_eq!(settings.parse_setting("until:25:00"), Err(()));
+    }
+
+    #[test]
+    fn parse_setting_ignores_other_arguments() {
+        let mut settings = VoteSettings::default();
+
+        assert_eq!(settings.parse_setting("Filmabend"), Ok(false));
+        assert_eq!(settings, VoteSettings::default());
+    }
+
+    #[test]
+    fn deadlines_lie_in_the_future() {
+        let now = current_timestamp();
+
+        let duration_date = VoteDeadline::Duration(90).get_date().unwrap();
+        assert!(duration_date >= now + chrono::Duration::minutes(90));
+        assert!(duration_date < now + chrono::Duration::minutes(91));
+
+        // A time of day closes the vote within the next 24 hours, even if the time already passed today
+        let time = (now - chrono::Duration::minutes(1)).time();
+        let until_date = VoteDeadline::Until(time).get_date().unwrap();
+        assert!(until_date > now);
+        assert!(until_date <= now + chrono::Duration::days(1));
+    }
+
+    #[test]
+    fn parse_setting_reads_ballot() {
+        let mut settings = VoteSettings::default();
+
+        assert_eq!(settings.parse_setting("ballot:secret"), Ok(true));
+        assert!(settings.secret);
+        assert_eq!(settings.parse_setting("ballot:open"), Ok(true));
+        assert!(!settings.secret);
+        assert_eq!(settings.parse_setting("ballot:hidden"), Err(()));
+    }
+
+    #[test]
+    fn max_count_has_a_single_winner() {
+        let bot_data = crate::get_test_bot_data();
+        let vote = test_vote(VoteMode::Single, &["A", "B", "C"]);
+
+        let vote_result = determine_option_with_max_count(&bot_data, &vote, &to_counts(&[("A", 1), ("B", 3), ("C", 2)]), 3, Vec::new());
+
+        assert_eq!(vote_result.winner, Some(String::from("B")));
+        assert_eq!(vote_result.tie_break, None);
+        assert!(vote_result.runoff_options.is_empty());
+    }
+
+    #[test]
+    fn max_count_starts_runoff_between_some_tied_options() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::Runoff;
+        let vote = test_vote(VoteMode::Single, &["A", "B", "C"]);
+
+        let vote_result = determine_option_with_max_count(&bot_data, &vote, &to_counts(&[("A", 2), ("B", 2), ("C", 1)]), 3, Vec::new());
+
+        assert_eq!(vote_result.winner, None);
+        assert_eq!(vote_result.runoff_options, vec![String::from("A"), String::from("B")]);
+    }
+
+    #[test]
+    fn max_count_breaks_tie_randomly_for_general_options() {
+        let mut bot_data = crate::get_test_bot_data();
+        bot_data.tie_break_policy = TieBreakPolicy::OldestMovie;
+        let vote = test_vote(VoteMode::Approval, &["A", "B", "C"]);
+
+        let vote_result = determine_option_with_max_count(&bot_data, &vote, &to_counts(&[("A", 2), ("B", 2), ("C", 1)]), 3, Vec::new());
+
+        assert!(matches!(vote_result.winner.as_deref(), Some("A") | Some("B")));
+        assert!(vote_result.tie_break.is_some());
+    }
+
+    #[test]
+    fn only_watch_list_movies_have_a_movie_id() {
+        let movie = crate::get_test_watch_list_entry("Alien", 1).movie;
+        let movie_option = |external: bool| {
+            VoteOptionEnum::MovieVoteOption(VoteOption::<crate::movie_behaviour::Movie> {
+                id: 4,
+                emoji: String::from("A"),
+                cargo: movie.clone(),
+                votes: Vec::new(),
+                external,
+            })
+        };
+
+        assert_eq!(movie_option(false).get_movie_id(), Some(4));
+        assert_eq!(movie_option(true).get_movie_id(), None);
+        assert_eq!(test_vote(VoteMode::Single, &["B"]).options[0].get_movie_id(), None);
+    }
 }
@@ -1,13 +1,17 @@
-use crate::movie_behaviour::{SortedMovieList, WatchListEntry, UserSortedMovieListVectorEntry, get_movie_link};
+use crate::movie_behaviour::{SortedMovieList, MovieSortOrder, WatchListEntry, UserSortedMovieListVectorEntry, get_movie_link, sort_movie_entries};
+use crate::filter_behaviour::{MovieFilter, matches_all_filters, generate_movie_count_string};
 use std::collections::HashMap;
 use itertools::Itertools;
 use crate::general_behaviour::{timestamp_to_string};
+use crate::seen_behaviour::format_seen_suffix;
+use crate::snooze_behaviour::format_snooze_suffix;
 use crate::{COLOR_BOT};
 
 /**
- * Formats the watch list hash map and sends it as an embedded message
+ * Formats the watch list hash map and sends it as an embedded message. Only movies that fulfill all
+ * given filters are shown.
  */
-pub fn show_watch_list(bot_data: &mut crate::BotData, order: String) {
+pub fn show_watch_list(bot_data: &mut crate::BotData, order: MovieSortOrder, descending: bool, filters: Vec<MovieFilter>) {
     let message = bot_data.message.as_ref().expect("Passing message to show_watch_list function failed.");
 
     // First check if there was already a watch list waiting for reactions
@@ -26,34 +30,35 @@ pub fn show_watch_list(bot_data: &mut crate::BotData, order: String) {
     }
 
     let mut watch_list_string: String = String::new();
-    let watch_list_count = count_watch_list_movies(bot_data);
+    let watch_list_count = count_watch_list_movies(bot_data, &filters);
     let total_pages: usize;
     let sorted_watch_list_enum_option: Option<SortedMovieList>;
 
     if watch_list_count > 0 {
         // If the ordering by user is demanded
-        if order == "user" {
-            let user_sorted_watch_list = create_user_sorted_watch_list_vector(bot_data);
+        if order == MovieSortOrder::User {
+            let user_sorted_watch_list = create_user_sorted_watch_list_vector(bot_data, &filters, descending);
             total_pages = user_sorted_watch_list.iter().map(|x| x.number_of_pages_required).sum();
             
-            watch_list_string += generate_user_sorted_watch_list_page_string(&user_sorted_watch_list, 1).as_str();
+            watch_list_string += generate_user_sorted_watch_list_page_string(&user_sorted_watch_list, &filters, 1).as_str();
 
-            sorted_watch_list_enum_option = Some(SortedMovieList::WatchListUserSorted(total_pages, user_sorted_watch_list));
+            sorted_watch_list_enum_option = Some(SortedMovieList::WatchListUserSorted(total_pages, user_sorted_watch_list, filters));
         } 
-        // If the ordering should be by id
+        // Otherwise sort the whole list by the given order
         else {
-            let id_sorted_watch_list: Vec<(u32, WatchListEntry)> = bot_data.watch_list.iter().sorted()
-                .filter_map(|(id, entry)| if entry.status.is_watch_list_status() {
+            let mut sorted_watch_list: Vec<(u32, WatchListEntry)> = bot_data.watch_list.iter()
+                .filter_map(|(id, entry)| if entry.status.is_watch_list_status() && matches_all_filters(entry, &filters) {
                     Some((*id, entry.clone()))
                 } else {
                     None
                 })
                 .collect();
-            total_pages = (id_sorted_watch_list.len() as f64 / crate::MAX_ENTRIES_PER_PAGE as f64).ceil() as usize;
+            sort_movie_entries(&mut sorted_watch_list, order, descending);
+            total_pages = (sorted_watch_list.len() as f64 / crate::MAX_ENTRIES_PER_PAGE as f64).ceil() as usize;
 
-            watch_list_string += generate_id_sorted_watch_list_page_string(&id_sorted_watch_list, 1).as_str();
+            watch_list_string += generate_sorted_watch_list_page_string(&sorted_watch_list, &order, descending, &filters, 1).as_str();
 
-            sorted_watch_list_enum_option = Some(SortedMovieList::WatchListIdSorted(total_pages, id_sorted_watch_list));
+            sorted_watch_list_enum_option = Some(SortedMovieList::WatchListSorted(total_pages, order, descending, sorted_watch_list, filters));
         }
     } else {
         let _ = bot_data.bot.send_embed(
@@ -61,7 +66,7 @@ pub fn show_watch_list(bot_data: &mut crate::BotData, order: String) {
             "",
             |embed| embed
                 .title("Filmliste")
-                .description("Es sind zur Zeit **0** Filme auf der Liste")
+                .description(generate_movie_count_string(0, &filters, false).as_str())
                 .color(COLOR_BOT)
         );
         return;
@@ -106,16 +111,13 @@ pub fn show_watch_list(bot_data: &mut crate::BotData, order: String) {
  */
 pub fn generate_user_sorted_watch_list_page_string(
     watch_list_vector: &Vec<UserSortedMovieListVectorEntry>,
+    filters: &Vec<MovieFilter>,
     page_to_show: usize
 ) -> String {
     let mut watch_list_string = String::new();
     
     let watch_list_count: usize = watch_list_vector.iter().map(|x| x.entries.len()).sum();
-    if watch_list_count == 1 {
-        watch_list_string += format!("Es ist zur Zeit **{}** Film auf der Liste\n\n", watch_list_count).as_str();
-    } else {
-        watch_list_string += format!("Es sind zur Zeit **{}** Filme auf der Liste\n\n", watch_list_count).as_str();
-    }
+    watch_list_string += generate_movie_count_string(watch_list_count, filters, false).as_str();
 
     if watch_list_count == 0 {
         return watch_list_string;
@@ -157,12 +159,14 @@ pub fn generate_user_sorted_watch_list_page_string(
         // For each of those append the string to the watch list
         .for_each(
             |(_, entry)| {
-                watch_list_string += format!(" {} [**{}**]({})\n> `{:0>4}` | hinzugefügt am {}\n\n", 
+                watch_list_string += format!(" {} [**{}**]({})\n> `{:0>4}` | hinzugefügt am {}{}{}\n\n", 
                         entry.1.status.get_emoji(), 
                         entry.1.movie.movie_title, 
                         get_movie_link(entry.1.movie.tmdb_id, false), 
                         entry.0.to_string(), 
-                        timestamp_to_string(&entry.1.added_timestamp, false)
+                        timestamp_to_string(&entry.1.added_timestamp, false),
+                        format_seen_suffix(&entry.1),
+                        format_snooze_suffix(&entry.1)
                     )
                     .as_str()
             }
@@ -172,14 +176,14 @@ pub fn generate_user_sorted_watch_list_page_string(
 }
 
 /**
- * Collects all movie entries from the watch list that have a watch list status
+ * Collects all movie entries from the watch list that have a watch list status and fulfill the filters
  * and returns them in a new HashMap that contains the user as key.
  */
-fn create_user_sorted_watch_list(bot_data: &crate::BotData) -> HashMap<&String, Vec<(u32, &WatchListEntry)>> {
+fn create_user_sorted_watch_list<'a>(bot_data: &'a crate::BotData, filters: &Vec<MovieFilter>) -> HashMap<&'a String, Vec<(u32, &'a WatchListEntry)>> {
     // Create a hashmap that stores the user as key and a tuple containing the id and the watch list entry of every movie
     let mut user_movies: HashMap<&String, Vec<(u32, &WatchListEntry)>> = HashMap::new();
     for (id, entry) in bot_data.watch_list.iter().sorted() {
-        if entry.status.is_watch_list_status() {
+        if entry.status.is_watch_list_status() && matches_all_filters(entry, filters) {
             // Append the id, entry tuple to the user movie vector
             if let Some(vector) = user_movies.get_mut(&entry.user) {
                 vector.push((*id, &entry));
@@ -197,44 +201,57 @@ fn create_user_sorted_watch_list(bot_data: &crate::BotData) -> HashMap<&String,
 /**
  * Returns a watch list vector that is sorted by users and contains all information needed for paginating the entries
  */
-fn create_user_sorted_watch_list_vector(bot_data: &crate::BotData) -> Vec<UserSortedMovieListVectorEntry> {
-    let user_sorted_watch_list_hash_map = create_user_sorted_watch_list(bot_data);
+fn create_user_sorted_watch_list_vector(bot_data: &crate::BotData, filters: &Vec<MovieFilter>, descending: bool) -> Vec<UserSortedMovieListVectorEntry> {
+    let user_sorted_watch_list_hash_map = create_user_sorted_watch_list(bot_data, filters);
     let get_number_pages_as_usize = |entries_len: usize| -> usize {
         (entries_len as f64 / crate::MAX_ENTRIES_PER_PAGE as f64).ceil() as usize
     };
 
-    user_sorted_watch_list_hash_map.iter()
+    let mut user_sorted_watch_list: Vec<UserSortedMovieListVectorEntry> = user_sorted_watch_list_hash_map.iter()
+        .sorted_by_key(|(name, _)| name.to_lowercase())
         .map(|(name, entries)| UserSortedMovieListVectorEntry {
             user_name: (*name).clone(),
             number_of_pages_required: get_number_pages_as_usize(entries.len()),
             entries: entries.iter().map(|(id, value)| (*id, (*value).clone())).collect(),
         })
-        .collect()
+        .collect();
+
+    if descending {
+        user_sorted_watch_list.reverse();
+    }
+
+    user_sorted_watch_list
 }
 
 /**
- * Generates the paginated description text for the id sorted watch list
+ * Generates the paginated description text for the watch list sorted by the given order
  */
-pub fn generate_id_sorted_watch_list_page_string(id_sorted_watch_list: &Vec<(u32, WatchListEntry)>, page_to_show: usize) -> String {
+pub fn generate_sorted_watch_list_page_string(
+    sorted_watch_list: &Vec<(u32, WatchListEntry)>,
+    order: &MovieSortOrder,
+    descending: bool,
+    filters: &Vec<MovieFilter>,
+    page_to_show: usize
+) -> String {
     let mut watch_list_string = String::new();
 
-    let watch_list_count = id_sorted_watch_list.len();
-    if watch_list_count == 1 {
-        watch_list_string += format!("Es ist zur Zeit **{}** Film auf der Liste\n\n", watch_list_count).as_str();
-    } else {
-        watch_list_string += format!("Es sind zur Zeit **{}** Filme auf der Liste\n\n", watch_list_count).as_str();
-    }
+    let watch_list_count = sorted_watch_list.len();
+    watch_list_string += generate_movie_count_string(watch_list_count, filters, false).as_str();
 
     if watch_list_count == 0 {
         return watch_list_string;
     }
 
-    watch_list_string += format!("Die Filme werden geordnet nach ID angezeigt.\n\n").as_str();
+    watch_list_string += format!(
+        "Die Filme werden geordnet nach {}{} angezeigt.\n\n", 
+        order.get_description(), 
+        if descending { " (absteigend)" } else { "" }
+    ).as_str();
 
     let first_index_to_show = (page_to_show - 1) * crate::MAX_ENTRIES_PER_PAGE;
 
     // For every movie entry
-    id_sorted_watch_list.iter()
+    sorted_watch_list.iter()
         // Get the index of every element
         .enumerate()
         // Get only those elements that belong on that page
@@ -246,13 +263,16 @@ pub fn generate_id_sorted_watch_list_page_string(id_sorted_watch_list: &Vec<(u32
         // Now build the watch_list_string for those
         .for_each(
             |(_, (id, entry))| {
-                watch_list_string += format!(" {} [**{}**]({})\n> `{:0>4}` | hinzugefügt von **{}** am {}\n\n", 
+                watch_list_string += format!(" {} [**{}**]({})\n> `{:0>4}` | hinzugefügt von **{}** am {}{}{}{}\n\n", 
                     entry.status.get_emoji(), 
                     entry.movie.movie_title, 
                     get_movie_link(entry.movie.tmdb_id, false), 
                     id.to_string(), 
                     entry.user, 
-                    timestamp_to_string(&entry.added_timestamp, false)
+                    timestamp_to_string(&entry.added_timestamp, false),
+                    format_seen_suffix(entry),
+                    format_snooze_suffix(entry),
+                    order.get_sort_value_string(entry).map(|value| format!(" | {}", value)).unwrap_or_default()
                 ).as_str();
             });
 
@@ -260,10 +280,10 @@ pub fn generate_id_sorted_watch_list_page_string(id_sorted_watch_list: &Vec<(u32
 }
 
 /**
- * Counts all movies from the list with watch list status and returns the count
+ * Counts all movies from the list with watch list status that fulfill the filters and returns the count
  */
-fn count_watch_list_movies(bot_data: &crate::BotData) -> usize {
+fn count_watch_list_movies(bot_data: &crate::BotData, filters: &Vec<MovieFilter>) -> usize {
     bot_data.watch_list.iter()
-        .filter(|(_, entry)| entry.status.is_watch_list_status())
+        .filter(|(_, entry)| entry.status.is_watch_list_status() && matches_all_filters(entry, filters))
         .count()
 }
\ No newline at end of file